@@ -1,5 +1,5 @@
 use crate::{
-    datastructure::{DataStructure, DataVariant, GenParamKind, StructKind},
+    datastructure::{DataStructure, DataVariant, Field, GenParamKind, StructKind},
     syntax::ImplHeader,
 };
 
@@ -11,7 +11,7 @@ use syn::{punctuated::Punctuated, DeriveInput, Ident};
 
 use alloc::{string::ToString, vec::Vec};
 
-use self::attribute_parsing::{Configuration, GenParamIgnorance};
+use self::attribute_parsing::{ArrayFmt, Configuration, GenParamIgnorance};
 
 mod attribute_parsing;
 
@@ -69,7 +69,14 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> syn::Result<TokenStr
 
     let mut field_counters = ds.variants.iter().enumerate().map(|(v_index, v)| {
         let field_amount = v.fields.len();
-        let field_tys = v.fields.iter().map(|x| x.ty);
+        let field_pv_counts = v.fields.iter().map(|f| {
+            let ty = f.ty;
+            if uses_array_panicvals(&config.array_fmts, ds, f) {
+                quote!(__cp_bCj7dq3Pud::__array_pv_count!(#ty))
+            } else {
+                quote!(<#ty as __cp_bCj7dq3Pud::PanicFmt>::PV_COUNT)
+            }
+        });
         let delimiter = &delimiters[v_index];
 
         quote!(
@@ -77,7 +84,7 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> syn::Result<TokenStr
                 field_amount: #field_amount,
                 summed_pv_count: {
                     0
-                    #( + <#field_tys as __cp_bCj7dq3Pud::PanicFmt>::PV_COUNT )*
+                    #( + #field_pv_counts )*
                 },
                 delimiter: #delimiter,
             }.call()
@@ -110,6 +117,21 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> syn::Result<TokenStr
         }
     }
 
+    let pv_count_init = match &config.flags {
+        Some(flags) => {
+            if !matches!(ds.data_variant, DataVariant::Struct) || ds.variants[0].fields.len() != 1 {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "the `flags` attribute requires a struct with a single integer field",
+                ));
+            }
+
+            let flag_amount = flags.len();
+            quote!(__cp_bCj7dq3Pud::fmt::FlagsFmt::pv_count(#flag_amount))
+        }
+        None => pv_count_init,
+    };
+
     let pv_count_init_lb = match &config.panicvals_lower_bound {
         Some(lb) => quote!(__cp_bCj7dq3Pud::utils::max_usize(#pv_count_init, #lb)),
         None => pv_count_init,
@@ -247,7 +269,12 @@ struct ArgsForInherentImpl<'a> {
 }
 
 fn emit_inherent_impl(
-    Configuration { display_fmt, .. }: &Configuration<'_>,
+    Configuration {
+        display_fmt,
+        flags,
+        array_fmts,
+        ..
+    }: &Configuration<'_>,
     ImplHeaderAndPvCountSelf {
         impl_header,
         pvcount_self,
@@ -290,12 +317,25 @@ fn emit_inherent_impl(
                 TokenStream2::new()
             };
 
+            let field_pvs = if uses_array_panicvals(array_fmts, ds, f) {
+                let ty = f.ty;
+                quote!(__cp_bCj7dq3Pud::array_panicvals!(fmtarg; #ty => *#field_patib))
+            } else if is_nonempty_tuple(f.ty) {
+                let ty = f.ty;
+                let ty = elide_lifetimes(quote!(#ty));
+                quote!(__cp_bCj7dq3Pud::tuple_panicvals!(fmtarg; #ty => *#field_patib))
+            } else {
+                quote!(
+                    __cp_bCj7dq3Pud::PanicFmt::PROOF
+                        .infer(#field_patib)
+                        .coerce(#field_patib)
+                        .to_panicvals(fmtarg)
+                )
+            };
+
             quote!(
                 #field_name_colon
-                &__cp_bCj7dq3Pud::PanicFmt::PROOF
-                    .infer(#field_patib)
-                    .coerce(#field_patib)
-                    .to_panicvals(fmtarg),
+                &#field_pvs,
                 &__cp_bCj7dq3Pud::fmt::#comma
                     .to_panicvals(fmtarg),
             )
@@ -325,13 +365,43 @@ fn emit_inherent_impl(
         }
     });
 
-    let ondebug = quote! (
-        fmtarg = fmtarg.indent();
+    let ondebug = match flags {
+        Some(flags) => {
+            let field = &ds.variants[0].fields[0].ident;
+            let field_ty = ds.variants[0].fields[0].ty;
+            let flag_names = flags.iter().map(|(name, _)| name.to_string());
+            let flag_values = flags.iter().map(|(_, value)| value);
+            let type_name = ds.name.to_string();
+
+            // casting through the unsigned type avoids sign-extending signed integers
+            let unsigned = quote!(<#field_ty as __cp_bCj7dq3Pud::__::FlagsBits>::Unsigned);
 
-        match self {
-            #(#branches)*
+            quote!(
+                __cp_bCj7dq3Pud::fmt::FlagsFmt::new(
+                    #type_name,
+                    self.#field as #unsigned as __cp_bCj7dq3Pud::__::u128,
+                    &[#(
+                        (#flag_names, (#flag_values) as #unsigned as __cp_bCj7dq3Pud::__::u128),
+                    )*],
+                )
+                .to_panicvals(fmtarg)
+            )
         }
-    );
+        None => quote! (
+            fmtarg = fmtarg.indent();
+
+            match self {
+                #(#branches)*
+            }
+        ),
+    };
+
+    // `FlagsFmt` indents `fmtarg` by itself
+    let fmtarg_mut = if flags.is_none() {
+        quote!(mut)
+    } else {
+        TokenStream2::new()
+    };
 
     let dofmt = match display_fmt {
         Some(display_fmt_) => quote!(
@@ -348,10 +418,111 @@ fn emit_inherent_impl(
         {
             pub const fn to_panicvals(
                 &self,
-                mut fmtarg: __cp_bCj7dq3Pud::FmtArg,
+                #fmtarg_mut fmtarg: __cp_bCj7dq3Pud::FmtArg,
             ) -> [__cp_bCj7dq3Pud::PanicVal<'_>; #get_pv_count] {
                 #dofmt
             }
         }
     )
 }
+
+// Whether `field` is formatted with the `array_panicvals` macro,
+// because its type is an array that doesn't implement `PanicFmt`.
+fn uses_array_panicvals(
+    array_fmts: &[Vec<Option<ArrayFmt>>],
+    ds: &DataStructure<'_>,
+    field: &Field<'_>,
+) -> bool {
+    match array_fmts[field.index.variant][field.index.pos] {
+        Some(array_fmt) => array_fmt == ArrayFmt::PanicVals,
+        None => is_array_of_custom_type(ds, field.ty),
+    }
+}
+
+// Whether `ty` is a tuple type with at least one element,
+// which is formatted with the `tuple_panicvals` macro,
+// because tuples don't have a `to_panicvals` method.
+fn is_nonempty_tuple(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Tuple(tuple) if !tuple.elems.is_empty())
+}
+
+// Replaces all the lifetimes in `tokens` with `'_`,
+// because `tuple_panicvals` uses the tuple type in an array length,
+// where the lifetime parameters of the impl can't be used.
+fn elide_lifetimes(tokens: TokenStream2) -> TokenStream2 {
+    use proc_macro2::{Group, TokenTree};
+
+    let mut out = TokenStream2::new();
+    let mut iter = tokens.into_iter();
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                // skips the name of the lifetime
+                iter.next();
+                out.extend(quote!('_));
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(group.delimiter(), elide_lifetimes(group.stream()));
+                new_group.set_span(group.span());
+                out.extend(core::iter::once(TokenTree::Group(new_group)));
+            }
+            tt => out.extend(core::iter::once(tt)),
+        }
+    }
+    out
+}
+
+// Whether `ty` is an array type that can't be formatted with its `PanicFmt` impl,
+// because only arrays of primitive types implement `PanicFmt`.
+//
+// This is determined syntactically, so arrays of type aliases of primitive types
+// are considered arrays of custom types.
+// Arrays of type parameters are considered arrays of primitive types,
+// because the `array_panicvals` macro needs the concrete element type
+// (neither way of formatting them compiles, this one has the clearer error).
+fn is_array_of_custom_type(ds: &DataStructure<'_>, ty: &syn::Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64", "bool", "char",
+    ];
+
+    let elem = match ty {
+        syn::Type::Array(arr) => &*arr.elem,
+        _ => return false,
+    };
+
+    match elem {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let path = &path.path;
+            let is_type_param = ds.generics.type_params().any(|tp| path.is_ident(&tp.ident));
+
+            !is_type_param && !is_primitive_path(path, PRIMITIVES)
+        }
+        syn::Type::Reference(reff) if reff.mutability.is_none() => {
+            !matches!(&*reff.elem, syn::Type::Path(path) if is_primitive_path(&path.path, &["str"]))
+        }
+        _ => true,
+    }
+}
+
+// Whether `path` is the name of one of the `primitives` types,
+// either unqualified (eg: `u8`) or through the `primitive` module (eg: `core::primitive::u8`)
+fn is_primitive_path(path: &syn::Path, primitives: &[&str]) -> bool {
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    if segments.iter().any(|s| !s.arguments.is_empty()) {
+        return false;
+    }
+
+    let is_primitive_module = match &segments[..] {
+        [_] => path.leading_colon.is_none(),
+        [krate, module, _] => {
+            (krate.ident == "core" || krate.ident == "std") && module.ident == "primitive"
+        }
+        _ => false,
+    };
+
+    is_primitive_module
+        && primitives
+            .iter()
+            .any(|prim| segments.last().unwrap().ident == prim)
+}
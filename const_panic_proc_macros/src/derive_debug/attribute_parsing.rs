@@ -21,6 +21,9 @@ mod keyword {
     syn::custom_keyword!(panicvals_lower_bound);
     syn::custom_keyword!(debug_print);
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(flags);
+    syn::custom_keyword!(array_impl);
+    syn::custom_keyword!(array_panicvals);
 }
 
 #[derive(Copy, Clone)]
@@ -35,9 +38,11 @@ struct ParsedAttributes<'a> {
     crate_path: syn::Path,
     display_fmt: Option<syn::Expr>,
     panicvals_lower_bound: Option<syn::Expr>,
+    flags: Option<Vec<(Ident, syn::Expr)>>,
     impls: Vec<ImplHeader>,
     gen_params_props: Vec<GenParamProps<'a>>,
     type_const_params: Vec<Ident>,
+    array_fmts: Vec<Vec<Option<ArrayFmt>>>,
     _marker: PhantomData<&'a ()>,
 }
 
@@ -46,17 +51,31 @@ pub(super) struct Configuration<'a> {
     pub(super) crate_path: syn::Path,
     pub(super) display_fmt: Option<syn::Expr>,
     pub(super) panicvals_lower_bound: Option<syn::Expr>,
+    pub(super) flags: Option<Vec<(Ident, syn::Expr)>>,
     pub(super) impls: Vec<ImplHeader>,
     pub(super) gen_params_props: Vec<GenParamProps<'a>>,
+    /// How each array field is formatted, indexed by variant and then field position,
+    /// `None` if it wasn't specified with an attribute.
+    pub(super) array_fmts: Vec<Vec<Option<ArrayFmt>>>,
     _marker: PhantomData<&'a ()>,
 }
 
+/// How a field of array type is formatted
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(super) enum ArrayFmt {
+    /// With the `PanicFmt` impl of the array type
+    Impl,
+    /// With the `array_panicvals` macro, formatting each element with its `PanicFmt` impl
+    PanicVals,
+}
+
 pub(super) fn parse_attributes<'a>(ds: &'a DataStructure<'a>) -> syn::Result<Configuration<'a>> {
     let mut this = ParsedAttributes {
         debug_print: false,
         crate_path: syn::parse_quote!(::const_panic),
         display_fmt: None,
         panicvals_lower_bound: None,
+        flags: None,
         impls: Vec::new(),
         gen_params_props: ds
             .generics
@@ -84,6 +103,11 @@ pub(super) fn parse_attributes<'a>(ds: &'a DataStructure<'a>) -> syn::Result<Con
                 GenericParam::Const(x) => Some(x.ident.clone()),
             })
             .collect(),
+        array_fmts: ds
+            .variants
+            .iter()
+            .map(|v| alloc::vec![None; v.fields.len()])
+            .collect(),
         _marker: PhantomData,
     };
 
@@ -157,6 +181,41 @@ fn parse_helper_attribute<'a>(
 
         input.parse::<Token!(=)>()?;
         this.panicvals_lower_bound = Some(syn::Expr::Verbatim(input.parse()?));
+    } else if let Some(_) = input.peek_parse(keyword::flags)? {
+        check_is_container(&ctx, empty)?;
+
+        let contents;
+        let _ = syn::parenthesized!(contents in input);
+
+        let flags = this.flags.get_or_insert_with(Vec::new);
+        while !contents.is_empty() {
+            let name = contents.parse::<syn::Ident>()?;
+            contents.parse::<Token!(=)>()?;
+
+            let mut value = TokenStream2::new();
+            while !contents.is_empty() && !contents.peek(Token!(,)) {
+                value.extend(core::iter::once(
+                    contents.parse::<proc_macro2::TokenTree>()?,
+                ));
+            }
+            if value.is_empty() {
+                return Err(contents.error("expected the value of the flag"));
+            }
+
+            flags.push((name, syn::Expr::Verbatim(value)));
+
+            if !contents.is_empty() {
+                contents.parse::<Token!(,)>()?;
+            }
+        }
+    } else if input.peek_parse(keyword::array_impl)?.is_some() {
+        let field = check_is_array_field(&ctx, empty)?;
+
+        this.array_fmts[field.index.variant][field.index.pos] = Some(ArrayFmt::Impl);
+    } else if input.peek_parse(keyword::array_panicvals)?.is_some() {
+        let field = check_is_array_field(&ctx, empty)?;
+
+        this.array_fmts[field.index.variant][field.index.pos] = Some(ArrayFmt::PanicVals);
     } else if let Some(_) = input.peek_parse(Token!(crate))? {
         check_is_container(&ctx, empty)?;
 
@@ -220,9 +279,11 @@ fn finish<'a>(
         crate_path,
         display_fmt,
         panicvals_lower_bound,
+        flags,
         impls,
         gen_params_props,
         type_const_params: _,
+        array_fmts,
         _marker,
     } = this;
 
@@ -231,8 +292,10 @@ fn finish<'a>(
         crate_path,
         display_fmt,
         panicvals_lower_bound,
+        flags,
         impls,
         gen_params_props,
+        array_fmts,
         _marker,
     })
 }
@@ -251,6 +314,19 @@ pub(crate) fn check_is_container(
     }
 }
 
+fn check_is_array_field<'a>(
+    ctx: &ParseCtx<'a>,
+    sp: &dyn syn::spanned::Spanned,
+) -> syn::Result<&'a Field<'a>> {
+    match ctx {
+        ParseCtx::Field(field) if matches!(field.ty, syn::Type::Array { .. }) => Ok(field),
+        _ => Err(syn::Error::new(
+            sp.span(),
+            "Can only use this attribute above a field of array type",
+        )),
+    }
+}
+
 pub(super) struct GenParamProps<'a> {
     pub(super) kind: GenParamKind,
     pub(super) ignored: GenParamIgnorance<'a>,
@@ -179,3 +179,109 @@ fn impl_attribute_typename_error() {
 
     assert!(err.consecutive_unspace(&["expected `Fooo`"]), "\n{}\n", err,);
 }
+
+#[test]
+fn flags_attribute_error() {
+    for case in [
+        r#"
+            #[pfmt(flags(A = 1))]
+            pub struct Fooo(u8, u8);
+        "#,
+        r#"
+            #[pfmt(flags(A = 1))]
+            pub enum Fooo {
+                Bar(u8),
+            }
+        "#,
+    ] {
+        let err = process_str(case).unwrap_err();
+
+        assert!(
+            err.consecutive_unspace(&["`flags` attribute requires a struct"]),
+            "\n{}\n",
+            err,
+        );
+    }
+
+    let err = process_str(
+        r#"
+            #[pfmt(flags(A = ))]
+            pub struct Fooo(u8);
+        "#,
+    )
+    .unwrap_err();
+
+    assert!(
+        err.consecutive_unspace(&["expected the value of the flag"]),
+        "\n{}\n",
+        err,
+    );
+}
+
+#[test]
+fn array_attributes() {
+    let s = process_str(
+        r#"
+            pub struct Fooo {
+                #[pfmt(array_impl)]
+                a: [Byte; 2],
+                #[pfmt(array_panicvals)]
+                b: [u8; 2],
+                c: [core::primitive::u8; 2],
+                d: [::std::primitive::u8; 2],
+            }
+        "#,
+    )
+    .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            "<[Byte; 2] as __cp_bCj7dq3Pud::PanicFmt>::PV_COUNT",
+            "__cp_bCj7dq3Pud::__array_pv_count!([u8; 2])",
+            "<[core::primitive::u8; 2] as __cp_bCj7dq3Pud::PanicFmt>::PV_COUNT",
+            "<[::std::primitive::u8; 2] as __cp_bCj7dq3Pud::PanicFmt>::PV_COUNT",
+        ]),
+        "\n{}\n",
+        s,
+    );
+
+    for case in [
+        r#"
+            #[pfmt(array_impl)]
+            pub struct Fooo([u8; 2]);
+        "#,
+        r#"
+            pub struct Fooo(#[pfmt(array_panicvals)] u8);
+        "#,
+    ] {
+        let err = process_str(case).unwrap_err();
+
+        assert!(
+            err.consecutive_unspace(&["above a field of array type"]),
+            "\n{}\n",
+            err,
+        );
+    }
+}
+
+#[test]
+fn tuple_fields() {
+    let s = process_str(
+        r#"
+            pub struct Fooo<'a> {
+                a: (u8, (&'a str, u8)),
+                b: (),
+            }
+        "#,
+    )
+    .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            "__cp_bCj7dq3Pud::tuple_panicvals!(fmtarg; (u8, (&'_ str, u8)) => *fa_7ac4rtizw8q)",
+            ".infer(fb_7ac4rtizw8q)",
+        ]),
+        "\n{}\n",
+        s,
+    );
+}
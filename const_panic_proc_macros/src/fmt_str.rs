@@ -0,0 +1,707 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::{quote, ToTokens};
+
+use syn::{
+    parse::{ParseStream, Parser},
+    Expr, Ident, LitBool, LitStr,
+};
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use core::ops::Range;
+
+#[cfg(test)]
+mod tests;
+
+// Lowers a std-like format string and its arguments to the arguments
+// of the `concat_panic`-like macro that is passed in.
+//
+// The input syntax is:
+// ```text
+// ($crate_path) ($macro_path) ($($prefix:tt)*) ($bind_args:literal)
+// $format_string:literal $(, $arg)* $(,)?
+// ```
+// where `$arg` is either a `$name:ident = $expr:expr` named argument,
+// or a `$expr:expr` positional argument.
+//
+// The output is a `$macro_path!{$($prefix)* $($lowered_args)*}` invocation.
+//
+// If `$bind_args` is `true`, the arguments are evaluated once, before that invocation,
+// so that arguments used multiple times in the format string are only evaluated once.
+// It's `false` for macros that require constant arguments,
+// since those can be used in items, which can't refer to local variables.
+pub(crate) fn lower_fmt_str(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let input = parse_input.parse2(input)?;
+
+    let krate = &input.krate;
+    let pieces = parse_fmt_str(&input.fmt_str)?;
+    let mut args = Arguments::new(input.args, input.bind_args)?;
+
+    let mut lowered = TokenStream2::new();
+    let mut text = String::new();
+
+    for piece in pieces {
+        match piece {
+            Piece::Text(x) => text.push_str(&x),
+            Piece::Placeholder { arg, spec, range } => {
+                let arg = args.get(&input.fmt_str, arg, range)?;
+
+                push_text(&mut lowered, &mut text, &input.fmt_str);
+
+                let spec = spec.to_fmtarg(krate);
+                lowered.extend(quote!({__spec: #spec}: #arg,));
+            }
+        }
+    }
+
+    push_text(&mut lowered, &mut text, &input.fmt_str);
+
+    if lowered.is_empty() {
+        let empty = LitStr::new("", input.fmt_str.span());
+        lowered = quote!(#empty);
+    }
+
+    args.check_all_used()?;
+
+    let macro_path = &input.macro_path;
+    let prefix = &input.prefix;
+
+    let invocation = quote!(#macro_path!{#prefix #lowered});
+
+    if input.bind_args && !args.bindings.is_empty() {
+        let (bindings, exprs): (Vec<_>, Vec<_>) = args.bindings.into_iter().unzip();
+        Ok(quote!(
+            match (#(&(#exprs),)*) {
+                (#(#bindings,)*) => #invocation
+            }
+        ))
+    } else {
+        Ok(invocation)
+    }
+}
+
+fn push_text(lowered: &mut TokenStream2, text: &mut String, fmt_str: &LitStr) {
+    if !text.is_empty() {
+        let lit = LitStr::new(text, fmt_str.span());
+        lowered.extend(quote!(#lit,));
+        text.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Input {
+    krate: TokenStream2,
+    macro_path: TokenStream2,
+    prefix: TokenStream2,
+    bind_args: bool,
+    fmt_str: LitStr,
+    args: Vec<Argument>,
+}
+
+struct Argument {
+    name: Option<Ident>,
+    expr: Expr,
+}
+
+fn parse_input(input: ParseStream<'_>) -> syn::Result<Input> {
+    let parse_group = |input: ParseStream<'_>| -> syn::Result<TokenStream2> {
+        let content;
+        syn::parenthesized!(content in input);
+        content.parse()
+    };
+
+    let krate = parse_group(input)?;
+    let macro_path = parse_group(input)?;
+    let prefix = parse_group(input)?;
+    let bind_args = syn::parse2::<LitBool>(parse_group(input)?)?.value;
+
+    let fmt_str = match input.parse::<LitStr>() {
+        Ok(x) => x,
+        Err(e) => {
+            return Err(syn::Error::new(
+                e.span(),
+                "expected a format string literal as the first argument",
+            ))
+        }
+    };
+
+    let mut args = Vec::new();
+    while !input.is_empty() {
+        input.parse::<syn::Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+
+        let name =
+            if input.peek(Ident) && input.peek2(syn::Token![=]) && !input.peek2(syn::Token![==]) {
+                let name = input.parse::<Ident>()?;
+                input.parse::<syn::Token![=]>()?;
+                Some(name)
+            } else {
+                None
+            };
+
+        let expr = input.parse::<Expr>()?;
+
+        args.push(Argument { name, expr });
+    }
+
+    Ok(Input {
+        krate,
+        macro_path,
+        prefix,
+        bind_args,
+        fmt_str,
+        args,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// The tokens that a placeholder is replaced with, the span of the argument
+// (for errors), and whether the argument is used.
+struct LoweredArg {
+    tokens: TokenStream2,
+    span: Span,
+    used: bool,
+}
+
+struct Arguments {
+    positional: Vec<LoweredArg>,
+    named: Vec<(Ident, LoweredArg)>,
+    // the variables that the arguments are bound to, and the argument expressions.
+    bindings: Vec<(Ident, Expr)>,
+    next_implicit: usize,
+}
+
+impl Arguments {
+    fn new(args: Vec<Argument>, bind_args: bool) -> syn::Result<Self> {
+        let mut this = Self {
+            positional: Vec::new(),
+            named: Vec::new(),
+            bindings: Vec::new(),
+            next_implicit: 0,
+        };
+
+        for Argument { name, expr } in args {
+            let span = syn::spanned::Spanned::span(&expr);
+
+            if name.is_none() && !this.named.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "positional arguments cannot follow named arguments",
+                ));
+            }
+
+            let tokens = if bind_args {
+                // `mixed_site` so that the binding can't be named by
+                // the identifiers captured by the format string.
+                let binding = Ident::new(
+                    &format!("__arg_{}", this.bindings.len()),
+                    Span::mixed_site(),
+                );
+                let tokens = quote!(*#binding);
+                this.bindings.push((binding, expr));
+                tokens
+            } else {
+                expr.into_token_stream()
+            };
+
+            let arg = LoweredArg {
+                tokens,
+                span,
+                used: false,
+            };
+
+            match name {
+                Some(name) => {
+                    if this.named.iter().any(|(x, _)| *x == name) {
+                        let msg = format!("duplicate argument named `{}`", name);
+                        return Err(syn::Error::new(name.span(), msg));
+                    }
+                    this.named.push((name, arg));
+                }
+                None => this.positional.push(arg),
+            }
+        }
+
+        Ok(this)
+    }
+
+    fn get(
+        &mut self,
+        fmt_str: &LitStr,
+        arg: ArgRef,
+        range: Range<usize>,
+    ) -> syn::Result<TokenStream2> {
+        let index = match arg {
+            ArgRef::Implicit => {
+                self.next_implicit += 1;
+                self.next_implicit - 1
+            }
+            ArgRef::Index(x) => x,
+            ArgRef::Name(name) => {
+                if let Some((_, arg)) = self.named.iter_mut().find(|(x, _)| *x == name) {
+                    arg.used = true;
+                    return Ok(arg.tokens.clone());
+                }
+
+                // inline captured identifier
+                let span = subspan(fmt_str, range);
+                return Ok(Ident::new(&name, span).into_token_stream());
+            }
+        };
+
+        let arg_count = self.positional.len();
+        match self.positional.get_mut(index) {
+            Some(arg) => {
+                arg.used = true;
+                Ok(arg.tokens.clone())
+            }
+            None => {
+                let msg = format!(
+                    "invalid reference to positional argument {} ({})",
+                    index,
+                    match arg_count {
+                        0 => "no arguments were given".to_string(),
+                        1 => "there is 1 argument".to_string(),
+                        n => format!("there are {} arguments", n),
+                    },
+                );
+                Err(syn::Error::new(subspan(fmt_str, range), msg))
+            }
+        }
+    }
+
+    fn check_all_used(&self) -> syn::Result<()> {
+        let mut res: syn::Result<()> = Ok(());
+
+        let unused_pos = self
+            .positional
+            .iter()
+            .filter(|arg| !arg.used)
+            .map(|arg| syn::Error::new(arg.span, "argument never used"));
+
+        let unused_named = self
+            .named
+            .iter()
+            .filter(|(_, arg)| !arg.used)
+            .map(|(name, _)| syn::Error::new(name.span(), "named argument never used"));
+
+        for err in unused_pos.chain(unused_named) {
+            match &mut res {
+                Ok(()) => res = Err(err),
+                Err(e) => e.combine(err),
+            }
+        }
+
+        res
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Text(String),
+    Placeholder {
+        arg: ArgRef,
+        spec: FmtSpec,
+        // the range of the placeholder in the format string, including braces
+        range: Range<usize>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum ArgRef {
+    Implicit,
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct FmtSpec {
+    fill: Option<char>,
+    alignment: Option<Alignment>,
+    sign_plus: bool,
+    is_alternate: bool,
+    zero_pad: bool,
+    width: Option<u8>,
+    digit_separator: Option<char>,
+    precision: Option<u8>,
+    kind: FmtKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum FmtKind {
+    Display,
+    Debug,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+}
+
+// `#[default]` on enum variants requires Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for FmtKind {
+    fn default() -> Self {
+        Self::Display
+    }
+}
+
+impl FmtSpec {
+    fn to_fmtarg(&self, krate: &TokenStream2) -> TokenStream2 {
+        let mut out = match self.kind {
+            FmtKind::Display => quote!(#krate::FmtArg::DISPLAY),
+            FmtKind::Debug => quote!(#krate::FmtArg::DEBUG),
+            FmtKind::LowerHex => quote!(#krate::FmtArg::DEBUG.set_lower_hex()),
+            FmtKind::UpperHex => quote!(#krate::FmtArg::DEBUG.set_hex()),
+            FmtKind::Octal => quote!(#krate::FmtArg::DEBUG.set_oct()),
+            FmtKind::Binary => quote!(#krate::FmtArg::DEBUG.set_bin()),
+        };
+
+        if self.is_alternate {
+            out.extend(quote!(.set_alternate(true)));
+        }
+        if let Some(fill) = self.fill {
+            out.extend(quote!(.set_fill(#fill)));
+        }
+        if let Some(alignment) = self.alignment {
+            let alignment = match alignment {
+                Alignment::Left => quote!(Left),
+                Alignment::Right => quote!(Right),
+                Alignment::Center => quote!(Center),
+            };
+            out.extend(quote!(.set_alignment(#krate::fmt::Alignment::#alignment)));
+        }
+        if self.sign_plus {
+            out.extend(quote!(.set_sign_plus(true)));
+        }
+        if self.zero_pad {
+            out.extend(quote!(.set_zero_pad(true)));
+        }
+        if let Some(width) = self.width {
+            out.extend(quote!(.set_width(#width)));
+        }
+        if let Some(separator) = self.digit_separator {
+            out.extend(quote!(.set_digit_separator(#separator)));
+        }
+        if let Some(precision) = self.precision {
+            out.extend(quote!(.set_precision(#precision)));
+        }
+
+        out
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn parse_fmt_str(fmt_str: &LitStr) -> syn::Result<Vec<Piece>> {
+    let string = fmt_str.value();
+    let error = |range: Range<usize>, msg: &str| -> syn::Error {
+        syn::Error::new(
+            subspan(fmt_str, range),
+            format!("invalid format string: {}", msg),
+        )
+    };
+
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = string.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if matches!(chars.peek(), Some((_, '{'))) => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                chars.next();
+                text.push('}');
+            }
+            '}' => {
+                return Err(error(
+                    i..i + 1,
+                    "unmatched `}` found, if you intended to print `}`, \
+                     you can escape it using `}}`",
+                ))
+            }
+            '{' => {
+                let end = match string[i..].find('}') {
+                    Some(x) => i + x,
+                    None => {
+                        return Err(error(
+                            i..string.len(),
+                            "expected `}` but string was terminated, \
+                             if you intended to print `{`, you can escape it using `{{`",
+                        ))
+                    }
+                };
+
+                if let Some(x) = string[i + 1..end].find('{') {
+                    let brace = i + 1 + x;
+                    return Err(error(
+                        brace..brace + 1,
+                        "expected `}`, found `{`, \
+                         if you intended to print `{`, you can escape it using `{{`",
+                    ));
+                }
+
+                while matches!(chars.peek(), Some(&(j, _)) if j <= end) {
+                    chars.next();
+                }
+
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(core::mem::take(&mut text)));
+                }
+
+                let range = i..end + 1;
+                let (arg, spec) = parse_placeholder(&string[i + 1..end], i + 1, &error)?;
+                pieces.push(Piece::Placeholder { arg, spec, range });
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+
+    Ok(pieces)
+}
+
+// Parses the contents of a `{...}` placeholder, `offset` is the position of `inner`
+// in the format string.
+fn parse_placeholder(
+    inner: &str,
+    offset: usize,
+    error: &dyn Fn(Range<usize>, &str) -> syn::Error,
+) -> syn::Result<(ArgRef, FmtSpec)> {
+    let (arg_str, spec_str) = match inner.find(':') {
+        Some(colon) => (&inner[..colon], Some((&inner[colon + 1..], colon + 1))),
+        None => (inner, None),
+    };
+
+    let arg_range = offset..offset + arg_str.len();
+    let arg_str = arg_str.trim_end();
+
+    let arg = if arg_str.is_empty() {
+        ArgRef::Implicit
+    } else if arg_str.bytes().all(|b| b.is_ascii_digit()) {
+        match arg_str.parse::<usize>() {
+            Ok(x) => ArgRef::Index(x),
+            Err(_) => return Err(error(arg_range, "invalid argument index")),
+        }
+    } else if is_identifier(arg_str) {
+        ArgRef::Name(arg_str.to_string())
+    } else {
+        let msg = format!(
+            "invalid argument name `{}`, expected an integer or an identifier",
+            arg_str
+        );
+        return Err(error(arg_range, &msg));
+    };
+
+    let spec = match spec_str {
+        Some((spec_str, spec_offset)) => parse_spec(spec_str, offset + spec_offset, error)?,
+        None => FmtSpec::default(),
+    };
+
+    Ok((arg, spec))
+}
+
+// Parses a `[[fill]align][+][#][0][width][grouping][.precision][type]` formatting spec,
+// `offset` is the position of `spec` in the format string.
+fn parse_spec(
+    spec: &str,
+    offset: usize,
+    error: &dyn Fn(Range<usize>, &str) -> syn::Error,
+) -> syn::Result<FmtSpec> {
+    let mut this = FmtSpec::default();
+    let mut rem = spec;
+
+    // the range of the `len` bytes starting at the current position
+    let range_at = |rem: &str, len: usize| {
+        let start = offset + spec.len() - rem.len();
+        start..start + len
+    };
+
+    let mut chars = rem.chars();
+    let first = chars.next();
+    let second = chars.next();
+    if let (Some(fill), Some(alignment)) = (first, second.and_then(parse_alignment)) {
+        this.fill = Some(fill);
+        this.alignment = Some(alignment);
+        rem = &rem[fill.len_utf8() + 1..];
+    } else if let Some(alignment) = first.and_then(parse_alignment) {
+        this.alignment = Some(alignment);
+        rem = &rem[1..];
+    }
+
+    if let Some(x) = rem.strip_prefix('+') {
+        this.sign_plus = true;
+        rem = x;
+    } else if rem.starts_with('-') {
+        return Err(error(range_at(rem, 1), "the `-` flag is not supported"));
+    }
+
+    if let Some(x) = rem.strip_prefix('#') {
+        this.is_alternate = true;
+        rem = x;
+    }
+
+    if rem.starts_with('0') && !rem[1..].starts_with('$') {
+        this.zero_pad = true;
+        rem = &rem[1..];
+    }
+
+    let (width, after_width) = parse_count(rem, "width", &range_at, error)?;
+    this.width = width;
+    rem = after_width;
+
+    if let Some(separator @ ('_' | ',')) = rem.chars().next() {
+        this.digit_separator = Some(separator);
+        rem = &rem[1..];
+    }
+
+    if let Some(after_dot) = rem.strip_prefix('.') {
+        if after_dot.starts_with('*') {
+            return Err(error(
+                range_at(rem, 2),
+                "precision arguments (`.*`) are not supported",
+            ));
+        }
+
+        let (precision, after_precision) = parse_count(after_dot, "precision", &range_at, error)?;
+        if precision.is_none() {
+            return Err(error(range_at(rem, 1), "expected a precision after `.`"));
+        }
+        this.precision = precision;
+        rem = after_precision;
+    }
+
+    this.kind = match rem.trim_end() {
+        "" => FmtKind::Display,
+        "?" => FmtKind::Debug,
+        "x" | "x?" => FmtKind::LowerHex,
+        "X" | "X?" => FmtKind::UpperHex,
+        "o" => FmtKind::Octal,
+        "b" => FmtKind::Binary,
+        ty => {
+            let msg = format!(
+                "unsupported formatting type `{}`, \
+                 expected one of `?`, `x`, `X`, `o`, `b`, `x?`, `X?`, or nothing",
+                ty
+            );
+            return Err(error(range_at(rem, ty.len()), &msg));
+        }
+    };
+
+    Ok(this)
+}
+
+// Parses the integer width or precision at the start of `rem`,
+// returning the string after it.
+fn parse_count<'a>(
+    rem: &'a str,
+    what: &str,
+    range_at: &dyn Fn(&str, usize) -> Range<usize>,
+    error: &dyn Fn(Range<usize>, &str) -> syn::Error,
+) -> syn::Result<(Option<u8>, &'a str)> {
+    let digits = rem.bytes().take_while(u8::is_ascii_digit).count();
+    let ident_len = if digits == 0 {
+        identifier_len(rem)
+    } else {
+        digits
+    };
+
+    if rem[ident_len..].starts_with('$') {
+        let msg = format!(
+            "{} arguments (`{}$`) are not supported",
+            what,
+            &rem[..ident_len]
+        );
+        return Err(error(range_at(rem, ident_len + 1), &msg));
+    }
+
+    if digits == 0 {
+        return Ok((None, rem));
+    }
+
+    match rem[..digits].parse::<u8>() {
+        Ok(x) => Ok((Some(x), &rem[digits..])),
+        Err(_) => {
+            let msg = format!("the {} can't be larger than 255", what);
+            Err(error(range_at(rem, digits), &msg))
+        }
+    }
+}
+
+fn parse_alignment(c: char) -> Option<Alignment> {
+    match c {
+        '<' => Some(Alignment::Left),
+        '>' => Some(Alignment::Right),
+        '^' => Some(Alignment::Center),
+        _ => None,
+    }
+}
+
+// The length of the identifier at the start of `s`, 0 if there is none.
+fn identifier_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    match chars.next() {
+        Some((_, c)) if c == '_' || unicode_xid::UnicodeXID::is_xid_start(c) => {}
+        _ => return 0,
+    }
+    chars
+        .find(|(_, c)| !unicode_xid::UnicodeXID::is_xid_continue(*c))
+        .map_or(s.len(), |(i, _)| i)
+}
+
+fn is_identifier(s: &str) -> bool {
+    s != "_" && identifier_len(s) == s.len()
+}
+
+// Gets the span of `range` inside the format string literal,
+// falling back to the span of the entire literal
+// when the compiler doesn't support sub-spans,
+// or when the literal contains escapes.
+fn subspan(fmt_str: &LitStr, range: Range<usize>) -> Span {
+    let lit = fmt_str.token();
+    let repr = lit.to_string();
+
+    // the length of the quotes (and `r` and `#`s) before and after the string
+    let (prefix_len, suffix_len) = if repr.starts_with('"') {
+        (1, 1)
+    } else if repr.starts_with('r') {
+        repr.find('"').map_or((0, 0), |x| (x + 1, x))
+    } else {
+        (0, 0)
+    };
+
+    let value = fmt_str.value();
+    let is_verbatim = prefix_len != 0
+        && repr.len() == prefix_len + value.len() + suffix_len
+        && repr.get(prefix_len..prefix_len + value.len()) == Some(value.as_str());
+
+    if is_verbatim {
+        lit.subspan(prefix_len + range.start..prefix_len + range.end)
+            .unwrap_or_else(|| fmt_str.span())
+    } else {
+        fmt_str.span()
+    }
+}
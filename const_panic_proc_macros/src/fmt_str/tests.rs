@@ -0,0 +1,152 @@
+use crate::test_utils::StrExt;
+
+use alloc::string::{String, ToString};
+
+fn process_str(s: &str) -> Result<String, String> {
+    syn::parse_str(s)
+        .and_then(crate::fmt_str::lower_fmt_str)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_compile_error().to_string())
+}
+
+fn process_fmt(args: &str) -> Result<String, String> {
+    process_str(&alloc::format!(
+        "(cp) (cp::concat_panic) () (false) {}",
+        args
+    ))
+}
+
+#[test]
+fn lowering() {
+    let s = process_fmt(r#""a{{{}}}b {x:?} {y:>+#08_.3x}", foo, y = bar"#).unwrap();
+    assert!(
+        s.consecutive_unspace(&[
+            "cp::concat_panic!{",
+            r#""a{", {__spec: cp::FmtArg::DISPLAY}: foo,"#,
+            r#""}b ", {__spec: cp::FmtArg::DEBUG}: x,"#,
+            r#"" ","#,
+            "{__spec: cp::FmtArg::DEBUG.set_lower_hex()",
+            ".set_alternate(true)",
+            ".set_alignment(cp::fmt::Alignment::Right)",
+            ".set_sign_plus(true)",
+            ".set_zero_pad(true)",
+            ".set_width(8u8)",
+            ".set_digit_separator('_')",
+            ".set_precision(3u8)",
+            "}: bar,",
+            "}",
+        ]),
+        "\n{}\n",
+        s,
+    );
+
+    let s = process_fmt(r#""{:ñ^5}""#).unwrap_err();
+    assert!(
+        s.contains("invalid reference to positional argument 0"),
+        "{}",
+        s
+    );
+
+    let s = process_fmt(r#""{:ñ^5}", foo"#).unwrap();
+    assert!(
+        s.consecutive_unspace(&[
+            ".set_fill('ñ')",
+            ".set_alignment(cp::fmt::Alignment::Center)"
+        ]),
+        "\n{}\n",
+        s,
+    );
+
+    let s = process_str(r#"(cp) (cp::concat_) (capacity = 10;) (false) """#).unwrap();
+    assert!(
+        s.consecutive_unspace(&[r#"cp::concat_!{capacity = 10; ""}"#]),
+        "\n{}\n",
+        s,
+    );
+}
+
+#[test]
+fn argument_binding() {
+    let s = process_str(
+        r#"(cp) (cp::concat_panic) () (true)
+        "{0} {0:?} {x} {y} {y}", foo::<A, B>(a, b), x = |a, b| a + b, y = [0, 1][c]"#,
+    )
+    .unwrap();
+    assert!(
+        s.consecutive_unspace(&[
+            "match (&(foo::<A, B>(a, b)), &(|a, b| a + b), &([0, 1][c]),) {",
+            "(__arg_0, __arg_1, __arg_2,) => cp::concat_panic!{",
+            "{__spec: cp::FmtArg::DISPLAY}: *__arg_0,",
+            r#"" ","#,
+            "{__spec: cp::FmtArg::DEBUG}: *__arg_0,",
+            r#"" ","#,
+            "{__spec: cp::FmtArg::DISPLAY}: *__arg_1,",
+            r#"" ","#,
+            "{__spec: cp::FmtArg::DISPLAY}: *__arg_2,",
+            r#"" ","#,
+            "{__spec: cp::FmtArg::DISPLAY}: *__arg_2,",
+            "}",
+            "}",
+        ]),
+        "\n{}\n",
+        s,
+    );
+
+    // without arguments, there's nothing to bind
+    let s = process_str(r#"(cp) (cp::concat_panic) () (true) "{x}""#).unwrap();
+    assert!(
+        s.consecutive_unspace(&["cp::concat_panic!{{__spec: cp::FmtArg::DISPLAY}: x,}"]),
+        "\n{}\n",
+        s,
+    );
+    assert!(!s.contains("match"), "\n{}\n", s);
+}
+
+#[test]
+fn fmt_str_errors() {
+    for (args, expected) in [
+        ("", "expected a format string literal"),
+        ("foo", "expected a format string literal"),
+        (r#""{""#, "expected `}` but string was terminated"),
+        (r#""}""#, "unmatched `}` found"),
+        (r#""{{}""#, "unmatched `}` found"),
+        (r#""{ {}""#, "expected `}`, found `{`"),
+        (r#""{a-b}""#, "invalid argument name `a-b`"),
+        (r#""{_}""#, "invalid argument name `_`"),
+        (r#""{:e}", 1"#, "unsupported formatting type `e`"),
+        (r#""{:-}", 1"#, "the `-` flag is not supported"),
+        (
+            r#""{:1$}", 1, 2"#,
+            "width arguments (`1$`) are not supported",
+        ),
+        (
+            r#""{:width$}", 1"#,
+            "width arguments (`width$`) are not supported",
+        ),
+        (
+            r#""{:.*}", 1, 2"#,
+            "precision arguments (`.*`) are not supported",
+        ),
+        (
+            r#""{:.p$}", 1"#,
+            "precision arguments (`p$`) are not supported",
+        ),
+        (r#""{:.}", 1"#, "expected a precision after `.`"),
+        (r#""{:256}", 1"#, "the width can't be larger than 255"),
+        (r#""{:.300}", 1"#, "the precision can't be larger than 255"),
+        (r#""{} {}", 1"#, "there is 1 argument"),
+        (r#""{2}", 1, 2"#, "there are 2 arguments"),
+        (r#""{}""#, "no arguments were given"),
+        (r#""{}", 1, 2"#, "argument never used"),
+        (r#""", a = 2"#, "named argument never used"),
+        (r#""{a}", a = 1, a = 2"#, "duplicate argument named `a`"),
+        (
+            r#""{a}", a = 1, 2"#,
+            "positional arguments cannot follow named arguments",
+        ),
+        (r#""{}", 1,, 2"#, "expected an expression"),
+    ] {
+        let s = process_fmt(args).unwrap_err();
+        assert!(s.contains(expected), "\nargs: {}\nerror: {}\n", args, s);
+    }
+}
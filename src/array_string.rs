@@ -132,6 +132,45 @@ impl<const CAP: usize> ArrayString<CAP> {
         }
     }
 
+    /// Constructs this string from a `&[&[PanicVal<'_>]]`,
+    /// truncating the formatted args if they're larger than `CAP`.
+    ///
+    /// When truncated, the end of the string is replaced with a marker
+    /// with the length of the untruncated string, eg: `... [truncated, 41230 bytes total]`.
+    /// The marker is omitted if it's larger than `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg, flatten_panicvals};
+    ///
+    /// let args: &[&[_]] = &[
+    ///     &flatten_panicvals!(FmtArg::DEBUG; "numbers: "),
+    ///     &flatten_panicvals!(FmtArg::DEBUG; &[3u8, 5, 8, 13, 21, 34, 55, 89]),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<99>::concat_panicvals_lossy(args),
+    ///     "numbers: [3, 5, 8, 13, 21, 34, 55, 89]",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<36>::concat_panicvals_lossy(args),
+    ///     "numbe... [truncated, 38 bytes total]",
+    /// );
+    ///
+    /// // too small for the marker
+    /// assert_eq!(ArrayString::<4>::concat_panicvals_lossy(args), "numb");
+    ///
+    /// ```
+    ///
+    pub const fn concat_panicvals_lossy(args: &[&[PanicVal<'_>]]) -> Self {
+        match crate::concat_panic_::make_panic_string_lossy::<CAP>(args) {
+            Ok(x) => x,
+            Err(_) => panic!("unreachable: strings are truncated when they're larger than CAP"),
+        }
+    }
+
     /// Constructs this string from a `&[PanicVal<'_>]`.
     ///
     /// Returns `None` if the formatted args would be larger than `CAP`.
@@ -160,6 +199,36 @@ impl<const CAP: usize> ArrayString<CAP> {
         Self::concat_panicvals(&[args])
     }
 
+    /// Constructs this string from a `&[PanicVal<'_>]`,
+    /// truncating the formatted args if they're larger than `CAP`.
+    ///
+    /// This truncates the string
+    /// [like `concat_panicvals_lossy` does](Self::concat_panicvals_lossy).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg, flatten_panicvals};
+    ///
+    /// let args = flatten_panicvals!(FmtArg::DEBUG;
+    ///     "hello ", ("world"), " ", &[3u8, 5, 8, 13, 21, 34, 55, 89]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<50>::from_panicvals_lossy(&args),
+    ///     "hello \"world\" [3, 5, 8, 13, 21, 34, 55, 89]",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<36>::from_panicvals_lossy(&args),
+    ///     "hello... [truncated, 43 bytes total]",
+    /// );
+    ///
+    /// ```
+    pub const fn from_panicvals_lossy(args: &[PanicVal<'_>]) -> Self {
+        Self::concat_panicvals_lossy(&[args])
+    }
+
     /// How long the string is in bytes.
     ///
     /// # Example
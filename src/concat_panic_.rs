@@ -1,7 +1,7 @@
 use crate::{
-    fmt::FmtKind,
-    panic_val::{PanicClass, PanicVal, StrFmt},
-    utils::{bytes_up_to, string_cap, WasTruncated},
+    fmt::{char_formatting::char_to_utf8, FmtKind},
+    panic_val::{BytesFmt, PanicClass, PanicVal, StrFmt},
+    utils::{bytes_sequence, bytes_up_to, RangedBytes, WasTruncated},
 };
 
 /// Panics by concatenating the argument slice.
@@ -49,11 +49,61 @@ pub const fn concat_panic(args: &[&[PanicVal<'_>]]) -> ! {
     //
     // Also, given that most(?) panic messages are smaller than 1024 bytes long,
     // it's not going to be any less efficient in the common case.
-    if let Err(_) = panic_inner::<(), 1024>(args) {}
+    if 1024 < MAX_PANIC_MSG_LEN {
+        if let Err(_) = panic_inner::<(), 1024>(args, MAX_PANIC_MSG_LEN) {}
+    }
 
-    if let Err(_) = panic_inner::<(), { 1024 * 6 }>(args) {}
+    if 1024 * 6 < MAX_PANIC_MSG_LEN {
+        if let Err(_) = panic_inner::<(), { 1024 * 6 }>(args, MAX_PANIC_MSG_LEN) {}
+    }
 
-    match panic_inner::<_, MAX_PANIC_MSG_LEN>(args) {
+    concat_panic_with_capacity::<MAX_PANIC_MSG_LEN>(args)
+}
+
+/// Panics by concatenating the argument slice,
+/// using a buffer of exactly `CAPACITY` bytes.
+///
+/// This is the function that the [`concat_panic`](macro@concat_panic) macro calls
+/// when it's passed the `capacity = ...;` argument.
+///
+/// The panic message is truncated to `CAPACITY` bytes,
+/// which can be larger than [`MAX_PANIC_MSG_LEN`]
+/// (eg: for long compile-time error messages),
+/// or smaller than it (eg: to limit the stack usage of runtime panics).
+///
+/// Truncated messages end with a marker that says how long the message would have been,
+/// eg: `... [truncated, 41230 bytes total]`,
+/// the marker is omitted if it's longer than `CAPACITY`.
+///
+/// # Stack usage
+///
+/// Besides the `CAPACITY` byte buffer, formatting the message uses roughly
+/// 1.3 KB of stack (measured on x86_64 with optimizations),
+/// which goes up by roughly 1.3 KB while writing the truncation marker,
+/// and by roughly 3.7 KB while formatting floats with a precision.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{FmtArg, PanicVal, concat_panic_with_capacity};
+///
+/// let res = std::panic::catch_unwind(|| {
+///     concat_panic_with_capacity::<8>(&[&[
+///         PanicVal::write_str("the error was "),
+///         PanicVal::from_u8(100, FmtArg::DISPLAY),
+///     ]])
+/// });
+///
+/// let msg = res.unwrap_err();
+/// assert_eq!(msg.downcast_ref::<String>().unwrap(), "the erro");
+///
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_with_capacity<const CAPACITY: usize>(args: &[&[PanicVal<'_>]]) -> ! {
+    match panic_inner::<_, CAPACITY>(args, CAPACITY) {
         Ok(x) => x,
         Err(_) => panic!(
             "\
@@ -66,16 +116,33 @@ pub const fn concat_panic(args: &[&[PanicVal<'_>]]) -> ! {
 }
 
 /// The maximum length of panic messages (in bytes),
-/// after which the message is truncated.
-// this should probably be smaller on platforms where this
-// const fn is called at runtime, and the stack is finy.
-pub const MAX_PANIC_MSG_LEN: usize = 32768;
+/// after which the message is truncated,
+/// ending with a marker that says how long the message would have been
+/// (eg: `... [truncated, 41230 bytes total]`).
+///
+/// This is `32768` by default,
+/// and can be lowered with the `"max_panic_msg_len_*"` crate features,
+/// which reduce the stack usage of the [`concat_panic`](macro@concat_panic) macro
+/// when it's called at runtime
+/// (it uses a buffer of this many bytes, plus the stack usage described in
+/// [`concat_panic_with_capacity`'s docs](concat_panic_with_capacity#stack-usage)).
+/// The [`concat_panic_with_capacity`] function
+/// allows choosing the capacity of individual panic messages.
+///
+/// If multiple `"max_panic_msg_len_*"` features are enabled, the smallest one is used.
+pub const MAX_PANIC_MSG_LEN: usize = if cfg!(feature = "max_panic_msg_len_256") {
+    256
+} else if cfg!(feature = "max_panic_msg_len_1024") {
+    1024
+} else {
+    32768
+};
 
 // writes a single PanicVal to an array
 macro_rules! write_panicval {
     (
         $outer_label:lifetime,
-        $mout:ident, $lout:ident, $tct:expr,
+        $tct:expr,
         (
             $len:expr,
             $capacity:expr,
@@ -91,36 +158,141 @@ macro_rules! write_panicval {
             leftpad: mut lpad,
             rightpad: mut rpad,
             fmt_kind,
+            fill,
         } = strfmt;
-
-        let ranged = match class {
-            PanicClass::PreFmt(str) => str,
-            PanicClass::Int(int) => {
-                if int.len() <= string_cap::MEDIUM {
-                    $mout = int.fmt::<{ string_cap::MEDIUM }>();
-                    $mout.ranged()
-                } else {
-                    $lout = int.fmt::<{ string_cap::LARGE }>();
-                    $lout.ranged()
-                }
-            }
+        let (fill_bytes, fill_len) = char_to_utf8(fill.unwrap_or(' '));
+
+        let (ranged, bytes_fmt, number_len) = match class {
+            PanicClass::PreFmt(str) => (str, None, None),
+            PanicClass::Bytes(bytes, bytes_fmt) => (bytes, Some(bytes_fmt), None),
+            // numbers are written in chunks below
+            PanicClass::Int(int) => (RangedBytes::EMPTY, None, Some(int.len())),
+            PanicClass::Float(float) => (RangedBytes::EMPTY, None, Some(float.len())),
             #[cfg(feature = "non_basic")]
-            PanicClass::Slice(_) => unreachable!(),
+            PanicClass::Slice(_) | PanicClass::Nested(..) => unreachable!(),
         };
 
-        let trunc_end = ranged.start + was_truncated.get_length(ranged.len());
+        let (trunc_end, number_end) = match number_len {
+            Some(number_len) => (ranged.start, was_truncated.get_length(number_len)),
+            None => (ranged.start + was_truncated.get_length(ranged.len()), 0),
+        };
 
         while lpad != 0 {
-            $write_buffer! {b' '}
+            let mut i = 0;
+            while i < fill_len {
+                $write_buffer! {fill_bytes[i]}
+                i += 1;
+            }
             lpad -= 1;
         }
 
-        if let FmtKind::Display = fmt_kind {
+        if let Some(bytes_fmt) = bytes_fmt {
+            use crate::debug_str_fmt::{lower_hex_as_ascii, ForEscaping};
+
+            let is_quoted = matches!(bytes_fmt, BytesFmt::Escaped | BytesFmt::ByteStr);
+            if let BytesFmt::ByteStr = bytes_fmt {
+                $write_buffer_checked! {b'b'}
+            }
+            if is_quoted {
+                $write_buffer_checked! {b'"'}
+            }
+            #[cfg(feature = "non_basic")]
+            if let BytesFmt::HexRow = bytes_fmt {
+                $write_buffer_checked! {b':'}
+                $write_buffer_checked! {b' '}
+            }
+
+            let mut i = ranged.start;
+            while i < trunc_end {
+                let (seq_len, is_escaped) = bytes_sequence(ranged.bytes, i, ranged.end, bytes_fmt);
+                let mut j = i;
+                i += seq_len;
+
+                if !is_escaped {
+                    while j < i {
+                        $write_buffer! {ranged.bytes[j]}
+                        j += 1;
+                    }
+                    continue;
+                }
+
+                match bytes_fmt {
+                    BytesFmt::Lossy => {
+                        // U+FFFD REPLACEMENT CHARACTER
+                        $write_buffer! {0xEF}
+                        $write_buffer! {0xBF}
+                        $write_buffer! {0xBD}
+                    }
+                    BytesFmt::Escaped | BytesFmt::ByteStr => {
+                        while j < i {
+                            let c = ranged.bytes[j];
+                            let mut written_c = c;
+                            if ForEscaping::is_byte_str_escaped(c) {
+                                $write_buffer! {b'\\'}
+                                if ForEscaping::is_backslash_escaped(c) {
+                                    written_c = ForEscaping::get_backslash_escape(c);
+                                } else {
+                                    $write_buffer! {b'x'}
+                                    $write_buffer! {lower_hex_as_ascii(c >> 4)}
+                                    written_c = lower_hex_as_ascii(c & 0b1111);
+                                };
+                            }
+                            $write_buffer! {written_c}
+                            j += 1;
+                        }
+                    }
+                    #[cfg(feature = "non_basic")]
+                    BytesFmt::HexRow => {
+                        let c = ranged.bytes[j];
+                        $write_buffer! {lower_hex_as_ascii(c >> 4)}
+                        $write_buffer! {lower_hex_as_ascii(c & 0b1111)}
+                        if crate::fmt::hex_dump::hex_row_byte_len(j - ranged.start) == 3 {
+                            $write_buffer! {b' '}
+                        }
+                    }
+                    #[cfg(feature = "non_basic")]
+                    BytesFmt::AsciiRow => {
+                        let c = ranged.bytes[j];
+                        $write_buffer! {if c.is_ascii_graphic() || c == b' ' { c } else { b'.' }}
+                    }
+                }
+            }
+
+            if let WasTruncated::No = was_truncated {
+                if is_quoted {
+                    $write_buffer_checked! {b'"'}
+                }
+                #[cfg(feature = "non_basic")]
+                if let BytesFmt::HexRow = bytes_fmt {
+                    let mut padding = crate::fmt::hex_dump::hex_row_padding(ranged.len());
+                    while padding != 0 {
+                        $write_buffer_checked! {b' '}
+                        padding -= 1;
+                    }
+                }
+            }
+        } else if let FmtKind::Display = fmt_kind {
             let mut i = ranged.start;
             while i < trunc_end {
                 $write_buffer! {ranged.bytes[i]}
                 i += 1;
             }
+
+            let mut offset = 0;
+            while offset < number_end {
+                let chunk = match class {
+                    PanicClass::Int(int) => int.fmt_chunk(offset),
+                    PanicClass::Float(float) => float.fmt_chunk(offset),
+                    _ => unreachable!(),
+                };
+
+                let mut i = 0;
+                while i < chunk.len {
+                    $write_buffer! {chunk.buffer[i]}
+                    i += 1;
+                }
+                offset += chunk.len;
+            }
         } else if rem_space != 0 {
             $write_buffer! {b'"'}
             let mut i = 0;
@@ -149,7 +321,11 @@ macro_rules! write_panicval {
         }
 
         while rpad != 0 {
-            $write_buffer! {b' '}
+            let mut i = 0;
+            while i < fill_len {
+                $write_buffer! {fill_bytes[i]}
+                i += 1;
+            }
             rpad -= 1;
         }
 
@@ -175,13 +351,55 @@ macro_rules! write_to_buffer_inner {
     ) => {
         let mut args = $args;
 
-        let mut mout;
-        let mut lout;
+        // the `PanicVal`s after the nested `PanicVal`s that are being written,
+        // along with the depth limit of the enclosing nested `PanicVal`s
+        // (in separate arrays to avoid the padding of an array of tuples).
+        #[cfg(feature = "non_basic")]
+        let mut nesting_stack = [&[] as &[PanicVal<'_>]; crate::fmt::MAX_DEPTH_LIMIT as usize];
+        #[cfg(feature = "non_basic")]
+        let mut depth_limit_stack = [0u8; crate::fmt::MAX_DEPTH_LIMIT as usize];
+        #[cfg(feature = "non_basic")]
+        let mut nesting = 0usize;
+        #[cfg(feature = "non_basic")]
+        let mut depth_limit = crate::fmt::MAX_DEPTH_LIMIT;
 
         'outer: while let [mut outer, ref nargs @ ..] = args {
-            while let [arg, nouter @ ..] = outer {
+            loop {
+                let arg = match outer {
+                    [arg, nouter @ ..] => {
+                        outer = nouter;
+                        arg
+                    }
+                    #[cfg(feature = "non_basic")]
+                    [] if nesting != 0 => {
+                        nesting -= 1;
+                        outer = nesting_stack[nesting];
+                        depth_limit = depth_limit_stack[nesting];
+                        continue;
+                    }
+                    [] => break,
+                };
+
                 let tct = arg.to_class_truncated($capacity - $len);
                 match tct.1 {
+                    #[cfg(feature = "non_basic")]
+                    PanicClass::Nested(pvs, ndepth_limit) => {
+                        let ndepth_limit =
+                            crate::utils::min_usize(ndepth_limit as usize, depth_limit as usize)
+                                as u8;
+
+                        if ndepth_limit == 0 {
+                            let ellipsis = PanicVal::write_str("...");
+                            let tct = ellipsis.to_class_truncated($capacity - $len);
+                            write_panicval! {'outer, tct, $wptb_args}
+                        } else {
+                            nesting_stack[nesting] = outer;
+                            depth_limit_stack[nesting] = depth_limit;
+                            nesting += 1;
+                            outer = pvs;
+                            depth_limit = ndepth_limit - 1;
+                        }
+                    }
                     #[cfg(feature = "non_basic")]
                     #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
                     PanicClass::Slice(slice) => {
@@ -193,7 +411,7 @@ macro_rules! write_to_buffer_inner {
                             let mut two_args: &[_] = &two_args;
                             while let [arg, ntwo_args @ ..] = two_args {
                                 let tct = arg.to_class_truncated($capacity - $len);
-                                write_panicval! {'outer, mout, lout, tct, $wptb_args}
+                                write_panicval! {'outer, tct, $wptb_args}
                                 two_args = ntwo_args;
                             }
 
@@ -204,11 +422,9 @@ macro_rules! write_to_buffer_inner {
                         }
                     }
                     _ => {
-                        write_panicval! {'outer, mout, lout, tct, $wptb_args}
+                        write_panicval! {'outer, tct, $wptb_args}
                     }
                 }
-
-                outer = nouter;
             }
             args = nargs;
         }
@@ -240,10 +456,65 @@ macro_rules! make_buffer_writer_macros {
     };
 }
 
+// The text around the total length in the marker that's written at the end of
+// truncated messages, eg: `... [truncated, 41230 bytes total]`
+const TRUNCATION_PREFIX: &str = "... [truncated, ";
+const TRUNCATION_SUFFIX: &str = " bytes total]";
+
+// Replaces the end of a message that was truncated to `$capacity` bytes
+// with a marker that says how long the message would have been.
+//
+// The marker isn't written if it's longer than `$capacity`.
+macro_rules! write_truncation_marker {
+    ($args:ident, $buffer:ident, $len:ident, $capacity:expr, $write_buffer:ident) => {
+        let total_len = compute_length($args);
+
+        if total_len > $len {
+            let mut digit_len = 1;
+            let mut power = 1usize;
+            while total_len / power >= 10 {
+                power *= 10;
+                digit_len += 1;
+            }
+
+            let marker_len = TRUNCATION_PREFIX.len() + digit_len + TRUNCATION_SUFFIX.len();
+
+            if marker_len <= $capacity {
+                $len = crate::utils::min_usize($len, $capacity - marker_len);
+
+                // not splitting a utf8-encoded char
+                while $len != 0 && $buffer[$len] & 0b1100_0000 == 0b1000_0000 {
+                    $len -= 1;
+                }
+
+                let mut i = 0;
+                while i < TRUNCATION_PREFIX.len() {
+                    $write_buffer! {TRUNCATION_PREFIX.as_bytes()[i]}
+                    i += 1;
+                }
+
+                while power != 0 {
+                    $write_buffer! {b'0' + (total_len / power % 10) as u8}
+                    power /= 10;
+                }
+
+                let mut i = 0;
+                while i < TRUNCATION_SUFFIX.len() {
+                    $write_buffer! {TRUNCATION_SUFFIX.as_bytes()[i]}
+                    i += 1;
+                }
+            }
+        }
+    };
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
-const fn panic_inner<T, const LEN: usize>(args: &[&[PanicVal<'_>]]) -> Result<T, NotEnoughSpace> {
+const fn panic_inner<T, const LEN: usize>(
+    args: &[&[PanicVal<'_>]],
+    max_capacity: usize,
+) -> Result<T, NotEnoughSpace> {
     let mut buffer = [0u8; LEN];
     let mut len = 0usize;
 
@@ -252,11 +523,16 @@ const fn panic_inner<T, const LEN: usize>(args: &[&[PanicVal<'_>]]) -> Result<T,
     write_to_buffer! {
         args
         (
-            len, LEN, MAX_PANIC_MSG_LEN, Err(NotEnoughSpace),
+            len, LEN, max_capacity, Err(NotEnoughSpace),
             write_buffer, write_buffer_checked,
         )
     }
 
+    // only the last attempt at writing the message can be truncated
+    if LEN == max_capacity {
+        write_truncation_marker! {args, buffer, len, LEN, write_buffer}
+    }
+
     unsafe {
         let buffer = bytes_up_to(&buffer, len);
         let str = core::str::from_utf8_unchecked(buffer);
@@ -322,6 +598,32 @@ pub(crate) const fn make_panic_string<const LEN: usize>(
     })
 }
 
+#[cfg(feature = "non_basic")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[doc(hidden)]
+pub(crate) const fn make_panic_string_lossy<const LEN: usize>(
+    args: &[&[PanicVal<'_>]],
+) -> Result<crate::ArrayString<LEN>, NotEnoughSpace> {
+    let mut buffer = [0u8; LEN];
+    let mut len = 0usize;
+
+    make_buffer_writer_macros! {buffer, len}
+
+    write_to_buffer! {
+        args
+        (len, LEN, LEN, Err(NotEnoughSpace), write_buffer, write_buffer_checked,)
+    }
+
+    write_truncation_marker! {args, buffer, len, LEN, write_buffer}
+
+    assert!(len as u32 as usize == len, "the panic message is too large");
+
+    Ok(crate::ArrayString {
+        buffer,
+        len: len as u32,
+    })
+}
+
 #[cfg(feature = "non_basic")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
 #[doc(hidden)]
@@ -335,8 +637,68 @@ pub const fn make_panic_string_unwrapped<const LEN: usize>(
     }
 }
 
-#[cfg(feature = "non_basic")]
-#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+// Writes the args to a `Formatter`, producing the same bytes as `panic_inner`,
+// without a length limit.
+pub(crate) fn fmt_panicvals(
+    args: &[&[PanicVal<'_>]],
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    // the amount of bytes written so far, used by `write_to_buffer` to compute remaining space
+    let mut len = 0usize;
+
+    // the bytes are written to the `Formatter` in chunks,
+    // splitting them only between utf8-encoded chars.
+    let mut chunk = [0u8; 64];
+    let mut chunk_len = 0usize;
+
+    macro_rules! write_chunk {
+        ($upto:expr) => {
+            match core::str::from_utf8(&chunk[..$upto]) {
+                Ok(str) => f.write_str(str)?,
+                Err(_) => return Err(core::fmt::Error),
+            }
+        };
+    }
+
+    macro_rules! write_byte {
+        ($value:expr) => {{
+            let byte: u8 = $value;
+            len += 1;
+
+            if chunk_len == chunk.len() {
+                // the start of the last char in the chunk,
+                // only used if `byte` continues that char.
+                let mut split = chunk_len;
+                if byte & 0b1100_0000 == 0b1000_0000 {
+                    while split != 0 && chunk[split - 1] & 0b1100_0000 == 0b1000_0000 {
+                        split -= 1;
+                    }
+                    split = split.saturating_sub(1);
+                }
+
+                write_chunk! {split}
+                chunk.copy_within(split..chunk_len, 0);
+                chunk_len -= split;
+            }
+
+            chunk[chunk_len] = byte;
+            chunk_len += 1;
+        }};
+    }
+
+    write_to_buffer! {
+        args
+        (
+            len, usize::MAX - 1, usize::MAX, Ok(()),
+            write_byte, write_byte,
+        )
+    }
+
+    write_chunk! {chunk_len}
+
+    Ok(())
+}
+
 #[doc(hidden)]
 pub const fn compute_length(args: &[&[PanicVal<'_>]]) -> usize {
     let mut len = 0usize;
@@ -0,0 +1,127 @@
+// The `const_eq` methods that the `concat_assert_eq` and `concat_assert_ne` macros
+// use to compare standard library types.
+//
+// User-defined types are compared with an inherent
+// `const fn const_eq(&self, other: &Self) -> bool` method.
+
+use crate::StdWrapper;
+
+macro_rules! impl_const_eq_prim {
+    ($($ty:ty)*) => {
+        $(
+            impl StdWrapper<&$ty> {
+                /// Compares `self` and `other` for equality.
+                ///
+                /// This is used by the [`concat_assert_eq`] and [`concat_assert_ne`] macros.
+                ///
+                /// [`concat_assert_eq`]: crate::concat_assert_eq
+                /// [`concat_assert_ne`]: crate::concat_assert_ne
+                pub const fn const_eq(self, other: &$ty) -> bool {
+                    *self.0 == *other
+                }
+            }
+        )*
+    };
+}
+
+impl_const_eq_prim! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    bool char
+}
+
+impl StdWrapper<&str> {
+    /// Compares `self` and `other` for equality.
+    ///
+    /// This is used by the [`concat_assert_eq`] and [`concat_assert_ne`] macros.
+    ///
+    /// [`concat_assert_eq`]: crate::concat_assert_eq
+    /// [`concat_assert_ne`]: crate::concat_assert_ne
+    pub const fn const_eq(self, other: &str) -> bool {
+        str_eq(self.0, other)
+    }
+}
+
+const fn str_eq(left: &str, right: &str) -> bool {
+    slice_eq_u8(left.as_bytes(), right.as_bytes())
+}
+
+const fn slice_eq_u8(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(feature = "non_basic")]
+macro_rules! impl_const_eq_slice {
+    ($(($ty:ty, |$l:ident, $r:ident| $elem_eq:expr))*) => {
+        $(
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s> StdWrapper<&[$ty]> {
+                /// Compares `self` and `other` for equality.
+                ///
+                /// This is used by the [`concat_assert_eq`] and [`concat_assert_ne`] macros.
+                ///
+                /// [`concat_assert_eq`]: crate::concat_assert_eq
+                /// [`concat_assert_ne`]: crate::concat_assert_ne
+                pub const fn const_eq(self, other: &[$ty]) -> bool {
+                    let left = self.0;
+                    if left.len() != other.len() {
+                        return false;
+                    }
+
+                    let mut i = 0;
+                    while i < left.len() {
+                        let ($l, $r) = (&left[i], &other[i]);
+                        if !$elem_eq {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s, const LEN: usize> StdWrapper<&[$ty; LEN]> {
+                /// Compares `self` and `other` for equality.
+                ///
+                /// This is used by the [`concat_assert_eq`] and [`concat_assert_ne`] macros.
+                ///
+                /// [`concat_assert_eq`]: crate::concat_assert_eq
+                /// [`concat_assert_ne`]: crate::concat_assert_ne
+                pub const fn const_eq(self, other: &[$ty; LEN]) -> bool {
+                    StdWrapper::<&[$ty]>(self.0).const_eq(other)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "non_basic")]
+impl_const_eq_slice! {
+    (u8, |l, r| *l == *r)
+    (u16, |l, r| *l == *r)
+    (u32, |l, r| *l == *r)
+    (u64, |l, r| *l == *r)
+    (u128, |l, r| *l == *r)
+    (usize, |l, r| *l == *r)
+    (i8, |l, r| *l == *r)
+    (i16, |l, r| *l == *r)
+    (i32, |l, r| *l == *r)
+    (i64, |l, r| *l == *r)
+    (i128, |l, r| *l == *r)
+    (isize, |l, r| *l == *r)
+    (bool, |l, r| *l == *r)
+    (char, |l, r| *l == *r)
+    (&'s str, |l, r| str_eq(l, r))
+}
@@ -33,6 +33,21 @@ impl ForEscaping {
         }
     }
 
+    // how long this byte takes to represent in a byte string,
+    // where all non-ascii bytes and `DEL` are escaped like `\xNN`.
+    pub(crate) const fn byte_str_len(c: u8) -> usize {
+        if c < 0x7F {
+            Self::byte_len(c)
+        } else {
+            4
+        }
+    }
+
+    // whether this byte is escaped in byte strings.
+    pub(crate) const fn is_byte_str_escaped(c: u8) -> bool {
+        c >= 0x7F || Self::is_escaped(c)
+    }
+
     pub(crate) const fn is_escaped(c: u8) -> bool {
         (c < 128) && ((FOR_ESCAPING.is_escaped & (1 << c)) != 0)
     }
@@ -53,6 +68,16 @@ pub(crate) const fn hex_as_ascii(n: u8) -> u8 {
     }
 }
 
+/// Converts 0..=0xF to its ascii representation of '0'..='9' and 'a'..='f'
+#[inline(always)]
+pub(crate) const fn lower_hex_as_ascii(n: u8) -> u8 {
+    if n < 10 {
+        n + b'0'
+    } else {
+        n - 10 + b'a'
+    }
+}
+
 #[doc(hidden)]
 pub(crate) const FOR_ESCAPING: &ForEscaping = {
     let mut is_backslash_escaped = 0;
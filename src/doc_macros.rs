@@ -25,6 +25,32 @@ alternate-`Debug` formats the argument, with binary-formatted numbers.
 `Debug` formats the argument, with hexadecimal-formatted numbers.
 - `alt_hex:` or `{#X}:`:
 alternate-`Debug` formats the argument, with hexadecimal-formatted numbers.
+- `lower_hex:` or `{x}:`:
+`Debug` formats the argument, with lowercase hexadecimal-formatted numbers.
+- `alt_lower_hex:` or `{#x}:`:
+alternate-`Debug` formats the argument, with lowercase hexadecimal-formatted numbers.
+- `oct:` or `{o}:`: `Debug` formats the argument, with octal-formatted numbers.
+- `alt_oct:` or `{#o}:`:
+alternate-`Debug` formats the argument, with octal-formatted numbers.
+- `byte_str:`: `Debug` formats the argument,
+with `u8` slices and arrays formatted as byte string literals (eg: `b"foo\x00"`).
+- `{radix = N}:`:
+`Debug` formats the argument, with numbers formatted in the `N` radix (from 2 to 36).
+- `{[[fill]align][+][#][0][width][grouping][.precision][type]}:`:
+formats the argument like the equivalent `{:...}` std formatting specifier,
+eg: `{>8}:`, `{*^10}:`, `{08}:`, `{.3}:`, `{+}:`, `{#010x}:`.
+`type` can be nothing (`Display`), `?`, `x`, `X`, `o`, `b`, `x?`, or `X?`.
+`grouping` can be `_` or `,`, which separate the digits of integers into groups
+(eg: `{_}:` writes `1_000_000`, `{#_X}:` writes `0xDEAD_BEEF`),
+this has no std equivalent.
+Whitespace is ignored, so a space can't be used as the `fill` (it's the default fill),
+and `:` can't be used as the `fill` either, to catch the `{:>8}:` typo at compile-time.
+This override replaces the width, fill, alignment,
+zero-padding, precision, sign, and digit grouping of the `$fmtarg` argument,
+unless it's one of the `{...}:` overrides listed above (eg: `{}:`, `{#x}:`).
+
+All of the other formatting overrides keep the width, fill, alignment,
+zero-padding, precision, sign, and digit grouping of the `$fmtarg` argument.
 "##,
 $($additional_fmt_overrides,)?
 r##"
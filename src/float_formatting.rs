@@ -0,0 +1,785 @@
+use crate::{
+    fmt::{Alignment, FmtArg, FmtKind},
+    panic_val::{PanicVal, PanicVariant, StrFmt},
+    utils::{string_cap, NumberChunk, Packed, PreFmtString, Sign, StartAndBytes},
+};
+
+use core::cmp::Ordering;
+
+#[derive(Copy, Clone)]
+enum FloatCategory {
+    Nan,
+    Infinite,
+    Zero,
+    Finite,
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct FloatVal {
+    sign: Sign,
+    category: FloatCategory,
+    fmt_kind: FmtKind,
+    sign_plus: bool,
+    // the amount of digits written after the decimal point,
+    // `None` means that the shortest roundtripping digits are written.
+    precision: Option<u8>,
+    // the amount of zeroes written between the sign and the number
+    zeros: u8,
+    // the amount of digits in `digits`
+    digit_count: u8,
+    // the length of the float in bytes, once written.
+    len: Packed<u16>,
+    // if `precision` is `None`: the float is `0.<digits> * 10^exponent`
+    // if `precision` is `Some`: the float is `digits * 2^exponent`
+    exponent: Packed<i16>,
+    digits: Packed<u64>,
+}
+
+impl FloatVal {
+    pub(crate) const fn from_f32(n: f32, fmtarg: FmtArg) -> PanicVal<'static> {
+        // `f32::to_bits` isn't const until Rust 1.83
+        union Bits {
+            float: f32,
+            bits: u32,
+        }
+        // SAFETY: f32 and u32 have the same size, and every bit pattern is a valid u32
+        let bits = unsafe { Bits { float: n }.bits };
+        Self::from_bits(bits as u64, 23, 8, fmtarg)
+    }
+
+    pub(crate) const fn from_f64(n: f64, fmtarg: FmtArg) -> PanicVal<'static> {
+        union Bits {
+            float: f64,
+            bits: u64,
+        }
+        // SAFETY: f64 and u64 have the same size, and every bit pattern is a valid u64
+        let bits = unsafe { Bits { float: n }.bits };
+        Self::from_bits(bits, 52, 11, fmtarg)
+    }
+
+    const fn from_bits(
+        bits: u64,
+        mantissa_bits: u32,
+        exponent_bits: u32,
+        fmtarg: FmtArg,
+    ) -> PanicVal<'static> {
+        let sign = if (bits >> (mantissa_bits + exponent_bits)) & 1 == 1 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        let max_biased_exp = (1u64 << exponent_bits) - 1;
+        let biased_exp = ((bits >> mantissa_bits) & max_biased_exp) as i32;
+        let fraction = bits & ((1u64 << mantissa_bits) - 1);
+        // the bias of the exponent when the mantissa is treated as an integer
+        let bias = (max_biased_exp >> 1) as i32 + mantissa_bits as i32;
+
+        // the float is `mantissa * 2^exp2`
+        let (category, mantissa, exp2, uneven_gaps) = if biased_exp == max_biased_exp as i32 {
+            if fraction == 0 {
+                (FloatCategory::Infinite, 0, 0, false)
+            } else {
+                (FloatCategory::Nan, 0, 0, false)
+            }
+        } else if biased_exp == 0 {
+            if fraction == 0 {
+                (FloatCategory::Zero, 0, 0, false)
+            } else {
+                (FloatCategory::Finite, fraction, 1 - bias, false)
+            }
+        } else {
+            let mantissa = fraction | (1 << mantissa_bits);
+            let uneven_gaps = fraction == 0 && biased_exp > 1;
+            (
+                FloatCategory::Finite,
+                mantissa,
+                biased_exp - bias,
+                uneven_gaps,
+            )
+        };
+
+        let (digits, digit_count, exponent) = match (fmtarg.precision(), category) {
+            (Some(_), _) => (mantissa, 0, exp2 as i16),
+            (None, FloatCategory::Finite) => {
+                let decimal = shortest_digits(mantissa, exp2, uneven_gaps);
+                (decimal.digits, decimal.digit_count, decimal.exponent)
+            }
+            (None, _) => (0, 0, 0),
+        };
+
+        let mut this = FloatVal {
+            sign,
+            category,
+            fmt_kind: fmtarg.fmt_kind,
+            sign_plus: fmtarg.sign_plus(),
+            precision: fmtarg.precision(),
+            zeros: 0,
+            digit_count,
+            len: Packed(0),
+            exponent: Packed(exponent),
+            digits: Packed(digits),
+        };
+
+        let unpadded_len = this.compute_len();
+        if fmtarg.zero_pad() && fmtarg.width() as u16 > unpadded_len {
+            this.zeros = (fmtarg.width() as u16 - unpadded_len) as u8;
+        }
+        this.len = Packed(unpadded_len + this.zeros as u16);
+
+        let strfmt = StrFmt::padded(fmtarg, FmtKind::Display, this.len(), Alignment::Right);
+
+        let var = if this.len() <= string_cap::PREFMT {
+            let chunk = this.fmt_chunk(0);
+            let StartAndBytes { start, bytes } =
+                crate::utils::tail_byte_array::<{ string_cap::PREFMT }>(this.len(), &chunk.buffer);
+
+            // SAFETY: the entire float is in the chunk, and FloatVal::fmt_chunk only writes ascii
+            PanicVariant::PreFmt(strfmt, unsafe { PreFmtString::new(start as u16, bytes) })
+        } else {
+            PanicVariant::Float(strfmt, this)
+        };
+
+        PanicVal::__new(var)
+    }
+
+    // Whether this is written in scientific notation,
+    // std only does this for Debug formatting of numbers
+    // outside of the `1e-4 <= abs(n) < 1e16` range.
+    const fn is_exponential(&self) -> bool {
+        let Packed(exponent) = self.exponent;
+
+        matches!(self.fmt_kind, FmtKind::Debug)
+            && self.precision.is_none()
+            && matches!(self.category, FloatCategory::Finite)
+            && (exponent > 16 || exponent < -3)
+    }
+
+    // the sign written before the number, NaN is never written with a sign
+    const fn sign_char(&self) -> Option<u8> {
+        match (self.sign, self.category, self.sign_plus) {
+            (_, FloatCategory::Nan, _) => None,
+            (Sign::Negative, _, _) => Some(b'-'),
+            (Sign::Positive, _, true) => Some(b'+'),
+            (Sign::Positive, _, false) => None,
+        }
+    }
+
+    const fn compute_len(&self) -> u16 {
+        let sign_len = self.sign_char().is_some() as i32;
+        let is_debug = matches!(self.fmt_kind, FmtKind::Debug);
+        let digit_count = self.digit_count as i32;
+        let Packed(exponent) = self.exponent;
+        let exponent = exponent as i32;
+
+        let len = match (self.precision, self.category) {
+            (_, FloatCategory::Nan | FloatCategory::Infinite) => 3,
+            (Some(precision), _) => {
+                let (_, start) = self.fixed_digits(precision);
+                let digit_len = (string_cap::FLOAT - start) as i32;
+                let precision = precision as i32;
+
+                let int_len = if digit_len > precision {
+                    digit_len - precision
+                } else {
+                    1
+                };
+                int_len + if precision != 0 { 1 + precision } else { 0 }
+            }
+            (None, FloatCategory::Zero) if is_debug => 3,
+            (None, FloatCategory::Zero) => 1,
+            (None, FloatCategory::Finite) if self.is_exponential() => {
+                let exp = exponent - 1;
+                let exp_len = if exp < 0 { 1 } else { 0 } + decimal_len(exp.unsigned_abs());
+
+                digit_count + (digit_count > 1) as i32 + 1 + exp_len
+            }
+            (None, FloatCategory::Finite) if exponent <= 0 => 2 - exponent + digit_count,
+            (None, FloatCategory::Finite) if exponent < digit_count => digit_count + 1,
+            (None, FloatCategory::Finite) => exponent + if is_debug { 2 } else { 0 },
+        };
+
+        (sign_len + len) as u16
+    }
+
+    // the bytes of this formatted float starting from `offset`,
+    // at most `string_cap::NUMBER_CHUNK` of them.
+    pub(crate) const fn fmt_chunk(self, offset: usize) -> NumberChunk {
+        let mut chunk = NumberChunk::new(offset, self.len());
+        // the index of the next written byte
+        let mut pos = 0usize;
+
+        macro_rules! write_byte {
+            ($byte:expr) => {{
+                if chunk.contains(pos) {
+                    chunk.buffer[pos - chunk.offset] = $byte;
+                }
+                pos += 1;
+            }};
+        }
+
+        macro_rules! write_str {
+            ($str:expr) => {{
+                let str: &[u8] = $str;
+                let mut i = 0;
+                while i < str.len() {
+                    write_byte! {str[i]}
+                    i += 1;
+                }
+            }};
+        }
+        macro_rules! write_zeros {
+            ($count:expr) => {{
+                let mut count = $count;
+                while count > 0 {
+                    write_byte! {b'0'}
+                    count -= 1;
+                }
+            }};
+        }
+
+        if let Some(sign) = self.sign_char() {
+            write_byte! {sign}
+        }
+
+        write_zeros!(self.zeros);
+
+        let is_debug = matches!(self.fmt_kind, FmtKind::Debug);
+        let (digit_arr, digit_count) = self.digit_array();
+        let Packed(exponent) = self.exponent;
+        let exponent = exponent as i32;
+
+        match (self.precision, self.category) {
+            (_, FloatCategory::Nan) => write_str!(b"NaN"),
+            (_, FloatCategory::Infinite) => write_str!(b"inf"),
+            (Some(precision), _) => {
+                let (fixed_arr, start) = self.fixed_digits(precision);
+                let digit_len = string_cap::FLOAT - start;
+                let precision = precision as usize;
+
+                let mut i = start;
+                if digit_len <= precision {
+                    write_byte! {b'0'}
+                } else {
+                    while i < string_cap::FLOAT - precision {
+                        write_byte! {fixed_arr[i]}
+                        i += 1;
+                    }
+                }
+
+                if precision != 0 {
+                    write_byte! {b'.'}
+                    if digit_len < precision {
+                        write_zeros!(precision - digit_len);
+                    }
+                    while i < string_cap::FLOAT {
+                        write_byte! {fixed_arr[i]}
+                        i += 1;
+                    }
+                }
+            }
+            (None, FloatCategory::Zero) if is_debug => write_str!(b"0.0"),
+            (None, FloatCategory::Zero) => write_str!(b"0"),
+            (None, FloatCategory::Finite) if self.is_exponential() => {
+                write_byte! {digit_arr[0]}
+                if digit_count > 1 {
+                    write_byte! {b'.'}
+                    let mut i = 1;
+                    while i < digit_count {
+                        write_byte! {digit_arr[i]}
+                        i += 1;
+                    }
+                }
+                write_byte! {b'e'}
+
+                let exp = exponent - 1;
+                if exp < 0 {
+                    write_byte! {b'-'}
+                }
+                let (exp_arr, exp_len) = u64_digits(exp.unsigned_abs() as u64);
+                let mut i = 0;
+                while i < exp_len {
+                    write_byte! {exp_arr[i]}
+                    i += 1;
+                }
+            }
+            (None, FloatCategory::Finite) => {
+                let int_len = if exponent > 0 { exponent as usize } else { 0 };
+
+                if int_len == 0 {
+                    write_byte! {b'0'}
+                }
+
+                let mut i = 0;
+                while i < digit_count && i < int_len {
+                    write_byte! {digit_arr[i]}
+                    i += 1;
+                }
+
+                if int_len >= digit_count {
+                    write_zeros!(int_len - digit_count);
+                    if is_debug {
+                        write_str!(b".0");
+                    }
+                } else {
+                    write_byte! {b'.'}
+                    if exponent < 0 {
+                        write_zeros!(exponent.unsigned_abs());
+                    }
+                    while i < digit_count {
+                        write_byte! {digit_arr[i]}
+                        i += 1;
+                    }
+                }
+            }
+        }
+
+        chunk
+    }
+
+    const fn digit_array(&self) -> ([u8; 20], usize) {
+        let Packed(digits) = self.digits;
+        let (arr, len) = u64_digits(digits);
+
+        if let FloatCategory::Finite = self.category {
+            (arr, len)
+        } else {
+            (arr, 0)
+        }
+    }
+
+    // The decimal digits of this float multiplied by `10^precision`,
+    // rounded to the nearest integer (with ties rounding to even),
+    // returned as the digits in `array[start..]`, along with `start`.
+    const fn fixed_digits(&self, precision: u8) -> ([u8; string_cap::FLOAT], usize) {
+        let Packed(mantissa) = self.digits;
+        let Packed(exp2) = self.exponent;
+        let one = BigNum::from_u64(1);
+
+        let scaled = BigNum::from_u64(mantissa).mul_pow10(precision as u32);
+        let mut n = if exp2 >= 0 {
+            scaled.mul_pow2(exp2 as u32)
+        } else {
+            let shift = exp2.unsigned_abs() as u32;
+            let quotient = scaled.div_pow2(shift);
+            let rem = scaled.sub(&quotient.mul_pow2(shift));
+            let round_up = match rem.cmp(&one.mul_pow2(shift - 1)) {
+                Ordering::Less => false,
+                Ordering::Equal => quotient.is_odd(),
+                Ordering::Greater => true,
+            };
+
+            if round_up {
+                quotient.add(&one)
+            } else {
+                quotient
+            }
+        };
+
+        let mut array = [0u8; string_cap::FLOAT];
+        let mut start = string_cap::FLOAT;
+
+        // writes the digits in chunks of 9, starting from the least significant one
+        loop {
+            let (quotient, mut chunk) = n.div_small(1_000_000_000);
+            n = quotient;
+
+            let mut i = 0;
+            while i < 9 {
+                start -= 1;
+                array[start] = b'0' + (chunk % 10) as u8;
+                chunk /= 10;
+                i += 1;
+
+                if chunk == 0 && n.len == 0 {
+                    break;
+                }
+            }
+
+            if n.len == 0 {
+                break;
+            }
+        }
+
+        (array, start)
+    }
+
+    pub(crate) const fn len(&self) -> usize {
+        let Packed(len) = self.len;
+        len as usize
+    }
+}
+
+// returns the ascii digits of `n`, along with how many there are
+const fn u64_digits(mut n: u64) -> ([u8; 20], usize) {
+    let mut arr = [0u8; 20];
+
+    let mut len = 1;
+    let mut m = n;
+    while m >= 10 {
+        len += 1;
+        m /= 10;
+    }
+
+    let mut i = len;
+    while i != 0 {
+        i -= 1;
+        arr[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+
+    (arr, len)
+}
+
+const fn decimal_len(mut n: u32) -> i32 {
+    let mut len = 1;
+    while n >= 10 {
+        len += 1;
+        n /= 10;
+    }
+    len
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// The shortest decimal representation that roundtrips to a float,
+// the represented number is `0.<digits> * 10^exponent`
+#[derive(Copy, Clone)]
+struct Decimal {
+    digits: u64,
+    digit_count: u8,
+    exponent: i16,
+}
+
+// Computes the shortest decimal digits that round-trip to `mantissa * 2^exp2`,
+// using the free-format algorithm from
+// "Printing Floating-Point Numbers Quickly and Accurately" by Burger and Dybvig.
+//
+// `uneven_gaps` must be true if the float below is closer than the float above,
+// which happens for powers of two (excluding the smallest normal float).
+const fn shortest_digits(mantissa: u64, exp2: i32, uneven_gaps: bool) -> Decimal {
+    // the float rounds to even, so the boundaries round to it when the mantissa is even
+    let boundaries_inclusive = mantissa % 2 == 0;
+
+    // v = r / s
+    // the upper boundary is (r + mplus) / s
+    // the lower boundary is (r - mminus) / s
+    let (mut r, mut s, mut mplus, mut mminus);
+    let mantissa_n = BigNum::from_u64(mantissa);
+    let one = BigNum::from_u64(1);
+
+    if exp2 >= 0 {
+        let exp2 = exp2 as u32;
+        if uneven_gaps {
+            r = mantissa_n.mul_pow2(exp2 + 2);
+            s = BigNum::from_u64(4);
+            mplus = one.mul_pow2(exp2 + 1);
+            mminus = one.mul_pow2(exp2);
+        } else {
+            r = mantissa_n.mul_pow2(exp2 + 1);
+            s = BigNum::from_u64(2);
+            mplus = one.mul_pow2(exp2);
+            mminus = mplus;
+        }
+    } else {
+        let nexp2 = exp2.unsigned_abs();
+        if uneven_gaps {
+            r = mantissa_n.mul_pow2(2);
+            s = one.mul_pow2(nexp2 + 2);
+            mplus = BigNum::from_u64(2);
+            mminus = one;
+        } else {
+            r = mantissa_n.mul_pow2(1);
+            s = one.mul_pow2(nexp2 + 1);
+            mplus = one;
+            mminus = one;
+        }
+    }
+
+    // estimate of ceil(log10(v)), which is either exact or too small.
+    let floor_log2 = exp2 + 63 - mantissa.leading_zeros() as i32;
+    let mut exponent = if floor_log2 == 0 {
+        0
+    } else {
+        // floor(floor_log2 * log10(2)) + 1,
+        // this approximation of floor is exact for floor_log2 in -1650..=1650
+        ((floor_log2 * 78913) >> 18) + 1
+    };
+
+    if exponent >= 0 {
+        s = s.mul_pow10(exponent as u32);
+    } else {
+        let nexp = exponent.unsigned_abs();
+        r = r.mul_pow10(nexp);
+        mplus = mplus.mul_pow10(nexp);
+        mminus = mminus.mul_pow10(nexp);
+    }
+
+    // fixes the estimate so that the upper boundary is below `10^exponent`
+    while reaches_upper(&r, &mplus, &s, boundaries_inclusive) {
+        s = s.mul_small(10);
+        exponent += 1;
+    }
+
+    let mut digits = 0u64;
+    loop {
+        r = r.mul_small(10);
+        mplus = mplus.mul_small(10);
+        mminus = mminus.mul_small(10);
+
+        let mut digit = 0u64;
+        while !r.cmp(&s).is_lt() {
+            r = r.sub(&s);
+            digit += 1;
+        }
+
+        let low = match r.cmp(&mminus) {
+            Ordering::Less => true,
+            Ordering::Equal => boundaries_inclusive,
+            Ordering::Greater => false,
+        };
+        let high = reaches_upper(&r, &mplus, &s, boundaries_inclusive);
+
+        exponent -= 1;
+        digits *= 10;
+        digits += match (low, high) {
+            (false, false) => {
+                digits += digit;
+                continue;
+            }
+            (true, false) => digit,
+            (false, true) => digit + 1,
+            (true, true) if r.mul_small(2).cmp(&s).is_lt() => digit,
+            (true, true) => digit + 1,
+        };
+        break;
+    }
+
+    // `exponent` is now the exponent of the last digit,
+    // removing trailing zeroes (which can be produced by rounding up)
+    while digits % 10 == 0 {
+        digits /= 10;
+        exponent += 1;
+    }
+
+    let (_, digit_count) = u64_digits(digits);
+
+    Decimal {
+        digits,
+        digit_count: digit_count as u8,
+        exponent: (exponent + digit_count as i32) as i16,
+    }
+}
+
+const fn reaches_upper(r: &BigNum, mplus: &BigNum, s: &BigNum, inclusive: bool) -> bool {
+    match r.add(mplus).cmp(s) {
+        Ordering::Less => false,
+        Ordering::Equal => inclusive,
+        Ordering::Greater => true,
+    }
+}
+
+// enough limbs for the largest intermediate values when formatting f64,
+// which are a bit under 1880 bits long
+// (the largest f64 multiplied by `10^255`, when formatted with that precision).
+const LIMBS: usize = 64;
+
+// An arbitrary-precision unsigned integer
+#[derive(Copy, Clone)]
+struct BigNum {
+    // little-endian digits in base 2^32
+    limbs: [u32; LIMBS],
+    // the amount of limbs in use
+    len: usize,
+}
+
+impl BigNum {
+    const fn from_u64(n: u64) -> Self {
+        let mut limbs = [0u32; LIMBS];
+        limbs[0] = n as u32;
+        limbs[1] = (n >> 32) as u32;
+        Self { limbs, len: 2 }.normalized()
+    }
+
+    const fn normalized(mut self) -> Self {
+        while self.len != 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+        self
+    }
+
+    const fn mul_small(mut self, m: u32) -> Self {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < self.len {
+            let x = self.limbs[i] as u64 * m as u64 + carry;
+            self.limbs[i] = x as u32;
+            carry = x >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn mul_pow2(mut self, n: u32) -> Self {
+        let limb_shift = (n / 32) as usize;
+        let bit_shift = n % 32;
+
+        if bit_shift != 0 {
+            let mut carry = 0u32;
+            let mut i = 0;
+            while i < self.len {
+                let x = self.limbs[i];
+                self.limbs[i] = (x << bit_shift) | carry;
+                carry = x >> (32 - bit_shift);
+                i += 1;
+            }
+            if carry != 0 {
+                self.limbs[self.len] = carry;
+                self.len += 1;
+            }
+        }
+
+        if limb_shift != 0 && self.len != 0 {
+            let mut i = self.len;
+            while i != 0 {
+                i -= 1;
+                self.limbs[i + limb_shift] = self.limbs[i];
+            }
+            while i < limb_shift {
+                self.limbs[i] = 0;
+                i += 1;
+            }
+            self.len += limb_shift;
+        }
+
+        self
+    }
+
+    const fn mul_pow10(mut self, mut n: u32) -> Self {
+        while n >= 9 {
+            self = self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(10u32.pow(n))
+    }
+
+    // divides by `2^n`, rounding down
+    const fn div_pow2(mut self, n: u32) -> Self {
+        let limb_shift = (n / 32) as usize;
+        let bit_shift = n % 32;
+
+        if limb_shift >= self.len {
+            return Self {
+                limbs: [0; LIMBS],
+                len: 0,
+            };
+        }
+
+        let mut i = 0;
+        while i < self.len {
+            self.limbs[i] = if i + limb_shift < self.len {
+                self.limbs[i + limb_shift]
+            } else {
+                0
+            };
+            i += 1;
+        }
+        self.len -= limb_shift;
+
+        if bit_shift != 0 {
+            i = 0;
+            while i < self.len {
+                let high = if i + 1 < self.len {
+                    self.limbs[i + 1]
+                } else {
+                    0
+                };
+                self.limbs[i] = (self.limbs[i] >> bit_shift) | (high << (32 - bit_shift));
+                i += 1;
+            }
+        }
+
+        self.normalized()
+    }
+
+    // divides by `d`, returning the quotient and remainder
+    const fn div_small(mut self, d: u32) -> (Self, u32) {
+        let mut rem = 0u64;
+        let mut i = self.len;
+        while i != 0 {
+            i -= 1;
+            let x = (rem << 32) | self.limbs[i] as u64;
+            self.limbs[i] = (x / d as u64) as u32;
+            rem = x % d as u64;
+        }
+        (self.normalized(), rem as u32)
+    }
+
+    const fn is_odd(&self) -> bool {
+        self.len != 0 && self.limbs[0] % 2 == 1
+    }
+
+    const fn add(mut self, other: &Self) -> Self {
+        let len = crate::utils::max_usize(self.len, other.len);
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < len {
+            let x = self.limbs[i] as u64 + other.limbs[i] as u64 + carry;
+            self.limbs[i] = x as u32;
+            carry = x >> 32;
+            i += 1;
+        }
+        self.len = len;
+        if carry != 0 {
+            self.limbs[len] = carry as u32;
+            self.len += 1;
+        }
+        self
+    }
+
+    // requires `self >= other`
+    const fn sub(mut self, other: &Self) -> Self {
+        let mut borrow = 0i64;
+        let mut i = 0;
+        while i < self.len {
+            let x = self.limbs[i] as i64 - other.limbs[i] as i64 - borrow;
+            if x < 0 {
+                self.limbs[i] = (x + (1 << 32)) as u32;
+                borrow = 1;
+            } else {
+                self.limbs[i] = x as u32;
+                borrow = 0;
+            }
+            i += 1;
+        }
+        self.normalized()
+    }
+
+    const fn cmp(&self, other: &Self) -> Ordering {
+        if self.len != other.len {
+            return if self.len < other.len {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        let mut i = self.len;
+        while i != 0 {
+            i -= 1;
+            let (l, r) = (self.limbs[i], other.limbs[i]);
+            if l != r {
+                return if l < r {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+        }
+        Ordering::Equal
+    }
+}
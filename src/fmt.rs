@@ -21,12 +21,26 @@ mod non_basic_fmt;
 #[cfg(feature = "non_basic")]
 mod fmt_compressed;
 
+#[cfg(feature = "non_basic")]
+pub(crate) mod hex_dump;
+
+#[cfg(feature = "non_basic")]
+pub(crate) mod flags_fmt;
+
+#[cfg(feature = "non_basic")]
+mod range_fmt;
+
+mod fmt_spec;
+
 pub mod char_formatting;
 
 #[cfg(feature = "non_basic")]
-pub use self::{fmt_compressed::PackedFmtArg, non_basic_fmt::*};
+pub use self::{
+    flags_fmt::FlagsFmt, fmt_compressed::PackedFmtArg, hex_dump::HexDump, non_basic_fmt::*,
+    range_fmt::RangeFmt,
+};
 
-use crate::wrapper::StdWrapper;
+use crate::{utils::PackedChar, wrapper::StdWrapper};
 
 use core::marker::PhantomData;
 
@@ -293,7 +307,7 @@ pub enum __CoerceToWitness<T, K: CoerceReturn<T>> {
 /// );
 ///
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Copy, Clone)]
 pub struct FmtArg {
     /// How much indentation is needed for a field/array element.
     ///
@@ -305,10 +319,35 @@ pub struct FmtArg {
     pub is_alternate: bool,
     /// Whether this is intended to be `Display` or `Debug` formatted.
     pub fmt_kind: FmtKind,
-    /// What integers are formatted as: decimal, hexadecimal, or binary.
+    /// What integers are formatted as: decimal, hexadecimal, binary, octal, etc.
     pub number_fmt: NumberFmt,
+
+    // The fields below are private so that formatting options can be added
+    // without breaking changes.
+    // They're set with the `set_*` methods, and read with the getter methods.
+    //
+    // The radix used by `NumberFmt::Radix`
+    pub(crate) radix: u8,
+    pub(crate) width: u8,
+    // only used when the `HAS_PRECISION` bit is set in `flags`
+    pub(crate) raw_precision: u8,
+    pub(crate) digit_group_len: u8,
+    pub(crate) depth_limit: u8,
+    // the alignment, and the boolean flags, see the `*_OFFSET` constants.
+    pub(crate) flags: u8,
+    pub(crate) fill: PackedChar,
+    pub(crate) digit_separator: PackedChar,
 }
 
+// The alignment is stored in the lowest 2 bits of `FmtArg.flags`,
+// these are the offsets of the boolean flags.
+pub(crate) const ZERO_PAD_OFFSET: u8 = 2;
+pub(crate) const SIGN_PLUS_OFFSET: u8 = 3;
+pub(crate) const BYTE_STR_OFFSET: u8 = 4;
+pub(crate) const HAS_PRECISION_OFFSET: u8 = 5;
+
+const ALIGNMENT_MASK: u8 = 0b11;
+
 impl FmtArg {
     /// A `FmtArg` with no indentation and `Display` formatting.
     pub const DISPLAY: Self = Self {
@@ -316,6 +355,14 @@ impl FmtArg {
         fmt_kind: FmtKind::Display,
         is_alternate: false,
         number_fmt: NumberFmt::Decimal,
+        radix: 10,
+        width: 0,
+        raw_precision: 0,
+        digit_group_len: 0,
+        depth_limit: MAX_DEPTH_LIMIT,
+        flags: 0,
+        fill: PackedChar::new(' '),
+        digit_separator: PackedChar::NONE,
     };
 
     /// A `FmtArg` with alternate `Display` formatting, starting with no indentation.
@@ -341,6 +388,24 @@ impl FmtArg {
     /// starting with no indentation.
     pub const ALT_HEX: Self = Self::HEX.set_alternate(true);
 
+    /// A `FmtArg` with `Debug` and `LowerHexadecimal` formatting and no indentation.
+    pub const LOWER_HEX: Self = Self::DISPLAY.set_lower_hex();
+
+    /// A `FmtArg` with alternate `Debug` and `LowerHexadecimal` formatting,
+    /// starting with no indentation.
+    pub const ALT_LOWER_HEX: Self = Self::LOWER_HEX.set_alternate(true);
+
+    /// A `FmtArg` with `Debug` and `Octal` formatting and no indentation.
+    pub const OCT: Self = Self::DISPLAY.set_oct();
+
+    /// A `FmtArg` with alternate `Debug` and `Octal` formatting,
+    /// starting with no indentation.
+    pub const ALT_OCT: Self = Self::OCT.set_alternate(true);
+
+    /// A `FmtArg` with `Debug` formatting, where `u8` slices and arrays are
+    /// formatted as byte strings, and no indentation.
+    pub const BYTE_STR: Self = Self::DISPLAY.set_byte_str();
+
     /// Sets whether alternate formatting is enabled
     pub const fn set_alternate(mut self, is_alternate: bool) -> Self {
         self.is_alternate = is_alternate;
@@ -372,6 +437,407 @@ impl FmtArg {
         self.number_fmt = NumberFmt::Binary;
         self
     }
+
+    /// Changes the formatting to `Debug`, and number formatting to `LowerHexadecimal`.
+    pub const fn set_lower_hex(mut self) -> Self {
+        self.fmt_kind = FmtKind::Debug;
+        self.number_fmt = NumberFmt::LowerHexadecimal;
+        self
+    }
+
+    /// Changes the formatting to `Debug`, and number formatting to `Octal`.
+    pub const fn set_oct(mut self) -> Self {
+        self.fmt_kind = FmtKind::Debug;
+        self.number_fmt = NumberFmt::Octal;
+        self
+    }
+
+    /// Changes the formatting to `Debug`,
+    /// and formats `u8` slices and arrays as byte string literals.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const MAGIC: &[u8] = b"\x7FELF\0";
+    ///
+    /// assert_eq!(const_panic::concat_!(FmtArg::BYTE_STR; MAGIC), r#"b"\x7fELF\x00""#);
+    ///
+    /// // the same can be done with the `byte_str` formatting override
+    /// assert_eq!(const_panic::concat_!(byte_str: MAGIC), r#"b"\x7fELF\x00""#);
+    ///
+    /// ```
+    pub const fn set_byte_str(mut self) -> Self {
+        self.fmt_kind = FmtKind::Debug;
+        self.flags |= 1 << BYTE_STR_OFFSET;
+        self
+    }
+
+    /// Changes the formatting to `Debug`,
+    /// and number formatting to [`Radix`](NumberFmt::Radix) with `radix` as the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const RADIX_3: FmtArg = FmtArg::DEBUG.set_radix(3);
+    ///
+    /// assert_eq!(const_panic::concat_!(RADIX_3; 100u8), "10201");
+    ///
+    /// // the `{radix = N}` formatting override uses this method
+    /// assert_eq!(const_panic::concat_!({radix = 36}: 1295u16), "zz");
+    ///
+    /// ```
+    pub const fn set_radix(mut self, radix: u8) -> Self {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the 2..=36 range"
+        );
+        self.fmt_kind = FmtKind::Debug;
+        self.number_fmt = NumberFmt::Radix;
+        self.radix = radix;
+        self
+    }
+
+    /// Sets the minimum amount of `char`s that primitive values are padded to.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{FmtArg, fmt::Alignment};
+    ///
+    /// const WIDE: FmtArg = FmtArg::DISPLAY.set_width(6);
+    /// const CENTER: FmtArg = WIDE.set_fill('*').set_alignment(Alignment::Center);
+    ///
+    /// const NUM: u8 = 100;
+    /// const FOO: &str = "foo";
+    ///
+    /// // literals aren't padded, because they're `Display` formatted with the default flags
+    /// assert_eq!(const_panic::concat_!(WIDE; NUM, "|", FOO, "|"), "   100|foo   |");
+    /// assert_eq!(const_panic::concat_!(CENTER; NUM), "*100**");
+    ///
+    /// // the same can be done with formatting overrides
+    /// assert_eq!(const_panic::concat_!({>6}: 100u8), "   100");
+    /// assert_eq!(const_panic::concat_!({*^6}: 100u8), "*100**");
+    ///
+    /// ```
+    pub const fn set_width(mut self, width: u8) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the `char` that values are padded with.
+    pub const fn set_fill(mut self, fill: char) -> Self {
+        self.fill = PackedChar::new(fill);
+        self
+    }
+
+    /// Sets how values are aligned when they're padded.
+    pub const fn set_alignment(mut self, alignment: Alignment) -> Self {
+        // `0` is no alignment
+        self.flags = (self.flags & !ALIGNMENT_MASK) | (alignment as u8 + 1);
+        self
+    }
+
+    /// Sets whether numbers are padded with `0`s after their sign and prefix.
+    pub const fn set_zero_pad(mut self, zero_pad: bool) -> Self {
+        self.flags = set_flag(self.flags, ZERO_PAD_OFFSET, zero_pad);
+        self
+    }
+
+    /// Sets the maximum amount of `char`s of `Display`-formatted strings,
+    /// and the amount of digits after the decimal point of floats.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const PREC: FmtArg = FmtArg::DISPLAY.set_precision(2);
+    ///
+    /// const HELLO: &str = "hello";
+    /// const ONE: f64 = 1.0;
+    ///
+    /// assert_eq!(const_panic::concat_!(PREC; HELLO, " ", ONE), "he 1.00");
+    /// assert_eq!(const_panic::concat_!({.2}: "hello", " ", {.2}: 1.0f64), "he 1.00");
+    ///
+    /// ```
+    pub const fn set_precision(mut self, precision: u8) -> Self {
+        self.raw_precision = precision;
+        self.flags |= 1 << HAS_PRECISION_OFFSET;
+        self
+    }
+
+    /// Sets whether non-negative numbers are prefixed with a `+`.
+    ///
+    /// Like in std, this applies to the two's complement of negative integers
+    /// when they're formatted in a non-decimal [`NumberFmt`].
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const PLUS: FmtArg = FmtArg::DEBUG.set_sign_plus(true);
+    ///
+    /// const NUMS: (u8, i8, f32) = (3, -5, 0.5);
+    ///
+    /// assert_eq!(const_panic::concat_!(PLUS; NUMS.0, " ", NUMS.1, " ", NUMS.2), "+3 -5 +0.5");
+    /// assert_eq!(const_panic::concat_!({+}: 3u8, " ", {+#x}: 255u8), "+3 +0xff");
+    ///
+    /// ```
+    pub const fn set_sign_plus(mut self, sign_plus: bool) -> Self {
+        self.flags = set_flag(self.flags, SIGN_PLUS_OFFSET, sign_plus);
+        self
+    }
+
+    /// Separates the digits of integers into groups with `separator`,
+    /// using the default amount of digits per group for the
+    /// [`number_fmt`](#structfield.number_fmt).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const COMMAS: FmtArg = FmtArg::DISPLAY.set_digit_separator(',');
+    /// const HEX: FmtArg = FmtArg::ALT_HEX.set_digit_separator('_');
+    ///
+    /// const NUM: u32 = 0xDEAD_BEEF;
+    ///
+    /// assert_eq!(const_panic::concat_!(COMMAS; NUM), "3,735,928,559");
+    /// assert_eq!(const_panic::concat_!(HEX; NUM), "0xDEAD_BEEF");
+    ///
+    /// // the same can be done with formatting overrides
+    /// assert_eq!(const_panic::concat_!({,}: 1000000u32), "1,000,000");
+    /// assert_eq!(const_panic::concat_!({#_X}: 0xDEAD_BEEFu32), "0xDEAD_BEEF");
+    ///
+    /// ```
+    pub const fn set_digit_separator(mut self, separator: char) -> Self {
+        self.digit_separator = PackedChar::new(separator);
+        self.digit_group_len = 0;
+        self
+    }
+
+    /// Separates the digits of integers into groups of `group_len` digits with `separator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_len` is `0`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::FmtArg;
+    ///
+    /// const BYTES: FmtArg = FmtArg::ALT_BIN.set_digit_grouping('_', 8);
+    ///
+    /// const NUM: u16 = 0b1010_1010_1111_0000;
+    ///
+    /// assert_eq!(const_panic::concat_!(BYTES; NUM), "0b10101010_11110000");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn set_digit_grouping(mut self, separator: char, group_len: u8) -> Self {
+        assert!(group_len != 0, "digit groups can't be empty");
+        self.digit_separator = PackedChar::new(separator);
+        self.digit_group_len = group_len;
+        self
+    }
+
+    /// Sets how many levels of nested `PanicVal`s are formatted
+    /// by `PanicVal`s constructed with [`PanicVal::from_nested`],
+    /// deeper levels are formatted as `...`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth_limit` is greater than [`MAX_DEPTH_LIMIT`].
+    ///
+    /// [`PanicVal::from_nested`]: crate::PanicVal::from_nested
+    #[track_caller]
+    pub const fn set_depth_limit(mut self, depth_limit: u8) -> Self {
+        assert!(
+            depth_limit <= MAX_DEPTH_LIMIT,
+            "the depth limit must not be greater than MAX_DEPTH_LIMIT"
+        );
+        self.depth_limit = depth_limit;
+        self
+    }
+
+    /// Resets the width, fill, alignment, zero-padding, precision,
+    /// sign, and digit grouping flags to their defaults.
+    pub const fn clear_flags(mut self) -> Self {
+        self.width = 0;
+        self.raw_precision = 0;
+        self.digit_group_len = 0;
+        self.flags &= 1 << BYTE_STR_OFFSET;
+        self.fill = PackedChar::new(' ');
+        self.digit_separator = PackedChar::NONE;
+        self
+    }
+
+    /// The radix that integers are formatted in
+    /// when [`number_fmt`](#structfield.number_fmt) is [`NumberFmt::Radix`],
+    /// ranging from 2 to 36.
+    pub const fn radix(self) -> u8 {
+        self.radix
+    }
+
+    /// The minimum amount of `char`s that primitive values are padded to,
+    /// equivalent to the `8` in `{:8}`.
+    ///
+    /// This is ignored by `Debug`-formatted strings and `char`s, like in std.
+    pub const fn width(self) -> u8 {
+        self.width
+    }
+
+    /// The `char` that values are padded with
+    /// when they're shorter than the [`width`](Self::width).
+    pub const fn fill(self) -> char {
+        self.fill.unwrap_or(' ')
+    }
+
+    /// How values are aligned when they're padded,
+    /// `None` means that numbers are right-aligned and everything else is left-aligned.
+    pub const fn alignment(self) -> Option<Alignment> {
+        match self.flags & ALIGNMENT_MASK {
+            0 => None,
+            1 => Some(Alignment::Left),
+            2 => Some(Alignment::Right),
+            _ => Some(Alignment::Center),
+        }
+    }
+
+    /// Whether numbers are padded with `0`s after the sign and `0x`-like prefixes,
+    /// equivalent to the `0` in `{:08}`.
+    ///
+    /// This overrides the [`fill`](Self::fill) and
+    /// [`alignment`](Self::alignment) for numbers.
+    pub const fn zero_pad(self) -> bool {
+        get_flag(self.flags, ZERO_PAD_OFFSET)
+    }
+
+    /// The maximum amount of `char`s of `Display`-formatted strings,
+    /// and the amount of digits after the decimal point of floats,
+    /// equivalent to the `3` in `{:.3}`.
+    pub const fn precision(self) -> Option<u8> {
+        if get_flag(self.flags, HAS_PRECISION_OFFSET) {
+            Some(self.raw_precision)
+        } else {
+            None
+        }
+    }
+
+    /// Whether non-negative numbers are prefixed with a `+`,
+    /// equivalent to the `+` in `{:+}`.
+    pub const fn sign_plus(self) -> bool {
+        get_flag(self.flags, SIGN_PLUS_OFFSET)
+    }
+
+    /// The separator written between groups of digits of integers,
+    /// eg: the `_` in `1_000_000` or the `,` in `1,000,000`.
+    ///
+    /// `None` means that the digits aren't grouped.
+    pub const fn digit_separator(self) -> Option<char> {
+        self.digit_separator.get()
+    }
+
+    /// How many digits are in each group separated by the
+    /// [`digit_separator`](Self::digit_separator).
+    ///
+    /// `0` means the default for the [`number_fmt`](#structfield.number_fmt):
+    /// groups of 4 digits for hexadecimal and binary, groups of 3 digits otherwise.
+    pub const fn digit_group_len(self) -> u8 {
+        self.digit_group_len
+    }
+
+    /// Whether `u8` slices and arrays are formatted as byte string literals,
+    /// eg: `b"foo\x00\xff"` instead of `[102, 111, 111, 0, 255]`.
+    pub const fn byte_str(self) -> bool {
+        get_flag(self.flags, BYTE_STR_OFFSET)
+    }
+
+    /// How many levels of nested `PanicVal`s are formatted
+    /// by `PanicVal`s constructed with this `FmtArg` by
+    /// [`PanicVal::from_nested`](crate::PanicVal::from_nested),
+    /// deeper levels are formatted as `...`.
+    ///
+    /// This can't be larger than [`MAX_DEPTH_LIMIT`], which is also the default.
+    pub const fn depth_limit(self) -> u8 {
+        self.depth_limit
+    }
+}
+
+impl core::fmt::Debug for FmtArg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FmtArg")
+            .field("indentation", &self.indentation)
+            .field("is_alternate", &self.is_alternate)
+            .field("fmt_kind", &self.fmt_kind)
+            .field("number_fmt", &self.number_fmt)
+            .field("radix", &self.radix())
+            .field("width", &self.width())
+            .field("fill", &self.fill())
+            .field("alignment", &self.alignment())
+            .field("zero_pad", &self.zero_pad())
+            .field("precision", &self.precision())
+            .field("sign_plus", &self.sign_plus())
+            .field("digit_separator", &self.digit_separator())
+            .field("digit_group_len", &self.digit_group_len())
+            .field("byte_str", &self.byte_str())
+            .field("depth_limit", &self.depth_limit())
+            .finish()
+    }
+}
+
+// Compares the formatting options as returned by the getter methods,
+// ignoring the radix unless `number_fmt` is `NumberFmt::Radix`,
+// since otherwise it doesn't affect the output.
+impl PartialEq for FmtArg {
+    fn eq(&self, other: &Self) -> bool {
+        let uses_radix = self.number_fmt == NumberFmt::Radix;
+
+        self.indentation == other.indentation
+            && self.is_alternate == other.is_alternate
+            && self.fmt_kind == other.fmt_kind
+            && self.number_fmt == other.number_fmt
+            && (!uses_radix || self.radix() == other.radix())
+            && self.width() == other.width()
+            && self.fill() == other.fill()
+            && self.alignment() == other.alignment()
+            && self.zero_pad() == other.zero_pad()
+            && self.precision() == other.precision()
+            && self.sign_plus() == other.sign_plus()
+            && self.digit_separator() == other.digit_separator()
+            && self.digit_group_len() == other.digit_group_len()
+            && self.byte_str() == other.byte_str()
+            && self.depth_limit() == other.depth_limit()
+    }
+}
+
+/// The maximum (and default) [depth limit](FmtArg::depth_limit)
+/// of nested `PanicVal`s.
+pub const MAX_DEPTH_LIMIT: u8 = 32;
+
+const fn get_flag(flags: u8, offset: u8) -> bool {
+    (flags >> offset) & 1 != 0
+}
+
+const fn set_flag(flags: u8, offset: u8, value: bool) -> u8 {
+    (flags & !(1 << offset)) | ((value as u8) << offset)
 }
 
 #[cfg(feature = "non_basic")]
@@ -404,7 +870,25 @@ pub enum FmtKind {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// How values are aligned when they're padded to the [`FmtArg::width`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Alignment {
+    /// Left-aligned, equivalent to `{:<8}`.
+    Left = 0,
+    /// Right-aligned, equivalent to `{:>8}`.
+    Right = 1,
+    /// Centered, equivalent to `{:^8}`,
+    /// with the extra padding char (if any) to the right.
+    Center = 2,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// What integers are formatted as.
+///
+/// Negative integers are formatted as their two's complement
+/// in every format other than `Decimal`.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NumberFmt {
@@ -414,4 +898,13 @@ pub enum NumberFmt {
     Binary = 1,
     /// Formatted as hexadecimal, eg: `FAD`, `0xDE`.
     Hexadecimal = 2,
+    /// Formatted as octal, eg: `755`, `0o644`.
+    Octal = 3,
+    /// Formatted as lowercase hexadecimal, eg: `fad`, `0xde`.
+    LowerHexadecimal = 4,
+    /// Formatted in the radix returned by [`FmtArg::radix`],
+    /// using lowercase letters for digits above 9, eg: `zz` (`1295` in radix 36).
+    ///
+    /// The alternate flag adds no prefix for this format.
+    Radix = 5,
 }
@@ -1,9 +1,9 @@
 //! `char`-formatted related items
 
 use crate::{
-    fmt::{FmtArg, FmtKind},
+    fmt::{Alignment, FmtArg, FmtKind},
     fmt_impls::basic_fmt_impls::primitive_static_panicfmt,
-    panic_val::{PanicVal, PanicVariant},
+    panic_val::{PanicVal, PanicVariant, StrFmt},
     utils::{string_cap, PreFmtString, StartAndBytes},
 };
 
@@ -13,23 +13,36 @@ mod tests;
 impl PanicVal<'_> {
     /// Constructs a `PanicVal` from a `char`.
     pub const fn from_char(c: char, fmtarg: FmtArg) -> Self {
-        let StartAndBytes { start, bytes } = match fmtarg.fmt_kind {
+        let (StartAndBytes { start, bytes }, char_len) = match fmtarg.fmt_kind {
             FmtKind::Display => {
                 let (arr, len) = char_to_utf8(c);
-                crate::utils::tail_byte_array::<{ string_cap::PREFMT }>(len, &arr)
+                // like in std, a precision of 0 truncates the char away
+                let (len, char_len) = match fmtarg.precision() {
+                    Some(0) => (0, 0),
+                    _ => (len, 1),
+                };
+                let bytes = crate::utils::tail_byte_array::<{ string_cap::PREFMT }>(len, &arr);
+                (bytes, char_len)
             }
             FmtKind::Debug => {
                 let fmtchar = char_to_debug(c);
-                crate::utils::tail_byte_array(fmtchar.len(), &fmtchar.encoded)
+                let bytes = crate::utils::tail_byte_array(fmtchar.len(), &fmtchar.encoded);
+                (bytes, 0)
             }
         };
+
+        // Debug-formatted chars aren't padded, like in std.
+        let strfmt = match fmtarg.fmt_kind {
+            FmtKind::Display => StrFmt::padded(fmtarg, FmtKind::Display, char_len, Alignment::Left),
+            FmtKind::Debug => StrFmt::DISPLAY,
+        };
         // SAFETY:
         // char_to_utf8 is exhaustively tested in the tests module.
         // char_to_debug is exhaustively tested in the tests module.
         // tail_byte_array is also tested for smaller/equal/larger input arrays.
-        let prefmt = unsafe { PreFmtString::new(start, bytes) };
+        let prefmt = unsafe { PreFmtString::new(start as u16, bytes) };
         PanicVal {
-            var: PanicVariant::PreFmt(prefmt),
+            var: PanicVariant::PreFmt(strfmt, prefmt),
         }
     }
 }
@@ -60,7 +73,7 @@ pub(crate) const fn char_debug_len(c: char) -> usize {
     inner + 2
 }
 
-const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
+pub(crate) const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
     let u32 = char as u32;
     match u32 {
         0..=127 => ([u32 as u8, 0, 0, 0], 1),
@@ -0,0 +1,189 @@
+use crate::PanicVal;
+
+use super::{ComputePvCount, FmtArg, FmtKind, TypeDelim};
+
+/// For formatting an integer as a set of bit flags, like the `bitflags` crate does.
+///
+/// The set flags are written by name (in the order they're passed to [`new`](Self::new)),
+/// separated by `" | "`,
+/// followed by the bits that aren't part of any named flag in lowercase hexadecimal
+/// (eg: `READ | EXEC | 0x40`).
+/// An empty set of flags is written as `0x0`.
+///
+/// When Debug formatted, the flags are wrapped in the type name,
+/// like a tuple struct (eg: `Perms(READ | EXEC | 0x40)`).
+///
+/// A named flag is only written if all of its bits are set,
+/// and some of those bits weren't already written by a previous flag.
+/// Flags whose value is `0` are never written.
+///
+/// The [`impl_panicfmt`] macro and the [`PanicFmt` derive] use this type for
+/// formatting types with the `#[pfmt(flags(...))]` attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt::FlagsFmt, ArrayString, FmtArg, PanicFmt, PanicVal};
+///
+/// struct Perms(u32);
+///
+/// impl PanicFmt for Perms {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = FlagsFmt::pv_count(3);
+/// }
+///
+/// impl Perms {
+///     const fn to_panicvals(&self, fmtarg: FmtArg) -> [PanicVal<'static>; Perms::PV_COUNT] {
+///         FlagsFmt::new(
+///             "Perms",
+///             self.0 as u128,
+///             &[("READ", 1), ("WRITE", 2), ("EXEC", 4)],
+///         )
+///         .to_panicvals(fmtarg)
+///     }
+/// }
+///
+/// assert_eq!(const_panic::concat_!(Perms(0b101)), "Perms(READ | EXEC)");
+/// assert_eq!(const_panic::concat_!(display: Perms(0x43)), "READ | WRITE | 0x40");
+/// assert_eq!(const_panic::concat_!(Perms(0)), "Perms(0x0)");
+///
+/// ```
+///
+/// [`impl_panicfmt`]: crate::impl_panicfmt
+/// [`PanicFmt` derive]: derive@crate::PanicFmt
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub struct FlagsFmt<'a> {
+    type_name: &'static str,
+    bits: u128,
+    flags: &'a [(&'static str, u128)],
+}
+
+impl<'a> FlagsFmt<'a> {
+    /// Constructs a `FlagsFmt`,
+    /// where `flags` is a list of the names and values of each flag.
+    ///
+    /// Signed integers must be converted to their unsigned equivalent before
+    /// being converted to `u128`, to avoid sign-extending them.
+    pub const fn new(
+        type_name: &'static str,
+        bits: u128,
+        flags: &'a [(&'static str, u128)],
+    ) -> Self {
+        Self {
+            type_name,
+            bits,
+            flags,
+        }
+    }
+
+    /// The amount of `PanicVal`s that [`to_panicvals`](Self::to_panicvals) needs to output
+    /// for `flag_amount` flags.
+    pub const fn pv_count(flag_amount: usize) -> usize {
+        ComputePvCount {
+            field_amount: 1,
+            // each flag name with the separator after it, and the unknown bits
+            summed_pv_count: 2 * flag_amount + 1,
+            delimiter: TypeDelim::Tupled,
+        }
+        .call()
+    }
+
+    /// Formats these flags into a `PanicVal` array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LEN` is less than
+    /// [`FlagsFmt::pv_count(flags.len())`](Self::pv_count).
+    pub const fn to_panicvals<const LEN: usize>(
+        &self,
+        mut fmtarg: FmtArg,
+    ) -> [PanicVal<'static>; LEN] {
+        let mut out = [PanicVal::EMPTY; LEN];
+        let mut len = 0;
+
+        let is_debug = matches!(fmtarg.fmt_kind, FmtKind::Debug);
+
+        if is_debug {
+            out[0] = PanicVal::write_str(self.type_name);
+            fmtarg = fmtarg.indent();
+            out[1] = TypeDelim::Tupled.open().to_panicval(fmtarg);
+            len = 2;
+        }
+
+        let separator = PanicVal::write_str(" | ");
+        let mut remaining = self.bits;
+        let mut written_any = false;
+
+        let mut i = 0;
+        while i < self.flags.len() {
+            let (name, value) = self.flags[i];
+
+            if value != 0 && self.bits & value == value && remaining & value != 0 {
+                if written_any {
+                    out[len] = separator;
+                    len += 1;
+                }
+                out[len] = PanicVal::write_str(name);
+                len += 1;
+
+                remaining &= !value;
+                written_any = true;
+            }
+
+            i += 1;
+        }
+
+        if remaining != 0 || !written_any {
+            if written_any {
+                out[len] = separator;
+                len += 1;
+            }
+            let hex = FmtArg::DISPLAY.set_lower_hex().set_alternate(true);
+            out[len] = PanicVal::from_u128(remaining, hex);
+            len += 1;
+        }
+
+        if is_debug {
+            out[len] = super::COMMA_TERM.to_panicval(fmtarg);
+            out[len + 1] = TypeDelim::Tupled.close().to_panicval(fmtarg.unindent());
+        }
+
+        out
+    }
+}
+
+// Maps the integer types that bit flags can be stored in
+// to the unsigned integer type of the same width,
+// so that the macros that use `FlagsFmt` can convert signed integers to `u128`
+// without sign-extending them.
+#[doc(hidden)]
+pub trait FlagsBits {
+    type Unsigned;
+}
+
+macro_rules! impl_flags_bits {
+    ($(($int:ty, $unsigned:ty))*) => {
+        $(
+            impl FlagsBits for $int {
+                type Unsigned = $unsigned;
+            }
+        )*
+    };
+}
+
+impl_flags_bits! {
+    (u8, u8)
+    (u16, u16)
+    (u32, u32)
+    (u64, u64)
+    (u128, u128)
+    (usize, usize)
+    (i8, u8)
+    (i16, u16)
+    (i32, u32)
+    (i64, u64)
+    (i128, u128)
+    (isize, usize)
+}
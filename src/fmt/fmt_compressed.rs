@@ -1,4 +1,7 @@
-use crate::fmt::{FmtArg, FmtKind, NumberFmt};
+use crate::{
+    fmt::{FmtArg, FmtKind, NumberFmt},
+    utils::PackedChar,
+};
 
 /// A version of FmtArg which occupies less space, but needs to be unpacked to be used.
 #[derive(Copy, Clone)]
@@ -6,10 +9,34 @@ use crate::fmt::{FmtArg, FmtKind, NumberFmt};
 pub struct PackedFmtArg {
     indentation: u8,
     bitfields: u8,
+    // the radix (at most 36) in the lowest 6 bits, and the alignment in the highest 2 bits
+    radix_alignment: u8,
+    // the depth limit (at most 32) in the lowest 6 bits,
+    // and the zero-padding and sign flags in the highest 2 bits
+    depth_limit_flags: u8,
+    width: u8,
+    precision: u8,
+    digit_group_len: u8,
+    fill: PackedChar,
+    digit_separator: PackedChar,
 }
 
 const FMT_KIND_OFFSET: u8 = 1;
 const NUMBER_FMT_OFFSET: u8 = FMT_KIND_OFFSET + FmtKind::BITS;
+const BYTE_STR_OFFSET: u8 = NUMBER_FMT_OFFSET + NumberFmt::BITS;
+const HAS_PRECISION_OFFSET: u8 = BYTE_STR_OFFSET + 1;
+
+const LOW_6_BITS: u8 = 0b11_1111;
+
+// where the boolean flags of `FmtArg.flags` are stored
+const FLAGS_TO_BITFIELDS: [(u8, u8); 2] = [
+    (crate::fmt::BYTE_STR_OFFSET, BYTE_STR_OFFSET),
+    (crate::fmt::HAS_PRECISION_OFFSET, HAS_PRECISION_OFFSET),
+];
+const FLAGS_TO_DEPTH_LIMIT_FLAGS: [(u8, u8); 2] = [
+    (crate::fmt::ZERO_PAD_OFFSET, 6),
+    (crate::fmt::SIGN_PLUS_OFFSET, 7),
+];
 
 impl FmtArg {
     /// Converts this `FmtArg` into a `PackedFmtArg`,
@@ -21,13 +48,29 @@ impl FmtArg {
             is_alternate,
             fmt_kind,
             number_fmt,
+            radix,
+            width,
+            raw_precision,
+            digit_group_len,
+            depth_limit,
+            flags,
+            fill,
+            digit_separator,
         } = self;
 
         PackedFmtArg {
             indentation,
             bitfields: is_alternate as u8
                 | ((fmt_kind as u8) << FMT_KIND_OFFSET)
-                | ((number_fmt as u8) << NUMBER_FMT_OFFSET),
+                | ((number_fmt as u8) << NUMBER_FMT_OFFSET)
+                | move_flags(flags, FLAGS_TO_BITFIELDS),
+            radix_alignment: radix | ((flags & 0b11) << 6),
+            depth_limit_flags: depth_limit | move_flags(flags, FLAGS_TO_DEPTH_LIMIT_FLAGS),
+            width,
+            precision: raw_precision,
+            digit_group_len,
+            fill,
+            digit_separator,
         }
     }
 }
@@ -40,15 +83,39 @@ impl PackedFmtArg {
         let fmt_kind = FmtKind::from_prim(self.bitfields >> FMT_KIND_OFFSET);
         let number_fmt = NumberFmt::from_prim(self.bitfields >> NUMBER_FMT_OFFSET);
 
+        let flags = (self.radix_alignment >> 6)
+            | unmove_flags(self.bitfields, FLAGS_TO_BITFIELDS)
+            | unmove_flags(self.depth_limit_flags, FLAGS_TO_DEPTH_LIMIT_FLAGS);
+
         FmtArg {
             indentation,
             is_alternate,
             fmt_kind,
             number_fmt,
+            radix: self.radix_alignment & LOW_6_BITS,
+            width: self.width,
+            raw_precision: self.precision,
+            digit_group_len: self.digit_group_len,
+            depth_limit: self.depth_limit_flags & LOW_6_BITS,
+            flags,
+            fill: self.fill,
+            digit_separator: self.digit_separator,
         }
     }
 }
 
+// moves the bits of `flags` from the first offset of each pair to the second one
+const fn move_flags(flags: u8, offsets: [(u8, u8); 2]) -> u8 {
+    let [(from0, to0), (from1, to1)] = offsets;
+    (((flags >> from0) & 1) << to0) | (((flags >> from1) & 1) << to1)
+}
+
+// the inverse of `move_flags`
+const fn unmove_flags(bits: u8, offsets: [(u8, u8); 2]) -> u8 {
+    let [(from0, to0), (from1, to1)] = offsets;
+    move_flags(bits, [(to0, from0), (to1, from1)])
+}
+
 macro_rules! enum_prim {
     (
         $type:ident, $bits:expr;
@@ -89,8 +156,11 @@ enum_prim! {
 }
 
 enum_prim! {
-    NumberFmt, 2;
+    NumberFmt, 3;
     default Decimal,
     Binary,
     Hexadecimal,
+    Octal,
+    LowerHexadecimal,
+    Radix,
 }
@@ -0,0 +1,198 @@
+use crate::fmt::{Alignment, FmtArg, FmtKind, NumberFmt};
+
+impl FmtArg {
+    // Replaces everything but the indentation and depth limit of `self` with `spec`.
+    #[doc(hidden)]
+    pub const fn __with_spec(self, spec: FmtArg) -> FmtArg {
+        FmtArg {
+            indentation: self.indentation,
+            depth_limit: self.depth_limit,
+            ..spec
+        }
+    }
+
+    // Parses the stringified tokens of a `{...}` formatting override,
+    // with this syntax: `[[fill]align][+][#][0][width][grouping][.precision][type]`
+    //
+    // whitespace is ignored, since `stringify` can add it between tokens.
+    #[doc(hidden)]
+    #[track_caller]
+    pub const fn __parse_spec(spec: &str) -> FmtArg {
+        let bytes = spec.as_bytes();
+        let mut this = FmtArg::DISPLAY;
+
+        let mut i = skip_whitespace(bytes, 0);
+
+        // `{:>8}:` is a likely typo of `{>8}:`,
+        // which would otherwise be parsed as using `:` as the fill.
+        if let Some(b':') = get_byte(bytes, i) {
+            panic!(
+                "formatting overrides are written without the leading `:` \
+                 of std formatting specifiers, eg: `>8` instead of `:>8`"
+            )
+        }
+
+        // fill and alignment
+        if i < bytes.len() {
+            let (fill, after_fill) = decode_char(bytes, i);
+            let after_fill = skip_whitespace(bytes, after_fill);
+
+            if let Some(alignment) = parse_alignment(bytes, after_fill) {
+                this = this.set_fill(fill).set_alignment(alignment);
+                i = after_fill + 1;
+            } else if let Some(alignment) = parse_alignment(bytes, i) {
+                this = this.set_alignment(alignment);
+                i += 1;
+            }
+        }
+
+        i = skip_whitespace(bytes, i);
+        if i < bytes.len() && bytes[i] == b'+' {
+            this = this.set_sign_plus(true);
+            i = skip_whitespace(bytes, i + 1);
+        }
+
+        if i < bytes.len() && bytes[i] == b'#' {
+            this.is_alternate = true;
+            i = skip_whitespace(bytes, i + 1);
+        }
+
+        if i < bytes.len() && bytes[i] == b'0' {
+            this = this.set_zero_pad(true);
+            i += 1;
+        }
+
+        let (width, after_width) = parse_u8(bytes, i);
+        if let Some(width) = width {
+            this = this.set_width(width);
+        }
+        i = skip_whitespace(bytes, after_width);
+
+        if let Some(separator @ (b'_' | b',')) = get_byte(bytes, i) {
+            this = this.set_digit_separator(separator as char);
+            i = skip_whitespace(bytes, i + 1);
+        }
+
+        if i < bytes.len() && bytes[i] == b'.' {
+            i = skip_whitespace(bytes, i + 1);
+            let (precision, after_precision) = parse_u8(bytes, i);
+            match precision {
+                Some(precision) => this = this.set_precision(precision),
+                None => panic!("expected the precision after `.` in formatting override"),
+            }
+            i = skip_whitespace(bytes, after_precision);
+        }
+
+        let (fmt_kind, number_fmt) = match get_byte(bytes, i) {
+            None => (FmtKind::Display, NumberFmt::Decimal),
+            Some(b'?') => (FmtKind::Debug, NumberFmt::Decimal),
+            Some(b'x') => (FmtKind::Debug, NumberFmt::LowerHexadecimal),
+            Some(b'X') => (FmtKind::Debug, NumberFmt::Hexadecimal),
+            Some(b'o') => (FmtKind::Debug, NumberFmt::Octal),
+            Some(b'b') => (FmtKind::Debug, NumberFmt::Binary),
+            Some(_) => panic!(
+                "expected one of `?`, `x`, `X`, `o`, `b`, or nothing \
+                 as the type of a formatting override"
+            ),
+        };
+        this.fmt_kind = fmt_kind;
+        this.number_fmt = number_fmt;
+
+        if i < bytes.len() {
+            i = skip_whitespace(bytes, i + 1);
+        }
+        // `x?` and `X?` are equivalent to `x` and `X`
+        if let (NumberFmt::LowerHexadecimal | NumberFmt::Hexadecimal, Some(b'?')) =
+            (number_fmt, get_byte(bytes, i))
+        {
+            i = skip_whitespace(bytes, i + 1);
+        }
+
+        if i != bytes.len() {
+            panic!(
+                "expected formatting override to be in the \
+                 `[[fill]align][+][#][0][width][grouping][.precision][type]` format"
+            )
+        }
+
+        this
+    }
+}
+
+const fn get_byte(bytes: &[u8], i: usize) -> Option<u8> {
+    if i < bytes.len() {
+        Some(bytes[i])
+    } else {
+        None
+    }
+}
+
+const fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+const fn parse_alignment(bytes: &[u8], i: usize) -> Option<Alignment> {
+    match get_byte(bytes, i) {
+        Some(b'<') => Some(Alignment::Left),
+        Some(b'>') => Some(Alignment::Right),
+        Some(b'^') => Some(Alignment::Center),
+        _ => None,
+    }
+}
+
+// parses a decimal number, returning the index after it
+#[track_caller]
+const fn parse_u8(bytes: &[u8], mut i: usize) -> (Option<u8>, usize) {
+    let mut n: Option<u32> = None;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        let digit = (bytes[i] - b'0') as u32;
+        let prev = match n {
+            Some(x) => x,
+            None => 0,
+        };
+        n = Some(prev * 10 + digit);
+        if prev * 10 + digit > u8::MAX as u32 {
+            panic!("widths and precisions in formatting overrides can't be larger than 255")
+        }
+        i += 1;
+    }
+
+    match n {
+        Some(x) => (Some(x as u8), i),
+        None => (None, i),
+    }
+}
+
+// decodes the utf8-encoded char starting at `i`,
+// returning the index after the char
+const fn decode_char(bytes: &[u8], i: usize) -> (char, usize) {
+    let first = bytes[i] as u32;
+    let (len, mut code) = if first < 0x80 {
+        (1, first)
+    } else if first < 0xE0 {
+        (2, first & 0x1F)
+    } else if first < 0xF0 {
+        (3, first & 0x0F)
+    } else {
+        (4, first & 0x07)
+    };
+
+    let mut j = 1;
+    while j < len {
+        code = (code << 6) | (bytes[i + j] as u32 & 0x3F);
+        j += 1;
+    }
+
+    // `char::from_u32` isn't const until Rust 1.67
+    union Transmute {
+        code: u32,
+        c: char,
+    }
+    // SAFETY: `bytes` comes from a `&str`, so decoding it produces a valid `char`
+    let c = unsafe { Transmute { code }.c };
+
+    (c, i + len)
+}
@@ -0,0 +1,172 @@
+use crate::{
+    utils::{bytes_from, bytes_up_to},
+    PanicVal,
+};
+
+use super::{FmtArg, IsCustomType, PanicFmt};
+
+use core::ops::Range;
+
+/// The amount of bytes printed in each row of a hexdump.
+pub(crate) const HEX_DUMP_ROW_LEN: usize = 16;
+
+/// For formatting a byte slice as an `xxd`-style hexdump.
+///
+/// Each row is formatted as the offset of its first byte (8 lowercase hex digits),
+/// followed by up to 16 bytes as hexadecimal (in groups of 2 bytes),
+/// followed by those bytes as ASCII
+/// (where bytes outside of the printable range are printed as `.`).
+///
+/// Rows are separated with newlines, there is no trailing newline after the last row.
+///
+/// The [`window`](Self::window) method can be used to only print a range of the bytes,
+/// to avoid going over the length limit of panic messages.
+///
+/// This ignores the [`FmtArg`] it's formatted with.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt::HexDump, ArrayString, FmtArg};
+///
+/// const HEADER: &[u8] = b"\x7fELF\x02\x01\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x03\x00>\x00";
+///
+/// assert_eq!(
+///     ArrayString::<200>::concat_panicvals(&[&HexDump::new(HEADER).to_panicvals(FmtArg::DEBUG)])
+///         .unwrap(),
+///     concat!(
+///         "00000000: 7f45 4c46 0201 0100 0000 0000 0000 0000  .ELF............\n",
+///         "00000010: 0300 3e00                                ..>.",
+///     ),
+/// );
+///
+/// // the offsets are relative to the start of the whole slice
+/// assert_eq!(
+///     ArrayString::<200>::concat_panicvals(&[
+///         &HexDump::new(HEADER).window(1..6).to_panicvals(FmtArg::DEBUG)
+///     ])
+///     .unwrap(),
+///     "00000001: 454c 4602 01                             ELF..",
+/// );
+///
+/// ```
+///
+/// With [`concat_panic`](crate::concat_panic):
+///
+/// ```compile_fail
+/// use const_panic::{concat_panic, fmt::HexDump};
+///
+/// const _: () = {
+///     const BLOB: &[u8] = &[0xFF; 40];
+///
+///     concat_panic!{"\n", HexDump::new(BLOB).window(8..24)}
+/// };
+/// ```
+///
+/// The above code fails to compile with this panic message:
+/// ```text
+///
+/// 00000008: ffff ffff ffff ffff ffff ffff ffff ffff  ................
+/// ```
+///
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    offset: u32,
+}
+
+impl<'a> HexDump<'a> {
+    /// Constructs a `HexDump` that prints all of `bytes`.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// Restricts the printed bytes to the `range` subrange of the current bytes.
+    ///
+    /// `range.end` is clamped to the amount of bytes,
+    /// printed offsets are still relative to the start of the slice passed to
+    /// [`new`](Self::new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`,
+    /// or if the resulting offset doesn't fit in a `u32`.
+    #[track_caller]
+    pub const fn window(self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end,
+            "the start of the range must not be greater than the end"
+        );
+
+        let end = crate::utils::min_usize(range.end, self.bytes.len());
+        let start = crate::utils::min_usize(range.start, end);
+
+        let offset = self.offset as usize + start;
+        assert!(
+            offset <= u32::MAX as usize,
+            "the offset of the window must fit in a u32"
+        );
+
+        Self {
+            bytes: bytes_from(bytes_up_to(self.bytes, end), start),
+            offset: offset as u32,
+        }
+    }
+
+    /// The bytes that are printed.
+    pub const fn bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The offset of the first printed byte.
+    pub const fn offset(self) -> u32 {
+        self.offset
+    }
+
+    /// Converts this `HexDump` into a single-element `PanicVal` array.
+    pub const fn to_panicvals(&self, _: FmtArg) -> [PanicVal<'a>; 1] {
+        [self.to_panicval(FmtArg::DISPLAY)]
+    }
+
+    /// Converts this `HexDump` into a `PanicVal`.
+    pub const fn to_panicval(&self, _: FmtArg) -> PanicVal<'a> {
+        PanicVal::from_hex_dump(self.bytes, self.offset)
+    }
+}
+
+impl PanicFmt for HexDump<'_> {
+    type This = Self;
+    type Kind = IsCustomType;
+    const PV_COUNT: usize = 1;
+}
+
+// the length of the hexadecimal for the `index`th byte of a hexdump row,
+// including the space that separates groups of 2 bytes.
+pub(crate) const fn hex_row_byte_len(index: usize) -> usize {
+    if index % 2 == 1 && index + 1 != HEX_DUMP_ROW_LEN {
+        3
+    } else {
+        2
+    }
+}
+
+// the length of the padding after the hexadecimal of a `len` byte hexdump row,
+// so that the ascii column of every row is aligned.
+pub(crate) const fn hex_row_padding(len: usize) -> usize {
+    // the 2 spaces between the hexadecimal and ascii columns
+    let mut padding = 2;
+    let mut i = len;
+    while i < HEX_DUMP_ROW_LEN {
+        padding += hex_row_byte_len(i);
+        i += 1;
+    }
+    padding
+}
+
+pub(crate) const fn next_hex_dump_row(bytes: &[u8]) -> &[u8] {
+    bytes_from(
+        bytes,
+        crate::utils::min_usize(HEX_DUMP_ROW_LEN, bytes.len()),
+    )
+}
@@ -177,7 +177,9 @@ impl<'a> PanicVal<'a> {
     /// Constructs a `PanicVal` from a `ShortString`.
     pub const fn from_short_str(this: ShortString, f: FmtArg) -> PanicVal<'a> {
         use crate::panic_val::{PanicVariant, StrFmt};
-        PanicVal::__new(PanicVariant::ShortString(StrFmt::new(f), this.to_compact()))
+        let (strfmt, string) = StrFmt::for_str(f, this.to_str());
+        let truncated = ShortString::new(string);
+        PanicVal::__new(PanicVariant::ShortString(strfmt, truncated.to_compact()))
     }
 }
 
@@ -295,6 +297,42 @@ impl ComputePvCount {
     }
 }
 
+/// Computes the amount of `PanicVal`s that [`array_panicvals`] outputs for
+/// a `[T; len]` array,
+/// where `elem_pv_count` is `<T as PanicFmt>::PV_COUNT`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt::array_pv_count, PanicFmt};
+///
+/// assert_eq!(array_pv_count(<u8>::PV_COUNT, 0), 2);
+/// assert_eq!(array_pv_count(<u8>::PV_COUNT, 4), 10);
+///
+/// // the amount of `PanicVal`s for a `[[u8; 4]; 3]` array
+/// assert_eq!(array_pv_count(array_pv_count(<u8>::PV_COUNT, 4), 3), 35);
+/// ```
+///
+/// [`array_panicvals`]: crate::array_panicvals
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn array_pv_count(elem_pv_count: usize, len: usize) -> usize {
+    // the brackets, and the separator after each element
+    2 + len * (elem_pv_count + 1)
+}
+
+/// Computes the amount of `PanicVal`s that [`array_panicvals`] outputs for
+/// a slice of `T` formatted with the `[T; ..max_len]` syntax,
+/// where `elem_pv_count` is `<T as PanicFmt>::PV_COUNT`.
+///
+/// This is the same as [`array_pv_count`], plus the `...` and the separator after it,
+/// which are written when the slice is longer than `max_len`.
+///
+/// [`array_panicvals`]: crate::array_panicvals
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn slice_pv_count(elem_pv_count: usize, max_len: usize) -> usize {
+    array_pv_count(elem_pv_count, max_len) + 2
+}
+
 /// Whether a struct or variant is Tupled or Braced.
 ///
 /// Unit structs/variants are considered braced.
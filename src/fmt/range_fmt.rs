@@ -0,0 +1,33 @@
+/// For formatting ranges of any integer type or `char`.
+///
+/// Ranges of `usize` (and `..`) can be formatted directly,
+/// this wrapper is for formatting the `Range`, `RangeFrom`, `RangeTo`,
+/// `RangeInclusive`, and `RangeToInclusive` types of the other integer types and `char`.
+///
+/// Those ranges don't implement [`PanicFmt`](crate::PanicFmt) themselves
+/// so that unsuffixed integer ranges (eg: `3..5`) keep being inferred as `usize` ranges.
+///
+/// Slices and arrays of `Range` and `RangeInclusive` of any integer type or `char`
+/// can be formatted directly.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_, fmt::RangeFmt};
+///
+/// assert_eq!(concat_!(RangeFmt(3u8..5)), "3..5");
+/// assert_eq!(concat_!(RangeFmt(-8i16..=-3)), "-8..=-3");
+/// assert_eq!(concat_!(RangeFmt('a'..)), "'a'..");
+/// assert_eq!(concat_!({#x}: RangeFmt(..=255u32)), "..=0xff");
+///
+/// // unsuffixed ranges are `usize` ranges
+/// assert_eq!(concat_!(3..5), "3..5");
+///
+/// // slices and arrays of ranges don't need the wrapper
+/// assert_eq!(concat_!([0u8..3, 5..8]), "[0..3, 5..8]");
+///
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub struct RangeFmt<R>(pub R);
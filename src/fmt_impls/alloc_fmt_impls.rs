@@ -0,0 +1,215 @@
+use crate::{
+    fmt::{FmtArg, PanicFmt},
+    PanicVal, StdWrapper,
+};
+
+use alloc::{boxed::Box, rc::Rc};
+
+// `cfg(target_has_atomic)` is only usable from Rust 1.60 onwards,
+// the `feature = "rust_1_64"` check comes first so that it isn't evaluated before that.
+#[cfg(all(feature = "rust_1_64", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "rust_1_88")]
+use alloc::{borrow::Cow, string::String};
+
+#[cfg(all(feature = "non_basic", feature = "rust_1_88"))]
+use alloc::vec::Vec;
+
+macro_rules! impl_pointer_panicfmt {
+    ($(
+        $(#[$attr:meta])*
+        $ptr:ident
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+            /// Note: there is only `to_panicvals` methods for this type pointing to
+            /// standard library types for now.
+            ///
+            impl<T: PanicFmt + ?Sized> PanicFmt for $ptr<T> {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                // formatted like the pointed-to value, like in std
+                const PV_COUNT: usize = T::PV_COUNT;
+            }
+        )*
+    };
+}
+
+impl_pointer_panicfmt! {
+    Box
+    Rc
+    #[cfg(all(feature = "rust_1_64", target_has_atomic = "ptr"))]
+    Arc
+}
+
+// Implements `to_panicvals` for `Box`, `Rc`, and `Arc` pointing to
+// a single standard library type, which output the pointed-to value.
+macro_rules! impl_for_pointers {
+    ($(($ty:ty, $unref:ty))*) => {
+        $(
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+            impl<'s> StdWrapper<&'s Box<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Box<", stringify!($ty), ">` to a `PanicVal` array.",
+                    "\n\nThis only outputs the pointed-to value, like the std `Debug` impl does."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 1] {
+                    [StdWrapper::<&$unref>(&**self.0).to_panicval(fmtarg)]
+                }
+            }
+
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+            impl<'s> StdWrapper<&'s Rc<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Rc<", stringify!($ty), ">` to a `PanicVal` array.",
+                    "\n\nThis only outputs the pointed-to value, like the std `Debug` impl does.",
+                    "\n\nThis isn't a `const fn`, because `Rc` can't be dereferenced in const,",
+                    " so `Rc`s can only be formatted at runtime.",
+                )]
+                pub fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 1] {
+                    [StdWrapper::<&$unref>(&**self.0).to_panicval(fmtarg)]
+                }
+            }
+
+            #[cfg(all(feature = "rust_1_64", target_has_atomic = "ptr"))]
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+            impl<'s> StdWrapper<&'s Arc<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Arc<", stringify!($ty), ">` to a `PanicVal` array.",
+                    "\n\nThis only outputs the pointed-to value, like the std `Debug` impl does.",
+                    "\n\nThis isn't a `const fn`, because `Arc` can't be dereferenced in const,",
+                    " so `Arc`s can only be formatted at runtime.",
+                )]
+                pub fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 1] {
+                    [StdWrapper::<&$unref>(&**self.0).to_panicval(fmtarg)]
+                }
+            }
+        )*
+    };
+}
+
+impl_for_pointers! {
+    (bool, bool)
+    (u8, u8)
+    (u16, u16)
+    (u32, u32)
+    (u64, u64)
+    (u128, u128)
+    (i8, i8)
+    (i16, i16)
+    (i32, i32)
+    (i64, i64)
+    (i128, i128)
+    (isize, isize)
+    (usize, usize)
+    (f32, f32)
+    (f64, f64)
+    (char, char)
+    (&'s str, str)
+    (str, str)
+}
+
+#[cfg(feature = "rust_1_88")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "rust_1_88")))
+)]
+#[clippy::msrv = "1.88"]
+impl PanicFmt for String {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
+#[cfg(feature = "rust_1_88")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "rust_1_88")))
+)]
+#[clippy::msrv = "1.88"]
+impl<'s> StdWrapper<&'s String> {
+    /// Formats this `String` into a single-`PanicVal` array
+    pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'s>; 1] {
+        [self.to_panicval(f)]
+    }
+    /// Formats this `String` into a `PanicVal`
+    pub const fn to_panicval(self, f: FmtArg) -> PanicVal<'s> {
+        PanicVal::from_str(self.0.as_str(), f)
+    }
+}
+
+#[cfg(feature = "rust_1_88")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "rust_1_88")))
+)]
+#[clippy::msrv = "1.88"]
+impl PanicFmt for Cow<'_, str> {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
+#[cfg(feature = "rust_1_88")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "rust_1_88")))
+)]
+#[clippy::msrv = "1.88"]
+impl<'s> StdWrapper<&'s Cow<'_, str>> {
+    /// Formats this `Cow<str>` into a single-`PanicVal` array
+    pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'s>; 1] {
+        [self.to_panicval(f)]
+    }
+    /// Formats this `Cow<str>` into a `PanicVal`
+    pub const fn to_panicval(self, f: FmtArg) -> PanicVal<'s> {
+        let str: &'s str = match self.0 {
+            Cow::Borrowed(x) => x,
+            Cow::Owned(x) => x.as_str(),
+        };
+        PanicVal::from_str(str, f)
+    }
+}
+
+#[cfg(all(feature = "non_basic", feature = "rust_1_88"))]
+macro_rules! impl_for_vecs {
+    ($($ty:ty)*) => {
+        $(
+            #[cfg_attr(
+                feature = "docsrs",
+                doc(cfg(all(feature = "alloc", feature = "non_basic", feature = "rust_1_88")))
+            )]
+            #[clippy::msrv = "1.88"]
+            impl<'s> PanicFmt for Vec<$ty> {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = 1;
+            }
+
+            #[cfg_attr(
+                feature = "docsrs",
+                doc(cfg(all(feature = "alloc", feature = "non_basic", feature = "rust_1_88")))
+            )]
+            #[clippy::msrv = "1.88"]
+            impl<'s> StdWrapper<&'s Vec<$ty>> {
+                /// Converts the `Vec` to a single-element `PanicVal` array.
+                pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'s>; 1] {
+                    [self.to_panicval(f)]
+                }
+                /// Converts the `Vec` to a `PanicVal`.
+                pub const fn to_panicval(self, f: FmtArg) -> PanicVal<'s> {
+                    StdWrapper(self.0.as_slice()).to_panicval(f)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "non_basic", feature = "rust_1_88"))]
+impl_for_vecs! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    f32 f64 bool char &'s str
+}
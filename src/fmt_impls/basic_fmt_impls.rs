@@ -1,4 +1,5 @@
 use crate::{
+    float_formatting::FloatVal,
     panic_val::{IntVal, PanicVal, PanicVariant, StrFmt},
     utils::Packed,
     FmtArg, PanicFmt, StdWrapper,
@@ -131,16 +132,37 @@ impl_panicfmt_int! {from_i64, from_i128, i64}
 impl_panicfmt_int! {from_i128, from_i128, i128}
 impl_panicfmt_int! {from_isize, from_i128, isize}
 
+macro_rules! impl_panicfmt_float {
+    ($panic_arg_ctor:ident, $ty:ty) => {
+        impl PanicVal<'_> {
+            /// Constructs this `PanicVal` from a floating point number.
+            pub const fn $panic_arg_ctor(this: $ty, f: FmtArg) -> PanicVal<'static> {
+                FloatVal::$panic_arg_ctor(this, f)
+            }
+        }
+
+        primitive_static_panicfmt! {
+            fn[](&self: $ty, f) {
+                PanicVal::$panic_arg_ctor(*self.0, f)
+            }
+        }
+    };
+}
+
+impl_panicfmt_float! {from_f32, f32}
+impl_panicfmt_float! {from_f64, f64}
+
 impl_panicfmt_panicarg! {
-    fn from_bool[](this: bool, _f) -> PanicVal<'static> {
-        PanicVal::write_str(if this { "true" } else { "false" })
+    fn from_bool[](this: bool, f) -> PanicVal<'static> {
+        PanicVal::from_str(if this { "true" } else { "false" }, f.set_display())
     }
 }
 
 impl<'a> PanicVal<'a> {
     /// Constructs a `PanicVal` from a `&str`
     pub const fn from_str(this: &'a str, f: FmtArg) -> PanicVal<'a> {
-        PanicVal::__new(PanicVariant::Str(StrFmt::new(f), Packed(this)))
+        let (strfmt, this) = StrFmt::for_str(f, this);
+        PanicVal::__new(PanicVariant::Str(strfmt, Packed(this)))
     }
 }
 
@@ -0,0 +1,205 @@
+use crate::{
+    fmt::{Alignment, FmtArg, FmtKind, PanicFmt},
+    panic_val::{PanicVariant, StrFmt},
+    utils::{string_cap, Packed, PackedChar, TailShortString},
+    PanicVal, StdWrapper,
+};
+
+use core::time::Duration;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
+
+// the maximum amount of fractional digits that are computed,
+// larger precisions are padded with zeroes.
+const MAX_FRAC_DIGITS: usize = 9;
+
+impl PanicFmt for Duration {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 4;
+}
+
+impl StdWrapper<&Duration> {
+    /// Converts this `Duration` to a `PanicVal` array.
+    ///
+    /// This is formatted like the `Debug` impl of `Duration`,
+    /// using the largest unit that the `Duration` is at least one of,
+    /// eg: `1.5s`, `250ms`, `3.000001µs`, `0ns`.
+    ///
+    /// The [`precision`](FmtArg::precision) limits the amount of
+    /// fractional digits (rounding the last one, with ties rounded to even),
+    /// and the [`width`](FmtArg::width) pads the whole `Duration`
+    /// (left-aligned by default), like in std.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::concat_;
+    ///
+    /// use core::time::Duration;
+    ///
+    /// const TIMEOUT: Duration = Duration::from_micros(1_500_250);
+    ///
+    /// assert_eq!(concat_!(TIMEOUT), "1.50025s");
+    /// assert_eq!(concat_!({.2}: TIMEOUT), "1.50s");
+    /// assert_eq!(concat_!({>8.1}: TIMEOUT), "    1.5s");
+    /// assert_eq!(concat_!(Duration::from_nanos(3_000_001)), "3.000001ms");
+    ///
+    /// ```
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; 4] {
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+
+        if secs > 0 {
+            fmt_decimal(secs, nanos, NANOS_PER_SEC / 10, "s", fmtarg)
+        } else if nanos >= NANOS_PER_MILLI {
+            fmt_decimal(
+                (nanos / NANOS_PER_MILLI) as u64,
+                nanos % NANOS_PER_MILLI,
+                NANOS_PER_MILLI / 10,
+                "ms",
+                fmtarg,
+            )
+        } else if nanos >= NANOS_PER_MICRO {
+            fmt_decimal(
+                (nanos / NANOS_PER_MICRO) as u64,
+                nanos % NANOS_PER_MICRO,
+                NANOS_PER_MICRO / 10,
+                "µs",
+                fmtarg,
+            )
+        } else {
+            fmt_decimal(nanos as u64, 0, 1, "ns", fmtarg)
+        }
+    }
+}
+
+// Formats `integer_part` followed by the decimal digits of `fractional_part`,
+// where `divisor` is the value of the first fractional digit.
+//
+// This is a port of the `fmt_decimal` function in the `Debug` impl of `Duration`.
+const fn fmt_decimal(
+    integer_part: u64,
+    mut fractional_part: u32,
+    mut divisor: u32,
+    postfix: &'static str,
+    fmtarg: FmtArg,
+) -> [PanicVal<'static>; 4] {
+    let max_digits = match fmtarg.precision() {
+        Some(precision) if (precision as usize) < MAX_FRAC_DIGITS => precision as usize,
+        _ => MAX_FRAC_DIGITS,
+    };
+
+    let mut digits = [b'0'; MAX_FRAC_DIGITS];
+    let mut digit_count = 0;
+    while fractional_part > 0 && digit_count < max_digits {
+        digits[digit_count] = b'0' + (fractional_part / divisor) as u8;
+        fractional_part %= divisor;
+        divisor /= 10;
+        digit_count += 1;
+    }
+
+    let mut integer_part = integer_part as u128;
+
+    // rounds up the digits if the remaining fractional part is more than half a digit,
+    // rounding ties to the even digit.
+    let last_digit_is_odd = if digit_count == 0 {
+        integer_part % 2 == 1
+    } else {
+        digits[digit_count - 1] % 2 == 1
+    };
+    let half_digit = divisor * 5;
+    if fractional_part > 0
+        && (fractional_part > half_digit || (fractional_part == half_digit && last_digit_is_odd))
+    {
+        let mut i = digit_count;
+        let mut carry = true;
+        while carry && i > 0 {
+            i -= 1;
+            if digits[i] < b'9' {
+                digits[i] += 1;
+                carry = false;
+            } else {
+                digits[i] = b'0';
+            }
+        }
+        if carry {
+            integer_part += 1;
+        }
+    }
+
+    // the amount of written digits, and the amount that it's zero-padded to
+    let (shown_digits, frac_width) = match fmtarg.precision() {
+        Some(precision) => (
+            crate::utils::min_usize(precision as usize, MAX_FRAC_DIGITS),
+            precision as usize,
+        ),
+        None => (digit_count, digit_count),
+    };
+
+    let fraction = if shown_digits == 0 {
+        PanicVal::EMPTY
+    } else {
+        const CAP: usize = string_cap::PREFMT;
+
+        let start = CAP - shown_digits - 1;
+        let mut buffer = [0u8; CAP];
+        buffer[start] = b'.';
+
+        let mut i = 0;
+        while i < shown_digits {
+            buffer[start + 1 + i] = digits[i];
+            i += 1;
+        }
+
+        let strfmt = StrFmt {
+            leftpad: 0,
+            rightpad: (frac_width - shown_digits) as u8,
+            fmt_kind: FmtKind::Display,
+            fill: PackedChar::new('0'),
+        };
+
+        // SAFETY: the buffer is ascii starting from `start`
+        let string = unsafe { TailShortString::new(start as u16, buffer) };
+
+        PanicVal::__new(PanicVariant::PreFmt(strfmt, string))
+    };
+
+    let prefix = if fmtarg.sign_plus() { "+" } else { "" };
+
+    let mut char_len = prefix.len() + crate::utils::char_count(postfix.as_bytes());
+    let mut n = integer_part;
+    loop {
+        char_len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if shown_digits != 0 {
+        char_len += 1 + frac_width;
+    }
+
+    let padding = StrFmt::padded(fmtarg, FmtKind::Display, char_len, Alignment::Left);
+
+    [
+        PanicVal::__new(PanicVariant::Str(
+            StrFmt {
+                rightpad: 0,
+                ..padding
+            },
+            Packed(prefix),
+        )),
+        PanicVal::from_u128(integer_part, FmtArg::DISPLAY),
+        fraction,
+        PanicVal::__new(PanicVariant::Str(
+            StrFmt {
+                leftpad: 0,
+                ..padding
+            },
+            Packed(postfix),
+        )),
+    ]
+}
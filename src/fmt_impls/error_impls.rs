@@ -0,0 +1,157 @@
+use crate::{
+    fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind, PanicFmt},
+    PanicVal, StdWrapper,
+};
+
+use core::{
+    alloc::{Layout, LayoutError},
+    array::TryFromSliceError,
+    char::{CharTryFromError, ParseCharError},
+    num::{FpCategory, TryFromIntError},
+    str::ParseBoolError,
+};
+
+// Implements formatting for errors that are debug formatted as a tuple struct
+// with a private `()` field.
+macro_rules! unit_field_error_impls {
+    ($($ty:ident => $display:expr;)*) => {
+        $(
+            impl PanicFmt for $ty {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = ComputePvCount {
+                    field_amount: 1,
+                    summed_pv_count: <()>::PV_COUNT,
+                    delimiter: cfmt::TypeDelim::Tupled,
+                }
+                .call();
+            }
+
+            impl StdWrapper<&$ty> {
+                #[doc = concat!(
+                    "Formats a `", stringify!($ty), "` ",
+                    "(supports both Debug and Display formatting)."
+                )]
+                pub const fn to_panicvals(
+                    self,
+                    fmtarg: FmtArg,
+                ) -> [PanicVal<'static>; $ty::PV_COUNT] {
+                    match fmtarg.fmt_kind {
+                        FmtKind::Display => {
+                            crate::utils::flatten_panicvals(&[&[PanicVal::write_str($display)]])
+                        }
+                        FmtKind::Debug => flatten_panicvals! {fmtarg;
+                            PanicVal::write_str(stringify!($ty)),
+                            open: cfmt::OpenParen,
+                                () => (), cfmt::COMMA_TERM,
+                            close: cfmt::CloseParen,
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+unit_field_error_impls! {
+    TryFromIntError => "out of range integral type conversion attempted";
+    TryFromSliceError => "could not convert slice to array";
+    CharTryFromError => "converted integer out of range for `char`";
+}
+
+primitive_static_panicfmt! {
+    fn[](&self: ParseBoolError, f) {
+        PanicVal::write_str(match f.fmt_kind {
+            FmtKind::Display => "provided string was not `true` or `false`",
+            FmtKind::Debug => "ParseBoolError",
+        })
+    }
+}
+
+/// Note: the kind of error in a `ParseCharError` can't be read in const,
+/// so this type is formatted as `ParseCharError { .. }` when Debug formatted,
+/// and as `cannot parse char from string` when Display formatted.
+///
+impl PanicFmt for ParseCharError {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
+impl StdWrapper<&ParseCharError> {
+    /// Formats a `ParseCharError` (supports both Debug and Display formatting).
+    ///
+    /// The kind of error can't be read in const,
+    /// so this outputs `ParseCharError { .. }` when Debug formatted,
+    /// and `cannot parse char from string` when Display formatted.
+    pub const fn to_panicvals(
+        self,
+        fmtarg: FmtArg,
+    ) -> [PanicVal<'static>; ParseCharError::PV_COUNT] {
+        [self.to_panicval(fmtarg)]
+    }
+
+    /// Formats a `ParseCharError` (supports both Debug and Display formatting).
+    ///
+    /// The kind of error can't be read in const,
+    /// so this outputs `ParseCharError { .. }` when Debug formatted,
+    /// and `cannot parse char from string` when Display formatted.
+    pub const fn to_panicval(self, fmtarg: FmtArg) -> PanicVal<'static> {
+        PanicVal::write_str(match fmtarg.fmt_kind {
+            FmtKind::Display => "cannot parse char from string",
+            FmtKind::Debug => "ParseCharError { .. }",
+        })
+    }
+}
+
+impl PanicFmt for Layout {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    // the alignment is formatted like `8 (1 << 3)`, taking 4 `PanicVal`s
+    const PV_COUNT: usize = ComputePvCount {
+        field_amount: 2,
+        summed_pv_count: <usize>::PV_COUNT + 4,
+        delimiter: cfmt::TypeDelim::Braced,
+    }
+    .call();
+}
+
+impl StdWrapper<&Layout> {
+    /// Formats a `Layout` (supports only Debug formatting).
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; Layout::PV_COUNT] {
+        let align = self.0.align();
+
+        // std formats the alignment ignoring the formatting flags
+        let log2 = PanicVal::from_u32(align.trailing_zeros(), FmtArg::DEBUG);
+        let align = PanicVal::from_usize(align, FmtArg::DEBUG);
+
+        flatten_panicvals! {fmtarg;
+            "Layout",
+            open: cfmt::OpenBrace,
+                "size: ", usize => self.0.size(), cfmt::COMMA_SEP,
+                "align: ", align, " (1 << ", log2, ")", cfmt::COMMA_TERM,
+            close: cfmt::CloseBrace,
+        }
+    }
+}
+
+primitive_static_panicfmt! {
+    fn[](&self: LayoutError, f) {
+        PanicVal::write_str(match f.fmt_kind {
+            FmtKind::Display => "invalid parameters to Layout::from_size_align",
+            FmtKind::Debug => "LayoutError",
+        })
+    }
+}
+
+primitive_static_panicfmt! {
+    fn[](&self: FpCategory, _f) {
+        PanicVal::write_str(match *self.0 {
+            FpCategory::Nan => "Nan",
+            FpCategory::Infinite => "Infinite",
+            FpCategory::Zero => "Zero",
+            FpCategory::Subnormal => "Subnormal",
+            FpCategory::Normal => "Normal",
+        })
+    }
+}
@@ -1,33 +1,87 @@
-use crate::{FmtArg, PanicFmt, PanicVal, StdWrapper};
+use crate::{
+    fmt::{IsCustomType, RangeFmt},
+    FmtArg, PanicFmt, PanicVal, StdWrapper,
+};
 
 use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
 macro_rules! impl_range_panicfmt_one {
     (
+        $kind:ident
         fn(&$self:ident: $ty:ty, $f:ident) -> $pv_count:literal {
             $($content:tt)*
         }
     ) => {
+        impl crate::StdWrapper<&$ty> {
+            #[doc = concat!("Converts this `", stringify!($ty), "` to a `PanicVal` array.")]
+            pub const fn to_panicvals($self, $f: FmtArg) -> [PanicVal<'static>; $pv_count] {
+                $($content)*
+            }
+        }
+
+        impl PanicFmt for RangeFmt<$ty> {
+            type This = Self;
+            type Kind = IsCustomType;
+            const PV_COUNT: usize = $pv_count;
+        }
+
+        impl RangeFmt<$ty> {
+            #[doc = concat!(
+                "Converts this `RangeFmt<", stringify!($ty), ">` to a `PanicVal` array."
+            )]
+            pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; $pv_count] {
+                StdWrapper(&self.0).to_panicvals(f)
+            }
+        }
+
+        impl_range_panicfmt_one! {@std_impls $kind $ty, $pv_count}
+    };
+    // ranges of other types than `usize` don't implement `PanicFmt`,
+    // so that unsuffixed integer ranges are inferred to be `usize` ranges.
+    (@std_impls opt_in $($_:tt)*) => {};
+    (@std_impls std $ty:ty, $pv_count:literal) => {
         impl PanicFmt for $ty {
             type This = Self;
             type Kind = crate::fmt::IsStdType;
             const PV_COUNT: usize = $pv_count;
         }
 
-        impl crate::StdWrapper<&$ty> {
+        impl crate::StdWrapper<&Option<$ty>> {
             #[doc = concat!(
-                "Converts this `", stringify!($ty), "` to a single-element `PanicVal` array."
+                "Converts this `Option<", stringify!($ty), ">` to a `PanicVal` array."
             )]
-            pub const fn to_panicvals($self, $f: FmtArg) -> [PanicVal<'static>; $pv_count] {
-                $($content)*
+            pub const fn to_panicvals(
+                self,
+                fmtarg: FmtArg,
+            ) -> [PanicVal<'static>; 4 + $pv_count] {
+                use crate::{fmt, utils::flatten_panicvals};
+
+                match self.0 {
+                    Some(x) => {
+                        let inner = fmtarg.indent();
+                        flatten_panicvals(&[
+                            &[
+                                PanicVal::write_str("Some"),
+                                fmt::OpenParen.to_panicval(inner),
+                            ],
+                            &StdWrapper(x).to_panicvals(inner),
+                            &[
+                                fmt::COMMA_TERM.to_panicval(inner),
+                                fmt::CloseParen.to_panicval(fmtarg),
+                            ],
+                        ])
+                    }
+                    None => flatten_panicvals(&[&[PanicVal::write_str("None")]]),
+                }
             }
         }
-    }
+    };
 }
 
 macro_rules! impl_range_panicfmt {
-    ($elem_ty:ty) => {
+    ($kind:ident $elem_ty:ty) => {
         impl_range_panicfmt_one! {
+            $kind
             fn(&self: Range<$elem_ty>, f) -> 3 {
                 [
                     StdWrapper(&self.0.start).to_panicval(f),
@@ -38,6 +92,7 @@ macro_rules! impl_range_panicfmt {
         }
 
         impl_range_panicfmt_one! {
+            $kind
             fn(&self: RangeFrom<$elem_ty>, f) -> 2 {
                 [
                     StdWrapper(&self.0.start).to_panicval(f),
@@ -47,6 +102,7 @@ macro_rules! impl_range_panicfmt {
         }
 
         impl_range_panicfmt_one! {
+            $kind
             fn(&self: RangeTo<$elem_ty>, f) -> 2 {
                 [
                     PanicVal::write_str(".."),
@@ -56,6 +112,7 @@ macro_rules! impl_range_panicfmt {
         }
 
         impl_range_panicfmt_one! {
+            $kind
             fn(&self: RangeToInclusive<$elem_ty>, f) -> 2 {
                 [
                     PanicVal::write_str("..="),
@@ -65,6 +122,7 @@ macro_rules! impl_range_panicfmt {
         }
 
         impl_range_panicfmt_one! {
+            $kind
             fn(&self: RangeInclusive<$elem_ty>, f) -> 3 {
                 [
                     StdWrapper(self.0.start()).to_panicval(f),
@@ -76,11 +134,24 @@ macro_rules! impl_range_panicfmt {
     };
 }
 
-impl_range_panicfmt! {usize}
+impl_range_panicfmt! {opt_in u8}
+impl_range_panicfmt! {opt_in u16}
+impl_range_panicfmt! {opt_in u32}
+impl_range_panicfmt! {opt_in u64}
+impl_range_panicfmt! {opt_in u128}
+impl_range_panicfmt! {std usize}
+impl_range_panicfmt! {opt_in i8}
+impl_range_panicfmt! {opt_in i16}
+impl_range_panicfmt! {opt_in i32}
+impl_range_panicfmt! {opt_in i64}
+impl_range_panicfmt! {opt_in i128}
+impl_range_panicfmt! {opt_in isize}
+impl_range_panicfmt! {opt_in char}
 
 ////////////////////////////////////////////////////////////////////////////////
 
 impl_range_panicfmt_one! {
+    std
     fn(&self: RangeFull, _f) -> 1 {
         [PanicVal::write_str("..")]
     }
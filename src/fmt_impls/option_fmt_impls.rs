@@ -1,7 +1,9 @@
 use crate::{FmtArg, PanicFmt, PanicVal};
 
 /// Note: there is only `to_panicvals` methods for `Option`s of standard library types
-/// for now.
+/// for now,
+/// `Option`s of other types can be formatted with the
+/// [`option_panicvals`](crate::option_panicvals) macro.
 ///
 impl<T> PanicFmt for Option<T>
 where
@@ -74,5 +76,7 @@ impl_for_option_outer! {
     ('static, i128, i128)
     ('static, isize, isize)
     ('static, usize, usize)
+    ('static, f32, f32)
+    ('static, f64, f64)
     ('s, &'s str, str)
 }
@@ -0,0 +1,128 @@
+use crate::{
+    fmt::{FmtArg, PanicFmt},
+    PanicVal, StdWrapper,
+};
+
+use typewit::TypeEq;
+
+// `Result`s are formatted with a generic const fn that has trait bounds,
+// which requires Rust 1.61.0.
+
+/// Note: `Result`s can only be formatted when both the `Ok` and `Err` types are
+/// `()`, `bool`, `char`, `&str`, or a primitive integer or float type.
+///
+/// `Result`s of other types can't be formatted with this impl,
+/// because a generic const fn can't call the `to_panicvals` method of a generic type,
+/// they can be formatted with the [`result_panicvals`](crate::result_panicvals) macro instead.
+///
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "non_basic", feature = "rust_1_64")))
+)]
+impl<'s, T, E> PanicFmt for Result<T, E>
+where
+    T: ResultPayload<'s>,
+    E: ResultPayload<'s>,
+{
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 5;
+}
+
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "non_basic", feature = "rust_1_64")))
+)]
+impl<'s, T, E> StdWrapper<&'s Result<T, E>>
+where
+    T: ResultPayload<'s>,
+    E: ResultPayload<'s>,
+{
+    /// Converts this `Result` to a `PanicVal` array.
+    pub const fn to_panicvals(self, mut fmtarg: FmtArg) -> [PanicVal<'s>; 5] {
+        use crate::fmt;
+
+        fmtarg = fmtarg.indent();
+        let (variant, payload) = match self.0 {
+            Ok(x) => ("Ok", payload_to_panicval(x, fmtarg)),
+            Err(x) => ("Err", payload_to_panicval(x, fmtarg)),
+        };
+
+        [
+            PanicVal::write_str(variant),
+            fmt::OpenParen.to_panicval(fmtarg),
+            payload,
+            fmt::COMMA_TERM.to_panicval(fmtarg),
+            fmt::CloseParen.to_panicval(fmtarg.unindent()),
+        ]
+    }
+}
+
+const fn payload_to_panicval<'s, T>(x: &'s T, fmtarg: FmtArg) -> PanicVal<'s>
+where
+    T: ResultPayload<'s>,
+{
+    macro_rules! match_witness {
+        ($($variant:ident)*) => {
+            match T::WITNESS {
+                $(
+                    ResultPayloadWitness::$variant(te) => {
+                        StdWrapper(te.in_ref().to_right(x)).to_panicval(fmtarg)
+                    }
+                )*
+                ResultPayloadWitness::Str(te) => {
+                    StdWrapper::<&str>(te.in_ref().to_right(x)).to_panicval(fmtarg)
+                }
+            }
+        };
+    }
+
+    match_witness! {Unit Bool Char U8 U16 U32 U64 U128 I8 I16 I32 I64 I128 Isize Usize F32 F64}
+}
+
+// Sealed trait for the types that `Result`s can be formatted with.
+//
+// The `'s` lifetime is the lifetime of the `&str` payload.
+#[doc(hidden)]
+pub trait ResultPayload<'s>: PanicFmt + Sized {
+    const WITNESS: ResultPayloadWitness<'s, Self>;
+}
+
+macro_rules! declare_result_payloads {
+    ($(($variant:ident, $ty:ty))*) => {
+        // A type witness for the types that `Result`s can be formatted with,
+        // which allows formatting them in generic const fns.
+        #[doc(hidden)]
+        pub enum ResultPayloadWitness<'s, T> {
+            $($variant(TypeEq<T, $ty>),)*
+        }
+
+        $(
+            impl<'s> ResultPayload<'s> for $ty {
+                const WITNESS: ResultPayloadWitness<'s, Self> =
+                    ResultPayloadWitness::$variant(TypeEq::NEW);
+            }
+        )*
+    };
+}
+
+declare_result_payloads! {
+    (Unit, ())
+    (Bool, bool)
+    (Char, char)
+    (U8, u8)
+    (U16, u16)
+    (U32, u32)
+    (U64, u64)
+    (U128, u128)
+    (I8, i8)
+    (I16, i16)
+    (I32, i32)
+    (I64, i64)
+    (I128, i128)
+    (Isize, isize)
+    (Usize, usize)
+    (F32, f32)
+    (F64, f64)
+    (Str, &'s str)
+}
@@ -0,0 +1,340 @@
+use crate::{
+    fmt::{Alignment, FmtArg, FmtKind, PanicFmt},
+    panic_val::{PanicVariant, StrFmt},
+    utils::Packed,
+    PanicVal, StdWrapper,
+};
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+// the amount of `PanicVal`s used by the text of an `Ipv4Addr`, without padding
+const IPV4_BODY: usize = 7;
+
+// the amount of `PanicVal`s used by the text of an `Ipv6Addr`, without padding
+const IPV6_BODY: usize = 15;
+
+// Copies the `PanicVal`s in `$src` to `$out`, starting at the `$start` index.
+macro_rules! copy_pvs {
+    ($out:ident[$start:expr] = $src:expr) => {{
+        let src = $src;
+        let mut i = 0;
+        while i < src.len() {
+            $out[$start + i] = src[i];
+            i += 1;
+        }
+    }};
+}
+
+const fn decimal_len(mut n: u32) -> usize {
+    let mut len = 1;
+    while n >= 10 {
+        n /= 10;
+        len += 1;
+    }
+    len
+}
+
+const fn hex_len(mut n: u16) -> usize {
+    let mut len = 1;
+    while n >= 16 {
+        n /= 16;
+        len += 1;
+    }
+    len
+}
+
+// The `PanicVal`s that pad the address to `fmtarg.width`,
+// written before and after the address respectively.
+//
+// Addresses are padded like strings, ignoring any other formatting flags, like in std.
+const fn padding(fmtarg: FmtArg, char_len: usize) -> (PanicVal<'static>, PanicVal<'static>) {
+    let padding = StrFmt::padded(fmtarg, FmtKind::Display, char_len, Alignment::Left);
+
+    (
+        PanicVal::__new(PanicVariant::Str(
+            StrFmt {
+                rightpad: 0,
+                ..padding
+            },
+            Packed(""),
+        )),
+        PanicVal::__new(PanicVariant::Str(
+            StrFmt {
+                leftpad: 0,
+                ..padding
+            },
+            Packed(""),
+        )),
+    )
+}
+
+// Formats the octets of an ipv4 address, returning them with the length of the text
+const fn ipv4_body(octets: [u8; 4]) -> ([PanicVal<'static>; IPV4_BODY], usize) {
+    let [a, b, c, d] = octets;
+    let dot = PanicVal::write_str(".");
+    let fmtarg = FmtArg::DISPLAY;
+
+    let pvs = [
+        PanicVal::from_u8(a, fmtarg),
+        dot,
+        PanicVal::from_u8(b, fmtarg),
+        dot,
+        PanicVal::from_u8(c, fmtarg),
+        dot,
+        PanicVal::from_u8(d, fmtarg),
+    ];
+
+    let len = 3
+        + decimal_len(a as u32)
+        + decimal_len(b as u32)
+        + decimal_len(c as u32)
+        + decimal_len(d as u32);
+
+    (pvs, len)
+}
+
+// Formats an ipv6 address as described in RFC 5952, like std does:
+// - ipv4-mapped addresses are written as `::ffff:a.b.c.d`
+// - the longest run of two or more zero segments is replaced with `::`
+//   (the first one if there's multiple runs of the same length)
+// - segments are written in lowercase hexadecimal, without leading zeroes
+//
+// Returns the `PanicVal`s with the length of the text
+const fn ipv6_body(ip: &Ipv6Addr) -> ([PanicVal<'static>; IPV6_BODY], usize) {
+    let mut out = [PanicVal::EMPTY; IPV6_BODY];
+    let segments = ip.segments();
+
+    if let [0, 0, 0, 0, 0, 0xffff, ab, cd] = segments {
+        let [a, b] = ab.to_be_bytes();
+        let [c, d] = cd.to_be_bytes();
+        let (ipv4, ipv4_len) = ipv4_body([a, b, c, d]);
+
+        out[0] = PanicVal::write_str("::ffff:");
+        copy_pvs! {out[1] = ipv4}
+
+        return (out, 7 + ipv4_len);
+    }
+
+    let mut zeroes_start = 0;
+    let mut zeroes_len = 0;
+    {
+        let mut current_start = 0;
+        let mut current_len = 0;
+        let mut i = 0;
+        while i < segments.len() {
+            if segments[i] == 0 {
+                if current_len == 0 {
+                    current_start = i;
+                }
+                current_len += 1;
+                if current_len > zeroes_len {
+                    zeroes_start = current_start;
+                    zeroes_len = current_len;
+                }
+            } else {
+                current_len = 0;
+            }
+            i += 1;
+        }
+    }
+
+    // a single zero segment isn't compressed
+    if zeroes_len < 2 {
+        zeroes_start = segments.len();
+        zeroes_len = 0;
+    }
+
+    let hex = FmtArg::DISPLAY.set_lower_hex();
+    let mut len = 0;
+    let mut pv_i = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        if i == zeroes_start {
+            out[pv_i] = PanicVal::write_str("::");
+            pv_i += 1;
+            len += 2;
+            i += zeroes_len;
+            continue;
+        }
+
+        if i != 0 && i != zeroes_start + zeroes_len {
+            out[pv_i] = PanicVal::write_str(":");
+            pv_i += 1;
+            len += 1;
+        }
+
+        out[pv_i] = PanicVal::from_u16(segments[i], hex);
+        pv_i += 1;
+        len += hex_len(segments[i]);
+        i += 1;
+    }
+
+    (out, len)
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for Ipv4Addr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = IPV4_BODY + 2;
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&Ipv4Addr> {
+    /// Formats an `Ipv4Addr` (supports both Debug and Display formatting),
+    /// eg: `10.0.0.1`.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; Ipv4Addr::PV_COUNT] {
+        let (body, len) = ipv4_body(self.0.octets());
+        let (prefix, postfix) = padding(fmtarg, len);
+
+        let mut out = [PanicVal::EMPTY; Ipv4Addr::PV_COUNT];
+        out[0] = prefix;
+        copy_pvs! {out[1] = body}
+        out[1 + IPV4_BODY] = postfix;
+        out
+    }
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for Ipv6Addr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = IPV6_BODY + 2;
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&Ipv6Addr> {
+    /// Formats an `Ipv6Addr` (supports both Debug and Display formatting).
+    ///
+    /// This uses the [RFC 5952] representation, like std,
+    /// eg: `2001:db8::1`, `::1`, `::ffff:10.0.0.1`.
+    ///
+    /// [RFC 5952]: https://www.rfc-editor.org/rfc/rfc5952
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; Ipv6Addr::PV_COUNT] {
+        let (body, len) = ipv6_body(self.0);
+        let (prefix, postfix) = padding(fmtarg, len);
+
+        let mut out = [PanicVal::EMPTY; Ipv6Addr::PV_COUNT];
+        out[0] = prefix;
+        copy_pvs! {out[1] = body}
+        out[1 + IPV6_BODY] = postfix;
+        out
+    }
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for IpAddr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = crate::utils::max_usize(Ipv4Addr::PV_COUNT, Ipv6Addr::PV_COUNT);
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&IpAddr> {
+    /// Formats an `IpAddr` (supports both Debug and Display formatting),
+    /// formatting the address like the `Ipv4Addr`/`Ipv6Addr` impls.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; IpAddr::PV_COUNT] {
+        match self.0 {
+            IpAddr::V4(ip) => {
+                let mut out = [PanicVal::EMPTY; IpAddr::PV_COUNT];
+                copy_pvs! {out[0] = StdWrapper(ip).to_panicvals(fmtarg)}
+                out
+            }
+            IpAddr::V6(ip) => StdWrapper(ip).to_panicvals(fmtarg),
+        }
+    }
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for SocketAddrV4 {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = IPV4_BODY + 4;
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&SocketAddrV4> {
+    /// Formats a `SocketAddrV4` (supports both Debug and Display formatting),
+    /// eg: `10.0.0.1:8080`.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; SocketAddrV4::PV_COUNT] {
+        let (body, ip_len) = ipv4_body(self.0.ip().octets());
+        let port = self.0.port();
+        let (prefix, postfix) = padding(fmtarg, ip_len + 1 + decimal_len(port as u32));
+
+        let mut out = [PanicVal::EMPTY; SocketAddrV4::PV_COUNT];
+        out[0] = prefix;
+        copy_pvs! {out[1] = body}
+        out[1 + IPV4_BODY] = PanicVal::write_str(":");
+        out[2 + IPV4_BODY] = PanicVal::from_u16(port, FmtArg::DISPLAY);
+        out[3 + IPV4_BODY] = postfix;
+        out
+    }
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for SocketAddrV6 {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = IPV6_BODY + 7;
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&SocketAddrV6> {
+    /// Formats a `SocketAddrV6` (supports both Debug and Display formatting),
+    /// eg: `[2001:db8::1]:8080`, `[fe80::1%2]:8080`.
+    ///
+    /// The scope id is only written when it's nonzero, like in std.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; SocketAddrV6::PV_COUNT] {
+        let (body, ip_len) = ipv6_body(self.0.ip());
+        let port = self.0.port();
+        let scope_id = self.0.scope_id();
+
+        let mut len = ip_len + 3 + decimal_len(port as u32);
+
+        let (percent, scope_id_pv) = if scope_id == 0 {
+            (PanicVal::EMPTY, PanicVal::EMPTY)
+        } else {
+            len += 1 + decimal_len(scope_id);
+            (
+                PanicVal::write_str("%"),
+                PanicVal::from_u32(scope_id, FmtArg::DISPLAY),
+            )
+        };
+
+        let (prefix, postfix) = padding(fmtarg, len);
+
+        let mut out = [PanicVal::EMPTY; SocketAddrV6::PV_COUNT];
+        out[0] = prefix;
+        out[1] = PanicVal::write_str("[");
+        copy_pvs! {out[2] = body}
+        out[2 + IPV6_BODY] = percent;
+        out[3 + IPV6_BODY] = scope_id_pv;
+        out[4 + IPV6_BODY] = PanicVal::write_str("]:");
+        out[5 + IPV6_BODY] = PanicVal::from_u16(port, FmtArg::DISPLAY);
+        out[6 + IPV6_BODY] = postfix;
+        out
+    }
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl PanicFmt for SocketAddr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = crate::utils::max_usize(SocketAddrV4::PV_COUNT, SocketAddrV6::PV_COUNT);
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_77")))]
+impl StdWrapper<&SocketAddr> {
+    /// Formats a `SocketAddr` (supports both Debug and Display formatting),
+    /// formatting the address like the `SocketAddrV4`/`SocketAddrV6` impls.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; SocketAddr::PV_COUNT] {
+        match self.0 {
+            SocketAddr::V4(addr) => {
+                let mut out = [PanicVal::EMPTY; SocketAddr::PV_COUNT];
+                copy_pvs! {out[0] = StdWrapper(addr).to_panicvals(fmtarg)}
+                out
+            }
+            SocketAddr::V6(addr) => StdWrapper(addr).to_panicvals(fmtarg),
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use crate::{
-    fmt::{FmtArg, FmtKind, PanicFmt},
+    fmt::{Alignment, FmtArg, FmtKind, PanicFmt},
+    panic_val::{BytesFmt, PanicVariant, StrFmt},
+    utils::{bytes_up_to, Packed},
     PanicVal, StdWrapper,
 };
 
-use core::num::{IntErrorKind, ParseIntError};
+use core::{
+    ffi::CStr,
+    num::{IntErrorKind, ParseIntError},
+};
 
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_82")))]
 impl PanicFmt for ParseIntError {
@@ -78,3 +83,49 @@ impl StdWrapper<&IntErrorKind> {
         })
     }
 }
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_82")))]
+impl PanicFmt for CStr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_82")))]
+impl<'s> StdWrapper<&'s CStr> {
+    /// Formats a `CStr` (supports both Debug and Display formatting).
+    ///
+    /// `Display` formatting replaces invalid utf8 with `U+FFFD`,
+    /// and `Debug` formatting escapes invalid utf8 as bytes, eg: `"hi\xff"`.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; CStr::PV_COUNT] {
+        [self.to_panicval(fmtarg)]
+    }
+
+    /// Formats a `CStr` (supports both Debug and Display formatting).
+    ///
+    /// `Display` formatting replaces invalid utf8 with `U+FFFD`,
+    /// and `Debug` formatting escapes invalid utf8 as bytes, eg: `"hi\xff"`.
+    ///
+    /// Like `str`s, `Display` formatted `CStr`s are truncated to the
+    /// [`precision`](FmtArg::precision) and padded to the [`width`](FmtArg::width),
+    /// while `Debug` formatted `CStr`s are neither truncated nor padded (like in std).
+    pub const fn to_panicval(self, fmtarg: FmtArg) -> PanicVal<'s> {
+        let bytes = self.0.to_bytes();
+
+        let (strfmt, bytes_fmt, bytes) = match fmtarg.fmt_kind {
+            FmtKind::Display => {
+                let max_chars = match fmtarg.precision() {
+                    Some(precision) => precision as usize,
+                    None => usize::MAX,
+                };
+                let (len, char_len) = crate::utils::lossy_chars_len(bytes, max_chars);
+                let strfmt = StrFmt::padded(fmtarg, FmtKind::Display, char_len, Alignment::Left);
+
+                (strfmt, BytesFmt::Lossy, bytes_up_to(bytes, len))
+            }
+            FmtKind::Debug => (StrFmt::new(fmtarg), BytesFmt::Escaped, bytes),
+        };
+
+        PanicVal::__new(PanicVariant::Bytes(strfmt, bytes_fmt, Packed(bytes)))
+    }
+}
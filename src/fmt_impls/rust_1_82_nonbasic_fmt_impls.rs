@@ -0,0 +1,55 @@
+use crate::{
+    fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind, PanicFmt},
+    PanicVal, StdWrapper,
+};
+
+use core::panic::Location;
+
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "non_basic", feature = "rust_1_82")))
+)]
+impl PanicFmt for Location<'_> {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = ComputePvCount {
+        field_amount: 3,
+        summed_pv_count: <str>::PV_COUNT + <u32>::PV_COUNT + <u32>::PV_COUNT,
+        delimiter: cfmt::TypeDelim::Braced,
+    }
+    .call();
+}
+
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "non_basic", feature = "rust_1_82")))
+)]
+impl<'a> StdWrapper<&'a Location<'_>> {
+    /// Formats a `Location` (supports both Debug and Display formatting).
+    ///
+    /// `Display` formatting outputs `file:line:column`, ignoring the `fmtarg` options
+    /// for the line and column numbers, like in std.
+    pub const fn to_panicvals(
+        self,
+        fmtarg: FmtArg,
+    ) -> [PanicVal<'a>; <Location<'static>>::PV_COUNT] {
+        let this = self.0;
+        match fmtarg.fmt_kind {
+            FmtKind::Display => crate::utils::flatten_panicvals(&[&[
+                PanicVal::write_str(this.file()),
+                PanicVal::write_str(":"),
+                PanicVal::from_u32(this.line(), FmtArg::DISPLAY),
+                PanicVal::write_str(":"),
+                PanicVal::from_u32(this.column(), FmtArg::DISPLAY),
+            ]]),
+            FmtKind::Debug => flatten_panicvals! {fmtarg;
+                "Location",
+                open: cfmt::OpenBrace,
+                    "file: ", str => this.file(), cfmt::COMMA_SEP,
+                    "line: ", u32 => this.line(), cfmt::COMMA_SEP,
+                    "column: ", u32 => this.column(), cfmt::COMMA_TERM,
+                close: cfmt::CloseBrace,
+            },
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    fmt::{ComputePvCount, FmtArg, PanicFmt, TypeDelim},
+    PanicVal,
+};
+
+macro_rules! impl_tuple_panicfmt {
+    ($(($($elem:ident)*))*) => {
+        $(
+            /// Note: because `const fn`s can't call the formatting methods of
+            /// generic types, tuples don't have a `to_panicvals` method.
+            /// They can be formatted when they're written as tuple expressions
+            /// in the arguments of the [`concat_panic`](crate::concat_panic)
+            /// (and related) macros, eg: `concat_panic!{"pair: ", (a, b)}`,
+            /// and with the [`tuple_panicvals`](crate::tuple_panicvals) macro otherwise.
+            ///
+            impl<$($elem: PanicFmt,)*> PanicFmt for ($($elem,)*) {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = ComputePvCount {
+                    field_amount: [$(stringify!($elem),)*].len(),
+                    summed_pv_count: 0 $( + $elem::PV_COUNT)*,
+                    delimiter: TypeDelim::Tupled,
+                }
+                .call();
+            }
+        )*
+    };
+}
+
+impl_tuple_panicfmt! {
+    (A)
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+    (A B C D E F G H I)
+    (A B C D E F G H I J)
+    (A B C D E F G H I J K)
+    (A B C D E F G H I J K L)
+}
+
+// The comma after the only element of a 1-tuple,
+// which (unlike the `fmt::COMMA_TERM` separator) is also written without the
+// alternate flag, eg: `(3,)`.
+#[doc(hidden)]
+pub const fn single_tuple_comma(fmtarg: FmtArg) -> PanicVal<'static> {
+    if fmtarg.is_alternate {
+        crate::fmt::COMMA_TERM.to_panicval(fmtarg)
+    } else {
+        PanicVal::write_str(",")
+    }
+}
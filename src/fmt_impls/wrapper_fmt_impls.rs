@@ -0,0 +1,249 @@
+use crate::{
+    fmt::{self as cfmt, ComputePvCount, FmtArg, PanicFmt, TypeDelim},
+    PanicVal, StdWrapper,
+};
+
+use core::{
+    cmp::Reverse,
+    mem::ManuallyDrop,
+    num::Wrapping,
+    ops::{Bound, ControlFlow},
+    task::Poll,
+};
+
+#[cfg(feature = "rust_1_82")]
+use core::num::Saturating;
+
+// the `PV_COUNT` of a tuple struct or variant with a single field
+const fn single_field_pv_count(field_pv_count: usize, delimiter: TypeDelim) -> usize {
+    ComputePvCount {
+        field_amount: 1,
+        summed_pv_count: field_pv_count,
+        delimiter,
+    }
+    .call()
+}
+
+macro_rules! impl_wrapper_panicfmt {
+    ($(
+        $(#[$attr:meta])*
+        impl[$($generics:tt)*] $ty:ty => $pv_count:expr;
+    )*) => {
+        $(
+            $(#[$attr])*
+            /// Note: there is only `to_panicvals` methods for this type wrapping
+            /// standard library types for now.
+            ///
+            impl<$($generics)*> PanicFmt for $ty {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = $pv_count;
+            }
+        )*
+    };
+}
+
+impl_wrapper_panicfmt! {
+    // only the wrapped value is formatted, like in std
+    impl[T: PanicFmt] Wrapping<T> => T::PV_COUNT;
+
+    #[cfg(feature = "rust_1_82")]
+    #[cfg_attr(
+        feature = "docsrs",
+        doc(cfg(all(feature = "non_basic", feature = "rust_1_82")))
+    )]
+    #[clippy::msrv = "1.82"]
+    // only the wrapped value is formatted, like in std
+    impl[T: PanicFmt] Saturating<T> => T::PV_COUNT;
+
+    impl[T: PanicFmt] Reverse<T> => single_field_pv_count(T::PV_COUNT, TypeDelim::Tupled);
+
+    impl[T: PanicFmt] ManuallyDrop<T> => single_field_pv_count(T::PV_COUNT, TypeDelim::Braced);
+
+    impl[T: PanicFmt] Bound<T> => single_field_pv_count(T::PV_COUNT, TypeDelim::Tupled);
+
+    impl[B: PanicFmt, C: PanicFmt] ControlFlow<B, C> => single_field_pv_count(
+        crate::utils::max_usize(B::PV_COUNT, C::PV_COUNT),
+        TypeDelim::Tupled,
+    );
+
+    impl[T: PanicFmt] Poll<T> => single_field_pv_count(T::PV_COUNT, TypeDelim::Tupled);
+}
+
+// Formats a tuple struct or variant with a single field.
+macro_rules! single_field_tupled {
+    ($fmtarg:ident, $name:expr, $field:expr) => {
+        flatten_panicvals! {$fmtarg;
+            $name,
+            open: cfmt::OpenParen,
+                $field, cfmt::COMMA_TERM,
+            close: cfmt::CloseParen,
+        }
+    };
+}
+
+// Implements `to_panicvals` for the wrappers of a single standard library type,
+// which output 5 `PanicVal`s when tupled, and 6 when braced.
+macro_rules! impl_for_wrappers {
+    ($(($ty:ty, $unref:ty))*) => {
+        $(
+            impl<'s> StdWrapper<&'s Wrapping<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Wrapping<", stringify!($ty), ">` to a `PanicVal` array.",
+                    "\n\nThis only outputs the wrapped value, like the std `Debug` impl does."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 1] {
+                    let Wrapping(x) = self.0;
+                    [StdWrapper::<&$unref>(x).to_panicval(fmtarg)]
+                }
+            }
+
+            #[cfg(feature = "rust_1_82")]
+            #[cfg_attr(
+                feature = "docsrs",
+                doc(cfg(all(feature = "non_basic", feature = "rust_1_82")))
+            )]
+            #[clippy::msrv = "1.82"]
+            impl<'s> StdWrapper<&'s Saturating<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Saturating<", stringify!($ty), ">` to a `PanicVal` array.",
+                    "\n\nThis only outputs the wrapped value, like the std `Debug` impl does."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 1] {
+                    let Saturating(x) = self.0;
+                    [StdWrapper::<&$unref>(x).to_panicval(fmtarg)]
+                }
+            }
+
+            impl<'s> StdWrapper<&'s Reverse<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Reverse<", stringify!($ty), ">` to a `PanicVal` array."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 5] {
+                    let Reverse(x) = self.0;
+                    let x = StdWrapper::<&$unref>(x).to_panicval(fmtarg.indent());
+
+                    single_field_tupled!(fmtarg, "Reverse", x)
+                }
+            }
+
+            impl<'s> StdWrapper<&'s ManuallyDrop<$ty>> {
+                #[doc = concat!(
+                    "Converts this `ManuallyDrop<", stringify!($ty), ">` to a `PanicVal` array."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 6] {
+                    let value = ManuallyDrop::into_inner(*self.0);
+                    let value = StdWrapper::<&$unref>(&value).to_panicval(fmtarg.indent());
+
+                    flatten_panicvals! {fmtarg;
+                        "ManuallyDrop",
+                        open: cfmt::OpenBrace,
+                            "value: ", value, cfmt::COMMA_TERM,
+                        close: cfmt::CloseBrace,
+                    }
+                }
+            }
+
+            impl<'s> StdWrapper<&'s Bound<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Bound<", stringify!($ty), ">` to a `PanicVal` array."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 5] {
+                    let (name, x) = match self.0 {
+                        Bound::Included(x) => ("Included", x),
+                        Bound::Excluded(x) => ("Excluded", x),
+                        Bound::Unbounded => {
+                            return crate::utils::flatten_panicvals(&[&[PanicVal::write_str("Unbounded")]]);
+                        }
+                    };
+                    let x = StdWrapper::<&$unref>(x).to_panicval(fmtarg.indent());
+
+                    single_field_tupled!(fmtarg, PanicVal::write_str(name), x)
+                }
+            }
+
+            impl<'s> StdWrapper<&'s Poll<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Poll<", stringify!($ty), ">` to a `PanicVal` array."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 5] {
+                    match self.0 {
+                        Poll::Ready(x) => {
+                            let x = StdWrapper::<&$unref>(x).to_panicval(fmtarg.indent());
+                            single_field_tupled!(fmtarg, "Ready", x)
+                        }
+                        Poll::Pending => {
+                            crate::utils::flatten_panicvals(&[&[PanicVal::write_str("Pending")]])
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_for_control_flow {
+    ($break_ty:ty, $break_unref:ty; $(($continue_ty:ty, $continue_unref:ty))*) => (
+        $(
+            impl<'s> StdWrapper<&'s ControlFlow<$break_ty, $continue_ty>> {
+                #[doc = concat!(
+                    "Converts this `ControlFlow<",
+                    stringify!($break_ty),
+                    ", ",
+                    stringify!($continue_ty),
+                    ">` to a `PanicVal` array."
+                )]
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'s>; 5] {
+                    let inner = fmtarg.indent();
+                    let (name, x) = match self.0 {
+                        ControlFlow::Continue(x) => {
+                            ("Continue", StdWrapper::<&$continue_unref>(x).to_panicval(inner))
+                        }
+                        ControlFlow::Break(x) => {
+                            ("Break", StdWrapper::<&$break_unref>(x).to_panicval(inner))
+                        }
+                    };
+
+                    single_field_tupled!(fmtarg, PanicVal::write_str(name), x)
+                }
+            }
+        )*
+    )
+}
+
+macro_rules! impl_for_std_types {
+    ($(($ty:ty, $unref:ty))*) => {
+        impl_for_wrappers! {$(($ty, $unref))*}
+
+        impl_for_std_types! {@control_flow [$(($ty, $unref))*] $(($ty, $unref))*}
+    };
+    (@control_flow $all:tt $(($ty:ty, $unref:ty))*) => {
+        $(
+            impl_for_std_types! {@control_flow_each ($ty, $unref) $all}
+        )*
+    };
+    (@control_flow_each ($ty:ty, $unref:ty) [$(($c_ty:ty, $c_unref:ty))*]) => {
+        impl_for_control_flow! {$ty, $unref; $(($c_ty, $c_unref))*}
+    };
+}
+
+impl_for_std_types! {
+    ((), ())
+    (bool, bool)
+    (u8, u8)
+    (u16, u16)
+    (u32, u32)
+    (u64, u64)
+    (u128, u128)
+    (i8, i8)
+    (i16, i16)
+    (i32, i32)
+    (i64, i64)
+    (i128, i128)
+    (isize, isize)
+    (usize, usize)
+    (f32, f32)
+    (f64, f64)
+    (char, char)
+    (&'s str, str)
+}
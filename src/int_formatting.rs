@@ -1,119 +1,162 @@
 use crate::{
-    fmt::{FmtArg, NumberFmt},
-    utils::{Sign, TailShortString},
+    fmt::{char_formatting::char_to_utf8, FmtArg, NumberFmt},
+    utils::{NumberChunk, Sign},
 };
 
-pub(crate) const fn fmt_decimal<const N: usize>(sign: Sign, mut n: u128) -> TailShortString<N> {
-    let mut start = N;
-    let mut buffer = [0u8; N];
-
-    loop {
-        start -= 1;
-        let digit = (n % 10) as u8;
-        buffer[start] = b'0' + digit;
-        n /= 10;
-        if n == 0 {
-            break;
-        }
-    }
+// How the digits of an integer are written
+#[derive(Copy, Clone)]
+pub(crate) struct DigitsFmt {
+    pub(crate) radix: u8,
+    // the byte used for the digit with the value 10 (`b'a'` or `b'A'`)
+    pub(crate) letter: u8,
+    // the amount of digits written, including leading zeroes
+    pub(crate) digits: u8,
+    // the separator written between groups of `group_len` digits,
+    // digits aren't grouped if `group_len` is 0.
+    pub(crate) separator: char,
+    pub(crate) group_len: u8,
+}
 
-    if let Sign::Negative = sign {
-        start -= 1;
-        buffer[start] = b'-';
+// Formats the bytes of `n` that are in `chunk`,
+// writing `sign` and then `prefix` before the digits.
+//
+// `len` is the length of the entire formatted integer.
+pub(crate) const fn fmt_int(
+    mut n: u128,
+    fmt: DigitsFmt,
+    sign: &[u8],
+    prefix: &[u8],
+    len: usize,
+    mut chunk: NumberChunk,
+) -> NumberChunk {
+    // the index of the last written byte, the integer is written from the end to the start
+    let mut pos = len;
+
+    macro_rules! write_byte {
+        ($byte:expr) => {{
+            pos -= 1;
+            if chunk.contains(pos) {
+                chunk.buffer[pos - chunk.offset] = $byte;
+            }
+        }};
+    }
+    macro_rules! write_bytes {
+        ($bytes:expr) => {{
+            let bytes: &[u8] = $bytes;
+            let mut i = bytes.len();
+            while i != 0 {
+                i -= 1;
+                write_byte! {bytes[i]}
+            }
+        }};
     }
 
-    // safety: buffer is only ever written ascii, so its automatically valid utf8.
-    unsafe { TailShortString::new(start as u8, buffer) }
-}
+    let (sep_bytes, sep_len) = char_to_utf8(fmt.separator);
 
-pub(crate) const fn fmt_binary<const N: usize>(
-    mut n: u128,
-    is_alternate: bool,
-) -> TailShortString<N> {
-    let mut start = N;
-    let mut buffer = [0u8; N];
-
-    loop {
-        start -= 1;
-        let digit = (n & 1) as u8;
-        buffer[start] = b'0' + digit;
-        n >>= 1;
-        if n == 0 {
-            break;
+    let mut i = 0;
+    // the bytes before the chunk aren't written
+    while i < fmt.digits && pos > chunk.offset {
+        if fmt.group_len != 0 && i != 0 && i % fmt.group_len == 0 {
+            let mut j = sep_len;
+            while j != 0 {
+                j -= 1;
+                write_byte! {sep_bytes[j]}
+            }
+        }
+
+        let digit = (n % fmt.radix as u128) as u8;
+        write_byte! {
+            match digit {
+                0..=9 => b'0' + digit,
+                _ => fmt.letter - 10 + digit,
+            }
         }
+        n /= fmt.radix as u128;
+        i += 1;
     }
 
-    if is_alternate {
-        start -= 1;
-        buffer[start] = b'b';
-        start -= 1;
-        buffer[start] = b'0';
+    if pos > chunk.offset {
+        write_bytes! {prefix}
+        write_bytes! {sign}
     }
 
-    // safety: buffer is only ever written ascii, so its automatically valid utf8.
-    unsafe { TailShortString::new(start as u8, buffer) }
+    chunk
 }
 
-pub(crate) const fn fmt_hexadecimal<const N: usize>(
-    mut n: u128,
-    is_alternate: bool,
-) -> TailShortString<N> {
-    let mut start = N;
-    let mut buffer = [0u8; N];
-
-    loop {
-        start -= 1;
-        let digit = (n & 0xF) as u8;
-        buffer[start] = match digit {
-            0..=9 => b'0' + digit,
-            _ => b'A' - 10 + digit,
-        };
-        n >>= 4;
-        if n == 0 {
-            break;
-        }
+// The radix, the byte for the digit with the value 10, and the alternate prefix of
+// every non-decimal `NumberFmt`
+pub(crate) const fn radix_info(number_fmt: NumberFmt, radix: u8) -> (u8, u8, &'static [u8]) {
+    match number_fmt {
+        NumberFmt::Decimal => (10, b'A', b""),
+        NumberFmt::Binary => (2, b'A', b"0b"),
+        NumberFmt::Octal => (8, b'A', b"0o"),
+        NumberFmt::Hexadecimal => (16, b'A', b"0x"),
+        NumberFmt::LowerHexadecimal => (16, b'a', b"0x"),
+        NumberFmt::Radix => (radix, b'a', b""),
     }
+}
 
-    if is_alternate {
-        start -= 1;
-        buffer[start] = b'x';
-        start -= 1;
-        buffer[start] = b'0';
+// The amount of digits in each group when the digits are separated,
+// and `digit_group_len` is `0`.
+pub(crate) const fn default_group_len(number_fmt: NumberFmt) -> u8 {
+    match number_fmt {
+        NumberFmt::Binary | NumberFmt::Hexadecimal | NumberFmt::LowerHexadecimal => 4,
+        _ => 3,
     }
-
-    // safety: buffer is only ever written ascii, so its automatically valid utf8.
-    unsafe { TailShortString::new(start as u8, buffer) }
 }
 
-pub(crate) const fn compute_len(sign: Sign, int: u128, bits: u8, fmt: FmtArg) -> u8 {
-    match fmt.number_fmt {
-        NumberFmt::Decimal => compute_decimal_len(sign, int),
-        NumberFmt::Hexadecimal => {
-            let with_0x = (fmt.is_alternate as u8) * 2;
-            let i = match sign {
-                Sign::Negative => bits,
-                Sign::Positive => (128 - int.leading_zeros()) as u8,
-            };
-            let tmp = if i == 0 {
-                1
-            } else {
-                i / 4 + (i % 4 != 0) as u8
-            };
-            tmp + with_0x
+// Computes the amount of digits in `int`.
+//
+// For non-decimal formats, `int` must be the value that `apply_mask` returns.
+pub(crate) const fn count_digits(mut int: u128, fmt: FmtArg) -> u8 {
+    if let NumberFmt::Decimal = fmt.number_fmt {
+        return count_decimal_digits(int);
+    }
+
+    let (radix, _, _) = radix_info(fmt.number_fmt, fmt.radix);
+
+    if radix.is_power_of_two() {
+        let digit_bits = radix.trailing_zeros() as u8;
+        let bits = (128 - int.leading_zeros()) as u8;
+        if bits == 0 {
+            1
+        } else {
+            bits / digit_bits + (bits % digit_bits != 0) as u8
         }
-        NumberFmt::Binary => {
-            let with_0b = (fmt.is_alternate as u8) * 2;
-            let i = match sign {
-                Sign::Negative => bits,
-                Sign::Positive => (128 - int.leading_zeros()) as u8,
-            };
-            (if i == 0 { 1 } else { i }) + with_0b
+    } else {
+        let mut len = 1;
+        while int >= radix as u128 {
+            int /= radix as u128;
+            len += 1;
         }
+        len
+    }
+}
+
+// The length of `digits` digits, separated into groups of `group_len` digits
+// by a separator of length `separator_len`.
+pub(crate) const fn grouped_len(digits: u8, group_len: u8, separator_len: u16) -> u16 {
+    // `group_len` is 0 when the digits aren't grouped
+    let separators = match (digits - 1).checked_div(group_len) {
+        Some(x) => x as u16,
+        None => 0,
+    };
+    digits as u16 + separators * separator_len
+}
+
+// Converts negative numbers into their two's complement, for non-decimal formatting.
+pub(crate) const fn apply_mask(sign: Sign, n: u128, bits: u8) -> u128 {
+    if let Sign::Negative = sign {
+        let mask: u128 = if bits == 128 { !0 } else { (1 << bits) - 1 };
+
+        (n as i128).wrapping_neg() as u128 & mask
+    } else {
+        n
     }
 }
 
-const fn compute_decimal_len(sign: Sign, mut n: u128) -> u8 {
-    let mut len = matches!(sign, Sign::Negative) as u8 + 1;
+const fn count_decimal_digits(mut n: u128) -> u8 {
+    let mut len = 1;
     if n >= 1_0000_0000_0000_0000 {
         n /= 1_0000_0000_0000_0000;
         len += 16;
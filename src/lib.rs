@@ -160,7 +160,14 @@
 //! ### Panic message length
 //!
 //! The panic message can only be up to [`MAX_PANIC_MSG_LEN`] long,
-//! after which it is truncated.
+//! after which it is truncated,
+//! ending with a marker that says how long the message would have been
+//! (eg: `... [truncated, 41230 bytes total]`).
+//!
+//! The [`concat_panic`] macro can be passed a `capacity = ...;` argument
+//! to use a different capacity for a single panic message,
+//! and the maximum can be lowered for the whole crate graph
+//! with the `"max_panic_msg_len_*"` features.
 //!
 //! # Cargo features
 //!
@@ -176,6 +183,9 @@
 //! - `"rust_1_64"`(disabled by default):
 //! Enables formatting of additional items that require Rust 1.64.0 to do so.
 //!
+//! - `"rust_1_77"`(disabled by default):
+//! Enables formatting of additional items that require Rust 1.77.0 to do so.
+//!
 //! - `"rust_1_82"`(disabled by default):
 //! Enables formatting of additional items that require Rust 1.82.0 to do so.
 //!
@@ -185,6 +195,28 @@
 //! - `"derive"`(disabled by default):
 //! Enables the [`PanicFmt` derive] macro.
 //!
+//! - `"fmt_str"`(disabled by default):
+//! Enables the [`panic`] and [`format_`] macros,
+//! which take std-like format strings (eg: `panic!("x = {x:?}, y = {:#X}", y)`),
+//! the [`format_`] macro also requires the `"non_basic"` feature.
+//!
+//! - `"alloc"`(disabled by default):
+//! Enables the [`to_string`] function, which formats `PanicVal`s into a `String`
+//! without a length limit,
+//! and panic formatting of `Box`, `Rc`, and (with the `"rust_1_64"` feature) `Arc`
+//! (`Rc` and `Arc` can only be formatted at runtime).
+//! Combined with the `"rust_1_88"` feature,
+//! this also enables panic formatting of `String`, `Cow<str>`,
+//! and (with the `"non_basic"` feature) `Vec`.
+//!
+//! - `"max_panic_msg_len_1024"`(disabled by default):
+//! Lowers [`MAX_PANIC_MSG_LEN`] to `1024`,
+//! limiting the stack usage of panicking with formatting at runtime.
+//!
+//! - `"max_panic_msg_len_256"`(disabled by default):
+//! Lowers [`MAX_PANIC_MSG_LEN`] to `256`,
+//! this takes priority over the `"max_panic_msg_len_1024"` feature.
+//!
 //! # Plans
 //!
 //! None for now
@@ -203,6 +235,10 @@
 //! [`impl_panicfmt`]: crate::impl_panicfmt
 //! [`flatten_panicvals`]: crate::flatten_panicvals
 //! [`MAX_PANIC_MSG_LEN`]: crate::MAX_PANIC_MSG_LEN
+//! [`concat_panic`]: macro@crate::concat_panic
+//! [`to_string`]: crate::to_string
+//! [`panic`]: macro@crate::panic
+//! [`format_`]: macro@crate::format_
 #![no_std]
 #![cfg_attr(feature = "docsrs", feature(doc_cfg))]
 #![warn(missing_docs)]
@@ -212,6 +248,9 @@
 
 extern crate self as const_panic;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod doc_macros;
 
@@ -224,6 +263,8 @@ mod debug_str_fmt;
 
 mod int_formatting;
 
+mod float_formatting;
+
 pub mod fmt;
 
 #[cfg(all(doctest, feature = "non_basic"))]
@@ -250,6 +291,10 @@ pub use crate::array_string::ArrayString;
 
 mod wrapper;
 
+mod const_eq;
+
+mod panicvals_display;
+
 mod fmt_impls {
     #[macro_use]
     pub(crate) mod basic_fmt_impls;
@@ -257,7 +302,12 @@ mod fmt_impls {
     #[cfg(feature = "rust_1_64")]
     mod rust_1_64_fmt_impls;
 
+    #[cfg(feature = "rust_1_77")]
+    #[clippy::msrv = "1.77"]
+    mod rust_1_77_fmt_impls;
+
     #[cfg(feature = "rust_1_82")]
+    #[clippy::msrv = "1.82"]
     mod rust_1_82_fmt_impls;
 
     #[macro_use]
@@ -273,16 +323,42 @@ mod fmt_impls {
     #[cfg(feature = "non_basic")]
     mod fmt_range;
 
+    #[cfg(feature = "non_basic")]
+    mod duration_fmt_impls;
+
+    #[cfg(feature = "non_basic")]
+    mod wrapper_fmt_impls;
+
+    #[cfg(feature = "non_basic")]
+    mod error_impls;
+
+    #[cfg(feature = "non_basic")]
+    pub(crate) mod tuple_impls;
+
+    #[cfg(feature = "alloc")]
+    mod alloc_fmt_impls;
+
+    #[cfg(all(feature = "non_basic", feature = "rust_1_64"))]
+    mod rust_1_64_nonbasic_fmt_impls;
+
+    #[cfg(all(feature = "non_basic", feature = "rust_1_82"))]
+    #[clippy::msrv = "1.82"]
+    mod rust_1_82_nonbasic_fmt_impls;
+
     #[cfg(all(feature = "non_basic", feature = "rust_1_88"))]
     mod rust_1_88_nonbasic_fmt_impls;
 }
 
 pub use crate::{
-    concat_panic_::{concat_panic, MAX_PANIC_MSG_LEN},
+    concat_panic_::{concat_panic, concat_panic_with_capacity, MAX_PANIC_MSG_LEN},
     panic_val::PanicVal,
+    panicvals_display::PanicValsDisplay,
     wrapper::StdWrapper,
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::panicvals_display::to_string;
+
 #[doc(no_inline)]
 pub use crate::fmt::{FmtArg, IsCustomType, PanicFmt};
 
@@ -293,29 +369,36 @@ pub use crate::fmt::{ComputePvCount, TypeDelim};
 #[doc(hidden)]
 pub mod __ {
     pub use core::{
-        assert, compile_error, concat,
+        assert, cfg, compile_error, concat,
         option::Option::{None, Some},
-        primitive::usize,
+        primitive::{u128, usize},
         result::Result::{Err, Ok},
         stringify,
     };
 
     pub use crate::*;
 
+    pub use crate::macros::concat_assert::assert_cmp_helpers::assert_cmp_fmtarg;
+
     #[cfg(feature = "non_basic")]
     pub use crate::reexported_non_basic::*;
+
+    #[cfg(feature = "fmt_str")]
+    pub use const_panic_proc_macros::__lower_fmt_str;
 }
 
 #[cfg(feature = "non_basic")]
 #[doc(hidden)]
 mod reexported_non_basic {
-    pub use core::{mem::forget, option::Option, primitive::str, unreachable};
+    pub use core::{mem::forget, option::Option, primitive::str, result::Result, unreachable};
 
     pub use typewit::MakeTypeWitness;
 
     pub use crate::{
         concat_panic_::{compute_length, make_panic_string_unwrapped},
         const_default::ConstDefault,
+        fmt::flags_fmt::FlagsBits,
+        fmt_impls::tuple_impls::single_tuple_comma,
         macros::concat_macro::ConcatCmd,
         utils::{assert_flatten_panicvals_length, flatten_panicvals, panicvals_id},
     };
@@ -1,5 +1,8 @@
 #[macro_use]
-mod concat_assert;
+pub(crate) mod concat_assert;
+
+#[macro_use]
+mod concat_unreachable;
 
 #[cfg(feature = "non_basic")]
 #[macro_use]
@@ -20,6 +23,9 @@ mod impl_panicfmt;
 #[macro_use]
 mod unwrapping;
 
+#[cfg(feature = "fmt_str")]
+mod fmt_str_macros;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __write_array {
@@ -111,6 +117,49 @@ macro_rules! coerce_fmt {
     };
 }
 
+/// Constructs a [`PanicValsDisplay`](crate::PanicValsDisplay)
+/// that writes `$value` formatted with `$fmtarg`.
+///
+/// `$value` can be of any type that implements [`PanicFmt`](crate::PanicFmt),
+/// and `$fmtarg` is a [`FmtArg`](crate::FmtArg).
+///
+/// Like [`format_args`], the returned value borrows temporaries
+/// that only live until the end of the enclosing statement,
+/// so it must be used in the same statement that it's constructed in
+/// (eg: as an argument to a formatting macro).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{panicvals_display, FmtArg};
+///
+/// assert_eq!(
+///     format!("{}", panicvals_display!(FmtArg::DEBUG; "hello\tworld")),
+///     r#""hello\tworld""#,
+/// );
+///
+/// assert_eq!(
+///     format!("{}", panicvals_display!(FmtArg::DISPLAY; "hello\tworld")),
+///     "hello\tworld",
+/// );
+///
+/// assert_eq!(
+///     format!("{:?}", panicvals_display!(FmtArg::ALT_DEBUG.set_hex(); 255u8)),
+///     "0xFF",
+/// );
+///
+/// ```
+///
+#[macro_export]
+macro_rules! panicvals_display {
+    ($fmtarg:expr; $value:expr $(,)?) => {
+        $crate::PanicValsDisplay(&[$crate::StdWrapper(
+            &$crate::coerce_fmt!($value).to_panicvals($fmtarg),
+        )
+        .deref_panic_vals()])
+    };
+}
+
 /// Panics with the concanenation of the arguments.
 ///
 /// [**Examples below**](#examples)
@@ -120,6 +169,7 @@ macro_rules! coerce_fmt {
 /// This macro uses this syntax:
 /// ```text
 /// concat_panic!(
+///     $(capacity = $capacity:expr;)?
 ///     $($fmtarg:expr;)?
 ///     $(
 ///         $( $format_override:tt: )? $arg_to_fmt:expr
@@ -128,6 +178,13 @@ macro_rules! coerce_fmt {
 /// )
 /// ```
 ///
+/// `$capacity` is an optional `usize` constant argument,
+/// which sets the capacity (in bytes) of the buffer that the panic message is written to,
+/// truncating messages longer than it.
+/// If this argument isn't passed, the capacity is up to
+/// [`MAX_PANIC_MSG_LEN`](crate::MAX_PANIC_MSG_LEN).
+/// [example below](#capacity-example)
+///
 /// `$fmtarg` is an optional [`FmtArg`](crate::FmtArg) argument
 /// which defaults to `FmtArg::DEBUG`,
 /// determining how non-literal `$arg_to_fmt` arguments are formatted.
@@ -137,6 +194,17 @@ macro_rules! coerce_fmt {
 ///
 #[doc = formatting_docs!()]
 ///
+/// ### Tuple formatting
+///
+/// Tuple expressions with at least one comma (eg: `(a, b)`, `(a,)`)
+/// are formatted like std formats tuples, eg: `(1, "a")`.
+/// Formatting overrides before the tuple apply to all of its elements.
+///
+/// Tuple-typed variables and nested tuples can be formatted with the
+/// [`tuple_panicvals`](crate::tuple_panicvals) macro.
+///
+/// This requires the `"non_basic"` feature (enabled by default).
+///
 /// # Limitations
 ///
 #[doc = crate::doc_macros::limitation_docs!()]
@@ -353,8 +421,34 @@ macro_rules! coerce_fmt {
 ///
 /// ```
 ///
+/// <a id = "capacity-example"></a>
+/// ### Capacity
+///
+/// This example demonstrates the `capacity = ...;` argument,
+/// which sets the capacity of the panic message.
+///
+/// ```rust
+/// use const_panic::concat_panic;
+///
+/// let res = std::panic::catch_unwind(|| {
+///     // only uses a 16 byte buffer for the panic message, truncating it.
+///     concat_panic!(capacity = 16; "foo bar baz: ", "qux quux")
+/// });
+///
+/// let msg = res.unwrap_err();
+/// assert_eq!(msg.downcast_ref::<String>().unwrap(), "foo bar baz: qux");
+///
+/// ```
+///
 #[macro_export]
 macro_rules! concat_panic {
+    (capacity = $capacity:expr; $($args:tt)*) => (
+        $crate::__concat_func_setup!{
+            (|args| $crate::concat_panic_with_capacity::<{ $capacity }>(args))
+            []
+            [$($args)*,]
+        }
+    );
     ($($args:tt)*) => (
         $crate::__concat_func_setup!{
             (|args| $crate::concat_panic(args))
@@ -387,6 +481,19 @@ macro_rules! __concat_func_setup {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __concat_func {
+    (
+        $fmt:ident $args:tt $prev:tt
+        [$keyword:tt: ($first:expr, $($elem:expr),* $(,)?), $($rem:tt)* ]
+    ) => {
+        $crate::__concat_func_tuple!{
+            $fmt
+            $args
+            $prev
+            ($crate::__set_fmt_from_kw!($keyword, $fmt))
+            [$first $(, $elem)*]
+            [$($rem)*]
+        }
+    };
     ($fmt:ident $args:tt [$($prev:tt)*] [$keyword:tt: $expr:expr, $($rem:tt)* ]) => {
         $crate::__concat_func!{
             $fmt
@@ -399,7 +506,17 @@ macro_rules! __concat_func {
         $crate::__concat_func!{
             $fmt
             $args
-            [$($prev)* ($crate::__set_fmt_from_kw!(display, $fmt), $expr)]
+            [$($prev)* ($crate::__set_fmt_from_kw!(__literal, $fmt), $expr)]
+            [$($rem)*]
+        }
+    };
+    ($fmt:ident $args:tt $prev:tt [($first:expr, $($elem:expr),* $(,)?), $($rem:tt)* ]) => {
+        $crate::__concat_func_tuple!{
+            $fmt
+            $args
+            $prev
+            ($fmt)
+            [$first $(, $elem)*]
             [$($rem)*]
         }
     };
@@ -411,6 +528,11 @@ macro_rules! __concat_func {
             [$($rem)*]
         }
     };
+    // continues with the `$rem` arguments after the current ones,
+    // used by the `concat_assert_*` macros.
+    ($fmt:ident {@then $args:tt $rem:tt} $prev:tt [$(,)*]) => {
+        $crate::__concat_func!{$fmt $args $prev $rem}
+    };
     ($fmt:ident (|$args:ident| $function_call:expr) [$(($fmt_arg:expr, $reff:expr))*] [$(,)*]) => {
         match &[
             $(
@@ -425,17 +547,67 @@ macro_rules! __concat_func {
     };
 }
 
+// Formats a tuple expression like a tuple struct without a name,
+// `$tfmt` is the `FmtArg` that the tuple is formatted with.
+//
+// `$tfmt` is evaluated once, when the opening parenthesis is formatted,
+// so that it's affected by the `open`/`close` overrides of the preceding arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_func_tuple {
+    ($fmt:ident $args:tt [$($prev:tt)*] $tfmt:tt [$only:expr] $rem:tt) => {{
+        let tuple_fmt: $crate::FmtArg;
+        $crate::__concat_func!{
+            $fmt
+            $args
+            [
+                $($prev)*
+                ({tuple_fmt = $tfmt; tuple_fmt.indent()}, $crate::fmt::OpenParen)
+                (tuple_fmt.indent(), $only)
+                (tuple_fmt.indent(), $crate::__::single_tuple_comma(tuple_fmt.indent()))
+                (tuple_fmt, $crate::fmt::CloseParen)
+            ]
+            $rem
+        }
+    }};
+    ($fmt:ident $args:tt [$($prev:tt)*] $tfmt:tt [$first:expr, $($elem:expr),*] $rem:tt) => {{
+        let tuple_fmt: $crate::FmtArg;
+        $crate::__concat_func!{
+            $fmt
+            $args
+            [
+                $($prev)*
+                ({tuple_fmt = $tfmt; tuple_fmt.indent()}, $crate::fmt::OpenParen)
+                (tuple_fmt.indent(), $first)
+                $(
+                    (tuple_fmt.indent(), $crate::fmt::COMMA_SEP)
+                    (tuple_fmt.indent(), $elem)
+                )*
+                (tuple_fmt.indent(), $crate::fmt::COMMA_TERM)
+                (tuple_fmt, $crate::fmt::CloseParen)
+            ]
+            $rem
+        }
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __set_fmt_from_kw {
+    // delimiters and literals are formatted without
+    // the padding and precision of `$fmtarg`,
+    // the other keywords only change the formatting that they specify.
     (open, $fmtarg:ident) => {{
         $fmtarg = $fmtarg.indent();
-        $fmtarg.set_display()
+        $fmtarg.set_display().clear_flags()
     }};
     (close, $fmtarg:ident) => {{
         $fmtarg = $fmtarg.unindent();
-        $fmtarg.set_display()
+        $fmtarg.set_display().clear_flags()
     }};
+    (__literal, $fmtarg:ident) => {
+        $fmtarg.set_display().set_alternate(false).clear_flags()
+    };
     (display, $fmtarg:ident) => {
         $fmtarg.set_display().set_alternate(false)
     };
@@ -484,6 +656,53 @@ macro_rules! __set_fmt_from_kw {
     ({#b}, $fmtarg:ident) => {
         $fmtarg.set_bin().set_alternate(true)
     };
+    (lower_hex, $fmtarg:ident) => {
+        $fmtarg.set_lower_hex().set_alternate(false)
+    };
+    ({x}, $fmtarg:ident) => {
+        $fmtarg.set_lower_hex().set_alternate(false)
+    };
+    (alt_lower_hex, $fmtarg:ident) => {
+        $fmtarg.set_lower_hex().set_alternate(true)
+    };
+    ({#x}, $fmtarg:ident) => {
+        $fmtarg.set_lower_hex().set_alternate(true)
+    };
+    (oct, $fmtarg:ident) => {
+        $fmtarg.set_oct().set_alternate(false)
+    };
+    ({o}, $fmtarg:ident) => {
+        $fmtarg.set_oct().set_alternate(false)
+    };
+    (alt_oct, $fmtarg:ident) => {
+        $fmtarg.set_oct().set_alternate(true)
+    };
+    ({#o}, $fmtarg:ident) => {
+        $fmtarg.set_oct().set_alternate(true)
+    };
+    (byte_str, $fmtarg:ident) => {
+        $fmtarg.set_byte_str().set_alternate(false)
+    };
+    // used by the `concat_assert_eq` and `concat_assert_ne` macros
+    ({__fmtarg: $new_fmtarg:expr}, $fmtarg:ident) => {
+        $fmtarg.__with_spec($new_fmtarg)
+    };
+    // used by the `panic` and `format_` macros
+    ({__spec: $spec:expr}, $fmtarg:ident) => {
+        $fmtarg.__with_spec({
+            const SPEC: $crate::FmtArg = $spec;
+            SPEC
+        })
+    };
+    ({radix = $radix:expr}, $fmtarg:ident) => {
+        $fmtarg.set_radix($radix).set_alternate(false)
+    };
+    ({$($spec:tt)*}, $fmtarg:ident) => {
+        $fmtarg.__with_spec({
+            const SPEC: $crate::FmtArg = $crate::FmtArg::__parse_spec(stringify!($($spec)*));
+            SPEC
+        })
+    };
     (_, $fmtarg:ident) => {
         $fmtarg
     };
@@ -501,6 +720,13 @@ macro_rules! __set_fmt_from_kw {
             "- alt_hex/{#X}\n",
             "- bin/{b}\n",
             "- alt_bin/{#b}\n",
+            "- lower_hex/{x}\n",
+            "- alt_lower_hex/{#x}\n",
+            "- oct/{o}\n",
+            "- alt_oct/{#o}\n",
+            "- byte_str\n",
+            "- {radix = N}\n",
+            "- {[[fill]align][+][#][0][width][grouping][.precision][type]}\n",
         ))
     };
 }
@@ -1,3 +1,5 @@
+pub(crate) mod assert_cmp_helpers;
+
 /// Asserts that `$condition` is true.
 ///
 /// When only the `$condition` argument is passed,
@@ -122,3 +124,793 @@ macro_rules! concat_assert {
         }
     }};
 }
+
+/// Asserts that `$left` and `$right` are equal.
+///
+/// When the assertion fails, this panics with a message like the one
+/// [`core::assert_eq`] panics with:
+/// ```text
+/// assertion `left == right` failed: <the optional formatted message>
+///   left: <$left>
+///  right: <$right>
+/// ```
+/// where `$left` and `$right` are `Debug` formatted,
+/// or alternate-`Debug` formatted if either of them is too long to fit on one line.
+///
+/// The optional arguments after `$right` are formatted by delegating them to the
+/// [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// # Equality
+///
+/// `$left` and `$right` must be the same type, which must implement
+/// [`PanicFmt`](crate::PanicFmt).
+///
+/// These standard library types can be compared:
+/// - integers, `bool`, `char`, and `&str`.
+/// - slices and arrays of the above types
+/// (requires the `"non_basic"` feature, enabled by default).
+///
+/// User-defined types are compared by calling their inherent
+/// `const fn const_eq(&self, other: &Self) -> bool` method.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Examples
+///
+/// ### Compile-time assertion
+///
+/// ```compile_fail
+/// use const_panic::concat_assert_eq;
+///
+/// const LEN: usize = 8;
+/// const ARRAY: [u8; 3] = [3, 5, 8];
+///
+/// const _: () = concat_assert_eq!(ARRAY.len(), LEN, "ARRAY must have ", LEN, " elements");
+/// ```
+/// the above code errors with this message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros/concat_assert.rs:10:15
+///   |
+/// 6 | const _: () = concat_assert_eq!(ARRAY.len(), LEN, "ARRAY must have ", LEN, " elements");
+///   |               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'assertion `left == right` failed: ARRAY must have 8 elements
+///   left: 3
+///  right: 8', src/macros/concat_assert.rs:6:15
+/// ```
+///
+/// ### Custom type
+///
+/// This example uses the [`impl_panicfmt`](crate::impl_panicfmt) macro,
+/// which requires the `"non_basic"` feature (enabled by default).
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{concat_assert_eq, PanicFmt};
+///
+/// let res = std::panic::catch_unwind(|| {
+///     concat_assert_eq!(Point { x: 3, y: 5 }, Point { x: 3, y: 8 });
+/// });
+///
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion `left == right` failed\n  left: Point { x: 3, y: 5 }\n right: Point { x: 3, y: 8 }",
+/// );
+///
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     struct Point {
+///         x: u32,
+///         y: u32,
+///     }
+/// }
+///
+/// impl Point {
+///     const fn const_eq(&self, other: &Self) -> bool {
+///         self.x == other.x && self.y == other.y
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_cmp! {(!) "assertion `left == right` failed" $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_cmp! {(!) "assertion `left == right` failed" $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$left` and `$right` are not equal.
+///
+/// When the assertion fails, this panics with a message like the one
+/// [`core::assert_ne`] panics with:
+/// ```text
+/// assertion `left != right` failed: <the optional formatted message>
+///   left: <$left>
+///  right: <$right>
+/// ```
+///
+/// This takes the same arguments, and compares values the same way,
+/// as [`concat_assert_eq`](crate::concat_assert_eq).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_assert_ne;
+///
+/// const fn rename(old: &str, new: &str) {
+///     concat_assert_ne!(old, new, "expected a different name for ", display: old);
+///     // ...
+/// }
+///
+/// rename("foo", "bar");
+///
+/// let res = std::panic::catch_unwind(|| rename("qux", "qux"));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion `left != right` failed: expected a different name for qux\
+///     \n  left: \"qux\"\n right: \"qux\"",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_cmp! {() "assertion `left != right` failed" $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_cmp! {() "assertion `left != right` failed" $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$left < $right`.
+///
+/// When the assertion fails, this panics with a message that includes
+/// the operand expressions and their `Debug` formatted values:
+/// ```text
+/// assertion failed: <$left> < <$right> (<$left>: <left value>, <$right>: <right value>): <the optional formatted message>
+/// ```
+/// eg: `assertion failed: len < CAP (len: 70, CAP: 64)`
+///
+/// The optional arguments after `$right` are formatted by delegating them to the
+/// [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// `$left` and `$right` are evaluated once each.
+///
+/// # Comparison
+///
+/// `$left` and `$right` are compared with the `<` operator,
+/// so they must be primitive types that can be compared in const contexts
+/// (eg: integers, `bool`, and `char`),
+/// which also implement [`PanicFmt`](crate::PanicFmt).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Examples
+///
+/// ### Compile-time assertion
+///
+/// ```compile_fail
+/// use const_panic::concat_assert_lt;
+///
+/// const CAP: usize = 64;
+///
+/// const fn new_buffer(len: usize) -> [u8; CAP] {
+///     concat_assert_lt!(len, CAP);
+///     [0; CAP]
+/// }
+///
+/// const _: [u8; CAP] = new_buffer(70);
+/// ```
+/// the above code errors with this message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///   --> src/macros/concat_assert.rs:13:22
+///    |
+/// 11 | const _: [u8; CAP] = new_buffer(70);
+///    |                      ^^^^^^^^^^^^^^ the evaluated program panicked at 'assertion failed: len < CAP (len: 70, CAP: 64)', src/macros/concat_assert.rs:8:5
+/// ```
+///
+/// ### Runtime assertion
+///
+/// ```rust
+/// use const_panic::concat_assert_lt;
+///
+/// let index = 8u32;
+/// let items = ["foo", "bar"];
+///
+/// let res = std::panic::catch_unwind(|| {
+///     concat_assert_lt!(index as usize, items.len(), "index out of bounds");
+/// });
+///
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: index as usize < items.len() \
+///      (index as usize: 8, items.len(): 2): index out of bounds",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_lt {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_ord! {< [$left, $right] $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_ord! {< [$left, $right] $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$left <= $right`.
+///
+/// When the assertion fails, this panics with a message like:
+/// ```text
+/// assertion failed: <$left> <= <$right> (<$left>: <left value>, <$right>: <right value>): <the optional formatted message>
+/// ```
+///
+/// This takes the same arguments, and compares values the same way,
+/// as [`concat_assert_lt`](crate::concat_assert_lt).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_assert_le;
+///
+/// const fn take_bytes(bytes: &[u8], n: usize) -> &[u8] {
+///     concat_assert_le!(n, bytes.len());
+///     // ...
+///     # bytes
+/// }
+///
+/// take_bytes(&[3, 5, 8], 3);
+///
+/// let res = std::panic::catch_unwind(|| take_bytes(&[3, 5, 8], 4));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: n <= bytes.len() (n: 4, bytes.len(): 3)",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_le {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_ord! {<= [$left, $right] $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_ord! {<= [$left, $right] $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$left > $right`.
+///
+/// When the assertion fails, this panics with a message like:
+/// ```text
+/// assertion failed: <$left> > <$right> (<$left>: <left value>, <$right>: <right value>): <the optional formatted message>
+/// ```
+///
+/// This takes the same arguments, and compares values the same way,
+/// as [`concat_assert_lt`](crate::concat_assert_lt).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_assert_gt;
+///
+/// const fn average(sum: u64, count: u64) -> u64 {
+///     concat_assert_gt!(count, 0, "cannot average zero values");
+///     sum / count
+/// }
+///
+/// assert_eq!(average(10, 4), 2);
+///
+/// let res = std::panic::catch_unwind(|| average(10, 0));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: count > 0 (count: 0, 0: 0): cannot average zero values",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_gt {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_ord! {> [$left, $right] $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_ord! {> [$left, $right] $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$left >= $right`.
+///
+/// When the assertion fails, this panics with a message like:
+/// ```text
+/// assertion failed: <$left> >= <$right> (<$left>: <left value>, <$right>: <right value>): <the optional formatted message>
+/// ```
+///
+/// This takes the same arguments, and compares values the same way,
+/// as [`concat_assert_lt`](crate::concat_assert_lt).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_assert_ge;
+///
+/// const MIN_ALIGN: usize = 4;
+///
+/// const fn check_align(align: usize) {
+///     concat_assert_ge!(align, MIN_ALIGN);
+/// }
+///
+/// check_align(8);
+///
+/// let res = std::panic::catch_unwind(|| check_align(2));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: align >= MIN_ALIGN (align: 2, MIN_ALIGN: 4)",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_ge {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__concat_assert_ord! {>= [$left, $right] $left, $right, []}
+    };
+    ($left:expr, $right:expr, $($fmt:tt)*) => {
+        $crate::__concat_assert_ord! {>= [$left, $right] $left, $right, [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$x` is in the `$range` range.
+///
+/// `$range` can be any of these range expressions:
+/// `lo..hi`, `lo..=hi`, `lo..`, `..hi`, `..=hi`.
+///
+/// When the assertion fails, this panics with the same message that
+/// [`concat_assert_le`] (for the `lo` bound),
+/// [`concat_assert_lt`] (for the `..hi` bound), or
+/// [`concat_assert_le`] (for the `..=hi` bound)
+/// would with the violated bound, eg:
+/// ```text
+/// assertion failed: len <= CAP (len: 70, CAP: 64)
+/// ```
+///
+/// The optional arguments after `$range` are formatted by delegating them to the
+/// [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// `$x` and the bounds of `$range` are evaluated once each,
+/// and are compared the same way as in [`concat_assert_lt`].
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_assert_in_range;
+///
+/// const CAP: usize = 64;
+///
+/// const fn new_buffer(len: usize) -> [u8; CAP] {
+///     concat_assert_in_range!(len, 1..=CAP, "invalid buffer length");
+///     [0; CAP]
+/// }
+///
+/// new_buffer(64);
+///
+/// let res = std::panic::catch_unwind(|| new_buffer(70));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: len <= CAP (len: 70, CAP: 64): invalid buffer length",
+/// );
+///
+/// let res = std::panic::catch_unwind(|| new_buffer(0));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion failed: 1 <= len (1: 1, len: 0): invalid buffer length",
+/// );
+/// ```
+///
+/// [`concat_assert_lt`]: crate::concat_assert_lt
+/// [`concat_assert_le`]: crate::concat_assert_le
+#[macro_export]
+macro_rules! concat_assert_in_range {
+    ($x:expr, $($range:tt)*) => {
+        $crate::__concat_assert_in_range! {@lo $x [] $($range)*}
+    };
+}
+
+/// Asserts that `$expr` matches the `$pattern` pattern, with an optional `if` guard.
+///
+/// When the assertion fails, this panics with a message like:
+/// ```text
+/// assertion `left matches right` failed: <the optional formatted message>
+///   left: <$expr>
+///  right: <$pattern>
+/// ```
+/// where `$expr` is `Debug` formatted
+/// (or alternate-`Debug` formatted if it's too long to fit on one line),
+/// and `$pattern` (along with its guard) is printed as written.
+///
+/// The optional arguments after the pattern are formatted by delegating them to the
+/// [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// `$expr` must be of a type that implements [`PanicFmt`](crate::PanicFmt).
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Examples
+///
+/// ### Runtime assertion
+///
+/// ```rust
+/// use const_panic::concat_assert_matches;
+///
+/// let n = 7u32;
+///
+/// concat_assert_matches!(n, 1..=9);
+///
+/// let res = std::panic::catch_unwind(|| {
+///     concat_assert_matches!(n, 0 | 10..=99, "expected a two-digit number: ", n);
+/// });
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion `left matches right` failed: expected a two-digit number: 7\
+///     \n  left: 7\n right: 0 | 10..=99",
+/// );
+/// ```
+///
+/// ### State machine
+///
+/// This example uses the [`impl_panicfmt`](crate::impl_panicfmt) macro,
+/// which requires the `"non_basic"` feature (enabled by default).
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::concat_assert_matches;
+///
+/// #[derive(Copy, Clone)]
+/// enum State {
+///     Idle,
+///     Reading { read: u32 },
+///     Closed,
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     enum State {
+///         Idle,
+///         Reading { read: u32 },
+///         Closed,
+///     }
+/// }
+///
+/// const fn read(state: State, bytes: u32) -> State {
+///     concat_assert_matches!(state, State::Idle | State::Reading { .. }, "cannot read");
+///     match state {
+///         State::Reading { read } => State::Reading { read: read + bytes },
+///         _ => State::Reading { read: bytes },
+///     }
+/// }
+///
+/// const fn close(state: State) -> State {
+///     concat_assert_matches!(state, State::Reading { read } if read != 0);
+///     State::Closed
+/// }
+///
+/// const READ: State = read(read(State::Idle, 3), 5);
+/// const CLOSED: State = close(READ);
+///
+/// let res = std::panic::catch_unwind(|| {
+///     read(CLOSED, 8);
+/// });
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion `left matches right` failed: cannot read\
+///     \n  left: Closed\n right: State::Idle | State::Reading { .. }",
+/// );
+///
+/// let res = std::panic::catch_unwind(|| {
+///     close(State::Reading { read: 0 });
+/// });
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "assertion `left matches right` failed\
+///     \n  left: Reading { read: 0 }\n right: State::Reading { read } if read != 0",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_assert_matches {
+    ($expr:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::__concat_assert_matches! {$expr, [$pattern $(if $guard)?] []}
+    };
+    ($expr:expr, $pattern:pat $(if $guard:expr)?, $($fmt:tt)*) => {
+        $crate::__concat_assert_matches! {$expr, [$pattern $(if $guard)?] [$($fmt)*]}
+    };
+}
+
+/// Asserts that `$condition` is true, only when debug assertions are enabled.
+///
+/// This takes the same arguments as [`concat_assert`](crate::concat_assert),
+/// and is like [`core::debug_assert`]:
+/// it only checks the assertion when the `debug_assertions` cfg is enabled
+/// in the crate that invokes this macro,
+/// otherwise the arguments are type checked without being evaluated.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_debug_assert;
+///
+/// const fn halve(n: u32) -> u32 {
+///     concat_debug_assert!(n % 2 == 0, "expected an even number, found: ", n);
+///     n / 2
+/// }
+///
+/// assert_eq!(halve(10), 5);
+///
+/// let res = std::panic::catch_unwind(|| halve(7));
+/// if cfg!(debug_assertions) {
+///     assert_eq!(
+///         res.unwrap_err().downcast_ref::<String>().unwrap(),
+///         "expected an even number, found: 7",
+///     );
+/// } else {
+///     assert_eq!(res.unwrap(), 3);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! concat_debug_assert {
+    ($($args:tt)*) => {
+        if $crate::__::cfg!(debug_assertions) {
+            $crate::concat_assert!{$($args)*}
+        }
+    };
+}
+
+/// Asserts that `$left` and `$right` are equal, only when debug assertions are enabled.
+///
+/// This takes the same arguments as [`concat_assert_eq`](crate::concat_assert_eq),
+/// and is like [`core::debug_assert_eq`]:
+/// it only checks the assertion when the `debug_assertions` cfg is enabled
+/// in the crate that invokes this macro,
+/// otherwise the arguments are type checked without being evaluated.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+#[macro_export]
+macro_rules! concat_debug_assert_eq {
+    ($($args:tt)*) => {
+        if $crate::__::cfg!(debug_assertions) {
+            $crate::concat_assert_eq!{$($args)*}
+        }
+    };
+}
+
+/// Asserts that `$left` and `$right` are not equal,
+/// only when debug assertions are enabled.
+///
+/// This takes the same arguments as [`concat_assert_ne`](crate::concat_assert_ne),
+/// and is like [`core::debug_assert_ne`]:
+/// it only checks the assertion when the `debug_assertions` cfg is enabled
+/// in the crate that invokes this macro,
+/// otherwise the arguments are type checked without being evaluated.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+#[macro_export]
+macro_rules! concat_debug_assert_ne {
+    ($($args:tt)*) => {
+        if $crate::__::cfg!(debug_assertions) {
+            $crate::concat_assert_ne!{$($args)*}
+        }
+    };
+}
+
+// `$negate` is `!` to panic when the operands aren't equal,
+// and nothing to panic when they're equal.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_assert_cmp {
+    (($($negate:tt)?) $header:literal $left:expr, $right:expr, [$($fmt:tt)*]) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if $($negate)? $crate::coerce_fmt!(*left).const_eq(right) {
+                    let fmtarg = $crate::__::assert_cmp_fmtarg(
+                        &$crate::coerce_fmt!(*left).to_panicvals($crate::FmtArg::DEBUG),
+                        &$crate::coerce_fmt!(*right).to_panicvals($crate::FmtArg::DEBUG),
+                    );
+
+                    $crate::__concat_assert_panic! {
+                        [($crate::FmtArg::DISPLAY, $header)]
+                        [$($fmt)*]
+                        [
+                            "\n  left: ", {__fmtarg: fmtarg}: *left,
+                            "\n right: ", {__fmtarg: fmtarg}: *right,
+                        ]
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_assert_matches {
+    ($expr:expr, [$pattern:pat $(if $guard:expr)?] [$($fmt:tt)*]) => {
+        match $expr {
+            $pattern $(if $guard)? => {}
+            ref left => {
+                let fmtarg = $crate::__::assert_cmp_fmtarg(
+                    &$crate::coerce_fmt!(*left).to_panicvals($crate::FmtArg::DEBUG),
+                    &[],
+                );
+
+                $crate::__concat_assert_panic! {
+                    [($crate::FmtArg::DISPLAY, "assertion `left matches right` failed")]
+                    [$($fmt)*]
+                    [
+                        "\n  left: ", {__fmtarg: fmtarg}: *left,
+                        "\n right: ", display: $crate::__::stringify!($pattern $(if $guard)?),
+                    ]
+                }
+            }
+        }
+    };
+}
+
+// `$op` is the comparison operator that must hold for `$left` and `$right`,
+// `$left_text` and `$right_text` are the expressions that are printed for the operands.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_assert_ord {
+    (
+        $op:tt
+        [$left_text:expr, $right_text:expr]
+        $left:expr, $right:expr, [$($fmt:tt)*]
+    ) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left $op *right) {
+                    $crate::__concat_assert_panic! {
+                        [
+                            ($crate::FmtArg::DISPLAY, "assertion failed: ")
+                            (
+                                $crate::FmtArg::DISPLAY,
+                                $crate::__::stringify!($left_text $op $right_text)
+                            )
+                            ($crate::FmtArg::DISPLAY, " (")
+                            ($crate::FmtArg::DISPLAY, $crate::__::stringify!($left_text))
+                            ($crate::FmtArg::DISPLAY, ": ")
+                            ($crate::FmtArg::DEBUG, *left)
+                            ($crate::FmtArg::DISPLAY, ", ")
+                            ($crate::FmtArg::DISPLAY, $crate::__::stringify!($right_text))
+                            ($crate::FmtArg::DISPLAY, ": ")
+                            ($crate::FmtArg::DEBUG, *right)
+                            ($crate::FmtArg::DISPLAY, ")")
+                        ]
+                        [$($fmt)*]
+                        []
+                    }
+                }
+            }
+        }
+    };
+}
+
+// Splits the range argument of `concat_assert_in_range` into its bounds
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_assert_in_range {
+    (@lo $x:tt [$($lo:tt)*] ..= $($rem:tt)*) => {
+        $crate::__concat_assert_in_range! {@hi $x [$($lo)*] (<=) [] $($rem)*}
+    };
+    (@lo $x:tt [$($lo:tt)*] .. $($rem:tt)*) => {
+        $crate::__concat_assert_in_range! {@hi $x [$($lo)*] (<) [] $($rem)*}
+    };
+    (@lo $x:tt [$($lo:tt)*] $t:tt $($rem:tt)*) => {
+        $crate::__concat_assert_in_range! {@lo $x [$($lo)* $t] $($rem)*}
+    };
+    (@lo $x:tt [$($lo:tt)*]) => {
+        $crate::__::compile_error!{
+            "expected a range argument, eg: `lo..hi`, `lo..=hi`, `lo..`, `..hi`, or `..=hi`"
+        }
+    };
+    (@hi $x:tt $lo:tt $op:tt [$($hi:tt)*] , $($fmt:tt)*) => {
+        $crate::__concat_assert_in_range! {@check $x $lo $op [$($hi)*] [$($fmt)*]}
+    };
+    (@hi $x:tt $lo:tt $op:tt [$($hi:tt)*] $t:tt $($rem:tt)*) => {
+        $crate::__concat_assert_in_range! {@hi $x $lo $op [$($hi)* $t] $($rem)*}
+    };
+    (@hi $x:tt $lo:tt $op:tt [$($hi:tt)*]) => {
+        $crate::__concat_assert_in_range! {@check $x $lo $op [$($hi)*] []}
+    };
+    (@check $x:tt $lo:tt ($op:tt) $hi:tt $fmt:tt) => {
+        match &$x {
+            x => {
+                $crate::__concat_assert_in_range! {@lower $x x $lo $fmt}
+                $crate::__concat_assert_in_range! {@upper $x x $op $hi $fmt}
+            }
+        }
+    };
+    (@lower $x:tt $x_val:ident [] $fmt:tt) => {};
+    (@lower $x:tt $x_val:ident [$($lo:tt)+] $fmt:tt) => {
+        $crate::__concat_assert_ord! {<= [$($lo)+, $x] $($lo)+, *$x_val, $fmt}
+    };
+    (@upper $x:tt $x_val:ident $op:tt [] $fmt:tt) => {};
+    (@upper $x:tt $x_val:ident $op:tt [$($hi:tt)+] $fmt:tt) => {
+        $crate::__concat_assert_ord! {$op [$x, $($hi)+] *$x_val, $($hi)+, $fmt}
+    };
+}
+
+// Panics with the message of a failed assertion, or of `concat_unreachable`-like macros,
+// `$prev` are the (already formatted) `(FmtArg, value)` pairs before the user's message,
+// and `$then` are the `concat_panic` arguments after the user's message.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_assert_panic {
+    ($prev:tt [] [$($then:tt)*]) => {
+        $crate::__concat_func_setup! {(|args| $crate::concat_panic(args)) $prev [$($then)*]}
+    };
+    ($prev:tt [capacity = $capacity:expr; $($fmt:tt)*] $then:tt) => {
+        $crate::__concat_assert_panic! {
+            @with_message
+            (|args| $crate::concat_panic_with_capacity::<{ $capacity }>(args))
+            $prev [$($fmt)*] $then
+        }
+    };
+    ($prev:tt [$($fmt:tt)*] $then:tt) => {
+        $crate::__concat_assert_panic! {
+            @with_message
+            (|args| $crate::concat_panic(args))
+            $prev [$($fmt)*] $then
+        }
+    };
+    (@with_message $callback:tt [$($prev:tt)*] [$($fmt:tt)*] $then:tt) => {
+        $crate::__concat_func_setup! {
+            {@then $callback $then}
+            [$($prev)* ($crate::FmtArg::DISPLAY, ": ")]
+            [$($fmt)*,]
+        }
+    };
+}
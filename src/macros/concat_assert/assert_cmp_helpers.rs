@@ -0,0 +1,27 @@
+use crate::{concat_panic_::compute_length, FmtArg, PanicVal};
+
+// The maximum length of the `Debug` output of an operand of
+// `concat_assert_eq` (and the other assertion macros that print `left` and `right`)
+// that's printed on a single line.
+//
+// This is 64 so that an operand line, which starts with the 8 byte long `"  left: "` label,
+// fits in an 80 column terminal,
+// while longer operands are pretty-printed over multiple lines.
+const ASSERT_CMP_MAX_LINE_LEN: usize = 64;
+
+// Returns the `FmtArg` that the operands of a failed
+// `concat_assert_eq`/`concat_assert_ne` are formatted with,
+// taking the `Debug` formatted operands.
+//
+// Returns `FmtArg::ALT_DEBUG` if either operand is too long to be printed on a single line,
+// `FmtArg::DEBUG` otherwise.
+#[doc(hidden)]
+pub const fn assert_cmp_fmtarg(left: &[PanicVal<'_>], right: &[PanicVal<'_>]) -> FmtArg {
+    if compute_length(&[left]) > ASSERT_CMP_MAX_LINE_LEN
+        || compute_length(&[right]) > ASSERT_CMP_MAX_LINE_LEN
+    {
+        FmtArg::ALT_DEBUG
+    } else {
+        FmtArg::DEBUG
+    }
+}
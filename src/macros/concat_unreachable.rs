@@ -0,0 +1,145 @@
+/// Panics with an "internal error: entered unreachable code" message,
+/// followed by the optional formatted arguments.
+///
+/// This is like [`core::unreachable`], formatting the arguments by
+/// delegating them to the [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// The panic message is
+/// `internal error: entered unreachable code: <the formatted arguments>`,
+/// or `internal error: entered unreachable code` when no arguments are passed.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_unreachable;
+///
+/// const fn digit_name(digit: u8) -> &'static str {
+///     match digit {
+///         0 => "zero",
+///         1..=8 => "some digit",
+///         9 => "nine",
+///         _ => concat_unreachable!("expected a digit, found ", digit),
+///     }
+/// }
+///
+/// assert_eq!(digit_name(9), "nine");
+///
+/// let res = std::panic::catch_unwind(|| digit_name(10));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "internal error: entered unreachable code: expected a digit, found 10",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_unreachable {
+    () => {
+        $crate::concat_panic!("internal error: entered unreachable code")
+    };
+    ($($fmt:tt)*) => {
+        $crate::__concat_assert_panic! {
+            [($crate::FmtArg::DISPLAY, "internal error: entered unreachable code")]
+            [$($fmt)*]
+            []
+        }
+    };
+}
+
+/// Panics with a "not yet implemented" message,
+/// followed by the optional formatted arguments.
+///
+/// This is like [`core::todo`], formatting the arguments by
+/// delegating them to the [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// The panic message is `not yet implemented: <the formatted arguments>`,
+/// or `not yet implemented` when no arguments are passed.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::concat_todo;
+///
+/// const fn parse_radix(radix: u32) -> u32 {
+///     match radix {
+///         10 | 16 => radix,
+///         _ => concat_todo!("parsing numbers in base ", radix),
+///     }
+/// }
+///
+/// let res = std::panic::catch_unwind(|| parse_radix(3));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "not yet implemented: parsing numbers in base 3",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_todo {
+    () => {
+        $crate::concat_panic!("not yet implemented")
+    };
+    ($($fmt:tt)*) => {
+        $crate::__concat_assert_panic! {
+            [($crate::FmtArg::DISPLAY, "not yet implemented")]
+            [$($fmt)*]
+            []
+        }
+    };
+}
+
+/// Panics with a "not implemented" message,
+/// followed by the optional formatted arguments.
+///
+/// This is like [`core::unimplemented`], formatting the arguments by
+/// delegating them to the [`concat_panic`](macro@crate::concat_panic) macro,
+/// so they can start with the `capacity = ...;` and `$fmtarg;` arguments.
+///
+/// The panic message is `not implemented: <the formatted arguments>`,
+/// or `not implemented` when no arguments are passed.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_unimplemented, FmtArg};
+///
+/// const fn opcode_len(opcode: u8) -> usize {
+///     match opcode {
+///         0x00..=0x7F => 1,
+///         _ => concat_unimplemented!(FmtArg::HEX; "opcode 0x", opcode),
+///     }
+/// }
+///
+/// let res = std::panic::catch_unwind(|| opcode_len(0xC3));
+/// assert_eq!(
+///     res.unwrap_err().downcast_ref::<String>().unwrap(),
+///     "not implemented: opcode 0xC3",
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! concat_unimplemented {
+    () => {
+        $crate::concat_panic!("not implemented")
+    };
+    ($($fmt:tt)*) => {
+        $crate::__concat_assert_panic! {
+            [($crate::FmtArg::DISPLAY, "not implemented")]
+            [$($fmt)*]
+            []
+        }
+    };
+}
@@ -0,0 +1,181 @@
+// This module isn't `#[macro_use]`,
+// so that the `panic` macro doesn't shadow `core::panic` inside this crate.
+
+/// Panics with a message formatted from a std-like format string.
+///
+/// This requires the `"fmt_str"` feature, disabled by default.
+///
+/// This is an alternative syntax for [`concat_panic`](macro@crate::concat_panic),
+/// the format string is parsed at compile-time,
+/// then lowered to the arguments of that macro.
+///
+/// [**Examples below**](#examples)
+///
+/// # Syntax
+///
+/// This macro uses this syntax:
+/// ```text
+/// panic!(
+///     $(capacity = $capacity:expr;)?
+///     $format_string:literal
+///     $(, $positional_arg:expr)*
+///     $(, $named_arg:ident = $named_arg_value:expr)*
+///     $(,)?
+/// )
+/// ```
+///
+/// `$capacity` is the same optional argument as in
+/// [`concat_panic`](macro@crate::concat_panic#syntax).
+///
+/// `$format_string` is a string literal,
+/// with `{...}` placeholders that are replaced with the formatted arguments.
+/// Like in std, `{{` and `}}` are escaped braces.
+///
+/// Passing no arguments (`panic!()`) panics with the `"explicit panic"` message.
+///
+/// # Format string
+///
+/// Placeholders use this syntax:
+/// ```text
+/// {[argument][:[[fill]align][+][#][0][width][grouping][.precision][type]]}
+/// ```
+///
+/// `argument` can be:
+/// - nothing: the next positional argument.
+/// - an integer: the positional argument at that index.
+/// - an identifier: the named argument with that name,
+/// or the variable/constant with that name if there's no named argument called that.
+///
+/// The formatting spec after the `:` is the same as std's, except that:
+/// - `type` can be nothing (`Display`), `?`, `x`, `X`, `o`, `b`, `x?`, or `X?`.
+/// The `x`, `X`, `o`, and `b` types `Debug` format the argument,
+/// with integers formatted in that radix.
+/// - width and precision arguments (eg: `{:1$}`, `{:.*}`, `{:width$}`) aren't supported,
+/// widths and precisions must be integer literals no larger than `255`.
+/// - the `-` flag isn't supported.
+/// - `grouping` can be `_` or `,`,
+/// which separate the digits of integers into groups
+/// (eg: `{:_}` writes `1_000_000`, `{:#_X}` writes `0xDEAD_BEEF`),
+/// this has no std equivalent.
+///
+/// Errors in the format string, and unused arguments, are reported at compile-time.
+///
+/// Each argument is evaluated once, even if it's used multiple times in the format string.
+///
+/// # Limitations
+///
+#[doc = crate::doc_macros::limitation_docs!()]
+///
+/// # Examples
+///
+/// ### Runtime panic
+///
+/// ```rust
+/// let x = 3u8;
+/// let y = 255u32;
+///
+/// let res = std::panic::catch_unwind(|| {
+///     const_panic::panic!("x = {x:?}, y = {:#X}, name = {name:>6}", y, name = "foo")
+/// });
+///
+/// let msg = res.unwrap_err();
+/// assert_eq!(
+///     msg.downcast_ref::<String>().unwrap(),
+///     "x = 3, y = 0xFF, name =    foo",
+/// );
+///
+/// ```
+///
+/// ### Compile-time panic
+///
+/// ```compile_fail
+/// const fn pair_sum(pair: [u32; 2], limit: u32) -> u32 {
+///     let sum = pair[0] + pair[1];
+///     if sum > limit {
+///         const_panic::panic!("\nthe sum of {pair:?} is {sum}, which is larger than {limit}");
+///     }
+///     sum
+/// }
+///
+/// const _: u32 = pair_sum([5, 8], 10);
+/// ```
+/// The above code produces this compile-time error:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///   --> src/macros/fmt_str_macros.rs:11:16
+///    |
+/// 9  | const _: u32 = pair_sum([5, 8], 10);
+///    |                ^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at '
+/// the sum of [5, 8] is 13, which is larger than 10', src/macros/fmt_str_macros.rs:5:9
+/// ```
+///
+/// ### Format string error
+///
+/// ```compile_fail
+/// let x = 3u8;
+/// const_panic::panic!("{x:e}");
+/// ```
+/// The above code produces this compile-time error:
+/// ```text
+/// error: invalid format string: unsupported formatting type `e`, expected one of `?`, `x`, `X`, `o`, `b`, `x?`, `X?`, or nothing
+///  --> src/macros/fmt_str_macros.rs:117:21
+///   |
+/// 5 | const_panic::panic!("{x:e}");
+///   |                     ^^^^^^^
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt_str")))]
+#[macro_export]
+macro_rules! panic {
+    () => (
+        $crate::concat_panic!("explicit panic")
+    );
+    (capacity = $capacity:expr; $($args:tt)*) => (
+        $crate::__::__lower_fmt_str!{
+            ($crate) ($crate::concat_panic) (capacity = $capacity;) (true) $($args)*
+        }
+    );
+    ($($args:tt)*) => (
+        $crate::__::__lower_fmt_str!{($crate) ($crate::concat_panic) () (true) $($args)*}
+    );
+}
+
+/// Formats a std-like format string with [`PanicFmt`] constants into a `&'static str`
+///
+/// This requires the `"fmt_str"` and `"non_basic"` features.
+///
+/// This takes the same format strings as the [`panic`] macro,
+/// formatting the arguments the same as the [`concat_`] macro,
+/// also requiring the arguments to be constant expressions.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::format_;
+///
+/// const NAME: &str = "foo";
+/// const VALUES: [u8; 3] = [3, 5, 8];
+///
+/// assert_eq!(
+///     format_!("{NAME}: {VALUES:?}, {0:#x} {0:08b}", 10u8),
+///     "foo: [3, 5, 8], 0xa 00001010",
+/// );
+///
+/// ```
+///
+/// [`PanicFmt`]: crate::fmt::PanicFmt
+/// [`panic`]: macro@crate::panic
+/// [`concat_`]: macro@crate::concat_
+///
+#[cfg(feature = "non_basic")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "fmt_str", feature = "non_basic")))
+)]
+#[macro_export]
+macro_rules! format_ {
+    () => ("");
+    ($($args:tt)*) => (
+        $crate::__::__lower_fmt_str!{($crate) ($crate::concat_) () (false) $($args)*}
+    );
+}
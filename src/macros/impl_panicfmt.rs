@@ -13,13 +13,21 @@
 /// (and must go in this order):
 ///
 /// - `#[pfmt(display_fmt = $display_fmt:expr)]`[**(example below)**](#display-example):
-/// Tells the macro to use the `$display_fmt` function to Display-format the type.
+///   Tells the macro to use the `$display_fmt` function to Display-format the type.
 ///
 ///
 /// - `#[pfmt(panicvals_lower_bound = $panicvals_lower_bound:expr)]`:
-/// Tells the macro to use at least `$panicvals_lower_bound` [`PanicVal`]s for
-/// formatting the type, useful for Display formatting with the
-/// `#[pfmt(display_fmt = ...)]` attribute.
+///   Tells the macro to use at least `$panicvals_lower_bound` [`PanicVal`]s for
+///   formatting the type, useful for Display formatting with the
+///   `#[pfmt(display_fmt = ...)]` attribute.
+///
+///
+/// - `#[pfmt(flags($($flag:ident = $flag_value:expr),+))]`[**(example below)**](#flags-example):
+///   Formats the type as a set of bit flags, using [`FlagsFmt`](crate::fmt::FlagsFmt),
+///   eg: `READ | EXEC | 0x40` when Display formatted,
+///   and `Perms(READ | EXEC | 0x40)` when Debug formatted.
+///   This can only be used (as the only attribute) on structs without generic parameters
+///   that have a single integer field.
 ///
 ///
 /// # Limitations
@@ -345,6 +353,37 @@
 ///
 /// ```
 ///
+/// <a id = "flags-example"></a>
+/// ### Flags formatting
+///
+/// ```rust
+/// use const_panic::{impl_panicfmt, FmtArg};
+///
+/// const RW: Perms = Perms(Perms::READ.0 | Perms::WRITE.0);
+///
+/// assert_eq!(const_panic::concat_!(display: RW), "READ | WRITE");
+/// assert_eq!(const_panic::concat_!(debug: RW), "Perms(READ | WRITE)");
+///
+/// // bits that aren't part of any flag are written in hexadecimal
+/// assert_eq!(const_panic::concat_!(Perms(0x45)), "Perms(READ | EXEC | 0x40)");
+///
+/// assert_eq!(const_panic::concat_!(Perms(0)), "Perms(0x0)");
+///
+/// struct Perms(u32);
+///
+/// impl Perms {
+///     const READ: Self = Self(1);
+///     const WRITE: Self = Self(2);
+///     const EXEC: Self = Self(4);
+/// }
+///
+/// impl_panicfmt! {
+///     #[pfmt(flags(READ = Perms::READ.0, WRITE = Perms::WRITE.0, EXEC = Perms::EXEC.0))]
+///     struct Perms(u32);
+/// }
+///
+/// ```
+///
 /// <a id = "all-the-syntax"></a>
 /// ### All the syntax
 ///
@@ -423,6 +462,18 @@
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
 #[macro_export]
 macro_rules! impl_panicfmt {
+    (
+        #[pfmt(flags($($flags:tt)*))]
+        struct $typename:ident ($int:ty $(,)?);
+    ) => (
+        $crate::__impl_panicfmt_flags!{$typename 0 $int; $($flags)*}
+    );
+    (
+        #[pfmt(flags($($flags:tt)*))]
+        struct $typename:ident { $field:ident: $int:ty $(,)? }
+    ) => (
+        $crate::__impl_panicfmt_flags!{$typename $field $int; $($flags)*}
+    );
     (
         $(# $attrs:tt)*
         $kind:ident $typename:ident < $($rem:tt)*
@@ -445,6 +496,47 @@ macro_rules! impl_panicfmt {
     );
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_panicfmt_flags {
+    (
+        $typename:ident $field:tt $int:ty;
+        $($flag:ident = $flag_value:expr),+ $(,)?
+    ) => (
+        impl $crate::PanicFmt for $typename {
+            type This = Self;
+            type Kind = $crate::fmt::IsCustomType;
+
+            const PV_COUNT: $crate::__::usize =
+                $crate::fmt::FlagsFmt::pv_count([$($crate::__::stringify!($flag)),+].len());
+        }
+
+        impl $typename {
+            pub const fn to_panicvals(
+                &self,
+                fmtarg: $crate::FmtArg,
+            ) -> [$crate::PanicVal<'static>; <$typename as $crate::PanicFmt>::PV_COUNT] {
+                let bits: $int = self.$field;
+
+                // casting through the unsigned type avoids sign-extending signed integers
+                $crate::fmt::FlagsFmt::new(
+                    $crate::__::stringify!($typename),
+                    bits as <$int as $crate::__::FlagsBits>::Unsigned as $crate::__::u128,
+                    &[$(
+                        (
+                            $crate::__::stringify!($flag),
+                            ($flag_value)
+                                as <$int as $crate::__::FlagsBits>::Unsigned
+                                as $crate::__::u128,
+                        )
+                    ),+],
+                )
+                .to_panicvals(fmtarg)
+            }
+        }
+    );
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_panicfmt_step_aaa {
@@ -538,7 +538,7 @@ macro_rules! __to_pvf_expr {
         $crate::__to_pvf_inner!{
             $fmtargs
 
-            [$($prev)* ($other, display, $reff)]
+            [$($prev)* ($other, __literal, $reff)]
 
             [$($rem)*]
         }
@@ -591,6 +591,566 @@ macro_rules! __to_pvf_used_length {
     };
 }
 
+/// Formats an array or slice of any [`PanicFmt`] type into an array of `PanicVal`s.
+///
+/// Slices and arrays of primitive types can be formatted directly,
+/// this macro is for formatting slices and arrays of other types,
+/// because `const fn`s can't call the formatting methods of generic types.
+///
+/// # Syntax
+///
+/// ```text
+/// array_panicvals!($fmtarg:expr; [$Elem:ty; $len:expr] => $array:expr)
+///
+/// array_panicvals!($fmtarg:expr; [$Elem:ty; ..$max_len:expr] => $slice:expr)
+/// ```
+///
+/// `$fmtarg` is the [`FmtArg`](crate::FmtArg) argument that the elements are formatted with.
+///
+/// The `[$Elem; $len]` syntax formats `$array`, a `[$Elem; $len]` array.
+/// This outputs an array of
+/// [`fmt::array_pv_count(<$Elem as PanicFmt>::PV_COUNT, $len)`](crate::fmt::array_pv_count)
+/// `PanicVal`s.
+///
+/// The `[$Elem; ..$max_len]` syntax formats `$slice`, which can be
+/// a `[$Elem]` slice of any length.
+/// Only the first `$max_len` elements are formatted, followed by `...` if there's more.
+/// This outputs an array of
+/// [`fmt::slice_pv_count(<$Elem as PanicFmt>::PV_COUNT, $max_len)`](crate::fmt::slice_pv_count)
+/// `PanicVal`s.
+///
+/// Nested arrays and slices are supported by writing the `[...]` syntax
+/// as the element type, eg: `[[u8; 4]; 4] => GRID`, `[[Point; 2]; ..8] => pairs`.
+/// Nested arrays must be written with this syntax, type aliases are not supported.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{array_panicvals, concat_, FmtArg};
+///
+/// #[derive(Copy, Clone)]
+/// struct Point {
+///     x: u8,
+///     y: u8,
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     struct Point {
+///         x: u8,
+///         y: u8,
+///     }
+/// }
+///
+/// const POINTS: [Point; 2] = [Point { x: 3, y: 5 }, Point { x: 8, y: 13 }];
+///
+/// assert_eq!(
+///     concat_!(array_panicvals!(FmtArg::DEBUG; [Point; 2] => POINTS)),
+///     "[Point { x: 3, y: 5 }, Point { x: 8, y: 13 }]",
+/// );
+///
+/// // only the first 1 element of the slice is formatted
+/// assert_eq!(
+///     concat_!(array_panicvals!(FmtArg::DEBUG; [Point; ..1] => POINTS)),
+///     "[Point { x: 3, y: 5 }, ...]",
+/// );
+///
+/// const GRID: [[u8; 2]; 2] = [[1, 2], [3, 4]];
+///
+/// assert_eq!(
+///     concat_!(array_panicvals!(FmtArg::ALT_DEBUG; [[u8; 2]; 2] => GRID)),
+///     concat!(
+///         "[\n",
+///         "    [\n",
+///         "        1,\n",
+///         "        2,\n",
+///         "    ],\n",
+///         "    [\n",
+///         "        3,\n",
+///         "        4,\n",
+///         "    ],\n",
+///         "]",
+///     ),
+/// );
+///
+/// ```
+///
+/// [`PanicFmt`]: crate::fmt::PanicFmt
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[macro_export]
+macro_rules! array_panicvals {
+    ($fmtarg:expr; $array_ty:tt => $array:expr $(,)?) => {
+        $crate::__array_panicvals! {$fmtarg; $array_ty => $array}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __array_panicvals {
+    ($fmtarg:expr; [[$($inner:tt)*]; ..$max_len:expr] => $array:expr) => {
+        $crate::__array_panicvals! {
+            @inner
+            $fmtarg,
+            (&[[$($inner)*]]) (&[[$($inner)*]]) = $array,
+            $crate::fmt::slice_pv_count($crate::__array_pv_count!([$($inner)*]), $max_len),
+            slice($max_len),
+            nested([$($inner)*])
+        }
+    };
+    ($fmtarg:expr; [[$($inner:tt)*]; $len:expr] => $array:expr) => {
+        $crate::__array_panicvals! {
+            @inner
+            $fmtarg,
+            (&[[$($inner)*]; $len]) (&[[$($inner)*]]) = $array,
+            $crate::fmt::array_pv_count($crate::__array_pv_count!([$($inner)*]), $len),
+            array($len),
+            nested([$($inner)*])
+        }
+    };
+    ($fmtarg:expr; [$elem:ty; ..$max_len:expr] => $array:expr) => {
+        $crate::__array_panicvals! {
+            @inner
+            $fmtarg,
+            (&[$elem]) (&[$elem]) = $array,
+            $crate::fmt::slice_pv_count(<$elem as $crate::__::PanicFmt>::PV_COUNT, $max_len),
+            slice($max_len),
+            single()
+        }
+    };
+    ($fmtarg:expr; [$elem:ty; $len:expr] => $array:expr) => {
+        $crate::__array_panicvals! {
+            @inner
+            $fmtarg,
+            (&[$elem; $len]) (&[$elem]) = $array,
+            $crate::fmt::array_pv_count(<$elem as $crate::__::PanicFmt>::PV_COUNT, $len),
+            array($len),
+            single()
+        }
+    };
+    (
+        @inner
+        $fmtarg:expr,
+        ($($array_ty:tt)*) ($($slice_ty:tt)*) = $array:expr,
+        $pv_count:expr,
+        $kind:ident($max_len:expr),
+        $elem_kind:ident $elem_args:tt
+    ) => ({
+        use $crate::{fmt, PanicVal};
+
+        let array: $($array_ty)* = &$array;
+        let array: $($slice_ty)* = array;
+
+        let mut fmtarg: $crate::FmtArg = $fmtarg.indent();
+        if array.is_empty() {
+            fmtarg = fmtarg.set_alternate(false);
+        }
+
+        let shown = $crate::utils::min_usize(array.len(), $max_len);
+
+        let mut out = [PanicVal::EMPTY; $pv_count];
+        out[0] = fmt::OpenBracket.to_panicval(fmtarg);
+        let mut out_len = 1;
+
+        let mut i = 0;
+        while i < shown {
+            let elem = $crate::__array_panicvals!(@elem fmtarg, array[i], $elem_kind $elem_args);
+
+            let mut j = 0;
+            while j < elem.len() {
+                out[out_len] = elem[j];
+                out_len += 1;
+                j += 1;
+            }
+
+            out[out_len] = if i + 1 == array.len() {
+                fmt::COMMA_TERM
+            } else {
+                fmt::COMMA_SEP
+            }
+            .to_panicval(fmtarg);
+            out_len += 1;
+            i += 1;
+        }
+
+        $crate::__array_panicvals!(@truncated $kind, out, out_len, fmtarg, shown < array.len());
+
+        out[out_len] = fmt::CloseBracket.to_panicval(fmtarg.unindent());
+        out
+    });
+    (@elem $fmtarg:ident, $elem:expr, single()) => {
+        $crate::coerce_fmt!($elem).to_panicvals($fmtarg)
+    };
+    (@elem $fmtarg:ident, $elem:expr, nested($inner_ty:tt)) => {
+        $crate::__array_panicvals!($fmtarg; $inner_ty => $elem)
+    };
+    (@truncated array, $($_:tt)*) => {};
+    (@truncated slice, $out:ident, $out_len:ident, $fmtarg:ident, $is_truncated:expr) => {
+        if $is_truncated {
+            $out[$out_len] = PanicVal::write_str("...");
+            $out[$out_len + 1] = fmt::COMMA_TERM.to_panicval($fmtarg);
+            $out_len += 2;
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __array_pv_count {
+    ([[$($inner:tt)*]; ..$max_len:expr]) => {
+        $crate::fmt::slice_pv_count($crate::__array_pv_count!([$($inner)*]), $max_len)
+    };
+    ([[$($inner:tt)*]; $len:expr]) => {
+        $crate::fmt::array_pv_count($crate::__array_pv_count!([$($inner)*]), $len)
+    };
+    ([$elem:ty; ..$max_len:expr]) => {
+        $crate::fmt::slice_pv_count(<$elem as $crate::__::PanicFmt>::PV_COUNT, $max_len)
+    };
+    ([$elem:ty; $len:expr]) => {
+        $crate::fmt::array_pv_count(<$elem as $crate::__::PanicFmt>::PV_COUNT, $len)
+    };
+}
+
+/// Formats a tuple of any [`PanicFmt`] types into an array of `PanicVal`s.
+///
+/// Tuple expressions can be formatted directly by the formatting macros,
+/// eg: `concat_panic!{"pair: ", (a, b)}`,
+/// this macro is for formatting tuples held in variables, fields, and nested tuples,
+/// because `const fn`s can't call the formatting methods of generic types.
+///
+/// # Syntax
+///
+/// ```text
+/// tuple_panicvals!($fmtarg:expr; ($($Elem:ty),+ $(,)?) => $tuple:expr)
+/// ```
+///
+/// `$fmtarg` is the [`FmtArg`](crate::FmtArg) argument that the elements are formatted with.
+///
+/// This formats `$tuple`, a `($($Elem,)+)` tuple (or a reference to one),
+/// and outputs an array of `<($($Elem,)+) as PanicFmt>::PV_COUNT` `PanicVal`s.
+///
+/// Nested tuples are supported by writing the tuple syntax as the element type,
+/// eg: `(u8, (&str, u8)) => TUPLE`.
+/// Nested tuples must be written with this syntax, type aliases are not supported.
+///
+/// The tuple type is used to compute the length of the returned array,
+/// so it can't use the generic parameters of the enclosing item,
+/// lifetimes can be written as `'_` instead (eg: `(u8, &'_ str)`).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_, tuple_panicvals, FmtArg};
+///
+/// #[derive(Copy, Clone)]
+/// struct Point {
+///     x: u8,
+///     y: u8,
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     struct Point {
+///         x: u8,
+///         y: u8,
+///     }
+/// }
+///
+/// const PAIR: (u8, (&str, Point)) = (3, ("hello", Point { x: 5, y: 8 }));
+///
+/// assert_eq!(
+///     concat_!(tuple_panicvals!(FmtArg::DEBUG; (u8, (&str, Point)) => PAIR)),
+///     r#"(3, ("hello", Point { x: 5, y: 8 }))"#,
+/// );
+///
+/// assert_eq!(
+///     concat_!(tuple_panicvals!(FmtArg::ALT_DEBUG; (&str, Point) => PAIR.1)),
+///     concat!(
+///         "(\n",
+///         "    \"hello\",\n",
+///         "    Point {\n",
+///         "        x: 5,\n",
+///         "        y: 8,\n",
+///         "    },\n",
+///         ")",
+///     ),
+/// );
+///
+/// ```
+///
+/// [`PanicFmt`]: crate::fmt::PanicFmt
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[macro_export]
+macro_rules! tuple_panicvals {
+    ($fmtarg:expr; ($($tuple_ty:tt)+) => $tuple:expr $(,)?) => {
+        $crate::__tuple_panicvals! {$fmtarg; ($($tuple_ty)+) => $tuple}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tuple_panicvals {
+    ($fmtarg:expr; ($($tuple_ty:tt)+) => $tuple:expr) => {
+        $crate::__tuple_panicvals! {
+            @parse
+            ($fmtarg, ($($tuple_ty)+), $tuple)
+            []
+            [e0 e1 e2 e3 e4 e5 e6 e7 e8 e9 e10 e11]
+            [$($tuple_ty)+]
+        }
+    };
+    (
+        @parse $args:tt [$($prev:tt)*] [$ident:ident $($idents:ident)*]
+        [($($inner:tt)+) $(, $($rem:tt)*)?]
+    ) => {
+        $crate::__tuple_panicvals! {
+            @parse
+            $args
+            [$($prev)* ($ident nested(($($inner)+)))]
+            [$($idents)*]
+            [$($($rem)*)?]
+        }
+    };
+    (
+        @parse $args:tt [$($prev:tt)*] [$ident:ident $($idents:ident)*]
+        [$elem:ty $(, $($rem:tt)*)?]
+    ) => {
+        $crate::__tuple_panicvals! {
+            @parse
+            $args
+            [$($prev)* ($ident single())]
+            [$($idents)*]
+            [$($($rem)*)?]
+        }
+    };
+    (@parse $args:tt $prev:tt [] [$($rem:tt)+]) => {
+        $crate::__::compile_error!("tuples with more than 12 elements are not supported")
+    };
+    (
+        @parse
+        ($fmtarg:expr, ($($tuple_ty:tt)+), $tuple:expr)
+        [$(($ident:ident $elem_kind:ident $elem_args:tt))+]
+        $idents:tt
+        []
+    ) => ({
+        use $crate::{fmt, PanicVal};
+
+        let tuple: &($($tuple_ty)+) = &$tuple;
+        let ($($ident,)+) = tuple;
+
+        let fmtarg: $crate::FmtArg = $fmtarg.indent();
+        let elem_count = [$(stringify!($ident)),+].len();
+
+        let mut out =
+            [PanicVal::EMPTY; <($($tuple_ty)+) as $crate::__::PanicFmt>::PV_COUNT];
+        out[0] = fmt::OpenParen.to_panicval(fmtarg);
+        let mut out_len = 1;
+        let mut i = 0;
+
+        $({
+            let elem = $crate::__tuple_panicvals!(@elem fmtarg, $ident, $elem_kind $elem_args);
+
+            let mut j = 0;
+            while j < elem.len() {
+                out[out_len] = elem[j];
+                out_len += 1;
+                j += 1;
+            }
+
+            out[out_len] = if elem_count == 1 {
+                $crate::__::single_tuple_comma(fmtarg)
+            } else if i + 1 == elem_count {
+                fmt::COMMA_TERM.to_panicval(fmtarg)
+            } else {
+                fmt::COMMA_SEP.to_panicval(fmtarg)
+            };
+            out_len += 1;
+            i += 1;
+        })+
+
+        out[out_len] = fmt::CloseParen.to_panicval(fmtarg.unindent());
+        out
+    });
+    (@elem $fmtarg:ident, $ident:ident, single()) => {
+        $crate::coerce_fmt!(*$ident).to_panicvals($fmtarg)
+    };
+    (@elem $fmtarg:ident, $ident:ident, nested($inner_ty:tt)) => {
+        $crate::__tuple_panicvals!($fmtarg; $inner_ty => *$ident)
+    };
+}
+
+/// Formats an `Option` of any [`PanicFmt`] type into an array of `PanicVal`s.
+///
+/// `Option`s of primitive types can be formatted directly,
+/// this macro is for formatting `Option`s of other types,
+/// because `const fn`s can't call the formatting methods of generic types.
+///
+/// # Syntax
+///
+/// ```text
+/// option_panicvals!($fmtarg:expr; Option<$T:ty> => $option:expr)
+/// ```
+///
+/// `$fmtarg` is the [`FmtArg`](crate::FmtArg) argument that the payload is formatted with.
+///
+/// This formats `$option`, an `Option<$T>` (or a reference to one),
+/// and outputs an array of `<Option<$T> as PanicFmt>::PV_COUNT` `PanicVal`s.
+///
+/// The `Option` type is used to compute the length of the returned array,
+/// so it can't use the generic parameters of the enclosing item,
+/// lifetimes can be written as `'_` instead (eg: `Option<&'_ str>`).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_, option_panicvals, FmtArg};
+///
+/// #[derive(Copy, Clone)]
+/// struct Point {
+///     x: u8,
+///     y: u8,
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     struct Point {
+///         x: u8,
+///         y: u8,
+///     }
+/// }
+///
+/// const SOME: Option<Point> = Some(Point { x: 3, y: 5 });
+/// const NONE: Option<Point> = None;
+///
+/// assert_eq!(
+///     concat_!(option_panicvals!(FmtArg::DEBUG; Option<Point> => SOME)),
+///     "Some(Point { x: 3, y: 5 })",
+/// );
+///
+/// assert_eq!(
+///     concat_!(option_panicvals!(FmtArg::DEBUG; Option<Point> => NONE)),
+///     "None",
+/// );
+///
+/// ```
+///
+/// [`PanicFmt`]: crate::fmt::PanicFmt
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[macro_export]
+macro_rules! option_panicvals {
+    ($fmtarg:expr; Option<$T:ty> => $option:expr $(,)?) => {{
+        use $crate::PanicVal;
+
+        let option: &$crate::__::Option<$T> = &$option;
+        let fmtarg: $crate::FmtArg = $fmtarg;
+
+        let mut out = [PanicVal::EMPTY; <$crate::__::Option<$T> as $crate::__::PanicFmt>::PV_COUNT];
+        match option {
+            $crate::__::Some(x) => $crate::__newtype_variant_panicvals!(out, fmtarg, "Some", *x),
+            $crate::__::None => out[0] = PanicVal::write_str("None"),
+        }
+        out
+    }};
+}
+
+/// Formats a `Result` of any [`PanicFmt`] types into an array of `PanicVal`s.
+///
+/// `Result`s of primitive types can be formatted directly
+/// (requires the `"rust_1_64"` feature),
+/// this macro is for formatting `Result`s of other types,
+/// because `const fn`s can't call the formatting methods of generic types.
+///
+/// # Syntax
+///
+/// ```text
+/// result_panicvals!($fmtarg:expr; Result<$T:ty, $E:ty> => $result:expr)
+/// ```
+///
+/// `$fmtarg` is the [`FmtArg`](crate::FmtArg) argument that the payload is formatted with.
+///
+/// This formats `$result`, a `Result<$T, $E>` (or a reference to one),
+/// and outputs an array of
+/// `4 + max_usize(<$T as PanicFmt>::PV_COUNT, <$E as PanicFmt>::PV_COUNT)` `PanicVal`s.
+///
+/// The `Result` type is used to compute the length of the returned array,
+/// so it can't use the generic parameters of the enclosing item,
+/// lifetimes can be written as `'_` instead (eg: `Result<u32, &'_ str>`).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_, result_panicvals, FmtArg};
+///
+/// #[derive(Copy, Clone)]
+/// enum MyError {
+///     TooLarge { max: u32 },
+/// }
+///
+/// const_panic::impl_panicfmt! {
+///     enum MyError {
+///         TooLarge { max: u32 },
+///     }
+/// }
+///
+/// const OK: Result<u32, MyError> = Ok(3);
+/// const ERR: Result<u32, MyError> = Err(MyError::TooLarge { max: 5 });
+///
+/// assert_eq!(
+///     concat_!(result_panicvals!(FmtArg::DEBUG; Result<u32, MyError> => OK)),
+///     "Ok(3)",
+/// );
+///
+/// assert_eq!(
+///     concat_!(result_panicvals!(FmtArg::DEBUG; Result<u32, MyError> => ERR)),
+///     "Err(TooLarge { max: 5 })",
+/// );
+///
+/// ```
+///
+/// [`PanicFmt`]: crate::fmt::PanicFmt
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[macro_export]
+macro_rules! result_panicvals {
+    ($fmtarg:expr; Result<$T:ty, $E:ty> => $result:expr $(,)?) => {{
+        use $crate::PanicVal;
+
+        let result: &$crate::__::Result<$T, $E> = &$result;
+        let fmtarg: $crate::FmtArg = $fmtarg;
+
+        let mut out = [PanicVal::EMPTY;
+            4 + $crate::utils::max_usize(
+                <$T as $crate::__::PanicFmt>::PV_COUNT,
+                <$E as $crate::__::PanicFmt>::PV_COUNT,
+            )];
+        match result {
+            $crate::__::Ok(x) => $crate::__newtype_variant_panicvals!(out, fmtarg, "Ok", *x),
+            $crate::__::Err(x) => $crate::__newtype_variant_panicvals!(out, fmtarg, "Err", *x),
+        }
+        out
+    }};
+}
+
+// Writes a `$variant($field)` variant into the start of `$out`,
+// leaving the remaining elements as they were.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_variant_panicvals {
+    ($out:ident, $fmtarg:ident, $variant:literal, $field:expr) => {{
+        use $crate::{fmt, PanicVal};
+
+        let fmtarg = $fmtarg.indent();
+        let field = $crate::coerce_fmt!($field).to_panicvals(fmtarg);
+
+        $out[0] = PanicVal::write_str($variant);
+        $out[1] = fmt::OpenParen.to_panicval(fmtarg);
+
+        let mut i = 0;
+        while i < field.len() {
+            $out[2 + i] = field[i];
+            i += 1;
+        }
+
+        $out[2 + i] = fmt::COMMA_TERM.to_panicval(fmtarg);
+        $out[3 + i] = fmt::CloseParen.to_panicval(fmtarg.unindent());
+    }};
+}
+
 /// Helper macro for defining and using a `macro_rules!` macro inline.
 ///
 /// The reason this was defined is to work around a limitation in stable const-eval,
@@ -1,6 +1,10 @@
 use crate::{
-    fmt::{FmtArg, FmtKind, NumberFmt},
-    utils::{string_cap, Packed, PreFmtString, RangedBytes, Sign, TailShortString, WasTruncated},
+    float_formatting::FloatVal,
+    fmt::{Alignment, FmtArg, FmtKind, NumberFmt},
+    utils::{
+        string_cap, tail_byte_array, NumberChunk, Packed, PackedChar, PreFmtString, RangedBytes,
+        Sign, StartAndBytes, WasTruncated,
+    },
 };
 
 #[cfg(feature = "non_basic")]
@@ -15,8 +19,11 @@ use crate::{
 /// This has constructor functions to make a `PanicVal` from:
 /// - `bool`
 /// - Integers
+/// - Floats
 /// - `&str`
+/// - `&[u8]` byte strings
 /// - Arrays/Slices of primitives (with the "non_basic" feature, enabled by default)
+/// - Nested `&[PanicVal]` slices (with the "non_basic" feature, enabled by default)
 /// - [`ShortString`](crate::fmt::ShortString)
 /// (with the "non_basic" feature, enabled by default)
 ///
@@ -30,17 +37,48 @@ pub(crate) enum PanicVariant<'a> {
     Str(StrFmt, Packed<&'a str>),
     #[cfg(feature = "non_basic")]
     ShortString(StrFmt, TinyString<{ string_cap::TINY }>),
-    PreFmt(PreFmtString),
-    Int(IntVal),
+    PreFmt(StrFmt, PreFmtString),
+    Bytes(StrFmt, BytesFmt, Packed<&'a [u8]>),
+    Int(StrFmt, IntVal),
+    Float(StrFmt, FloatVal),
     #[cfg(feature = "non_basic")]
     Slice(crate::slice_stuff::Slice<'a>),
+    // the `PanicVal`s, and the `FmtArg::depth_limit` they were nested with
+    #[cfg(feature = "non_basic")]
+    Nested(Packed<&'a [PanicVal<'a>]>, u8),
 }
 
 pub(crate) enum PanicClass<'a> {
     PreFmt(RangedBytes<&'a [u8]>),
+    Bytes(RangedBytes<&'a [u8]>, BytesFmt),
     Int(IntVal),
+    Float(FloatVal),
     #[cfg(feature = "non_basic")]
     Slice(crate::slice_stuff::Slice<'a>),
+    #[cfg(feature = "non_basic")]
+    Nested(&'a [PanicVal<'a>], u8),
+}
+
+// How the bytes in a `PanicVariant::Bytes` are written
+#[derive(Copy, Clone)]
+pub(crate) enum BytesFmt {
+    // as text, replacing invalid utf8 with `U+FFFD`, like `String::from_utf8_lossy`
+    #[cfg_attr(not(feature = "rust_1_82"), allow(dead_code))]
+    Lossy,
+    // quoted, escaping ascii like byte strings, and invalid utf8 like `\xff`,
+    // eg: `"föo\xff"`
+    #[cfg_attr(not(feature = "rust_1_82"), allow(dead_code))]
+    Escaped,
+    // a byte string literal, eg: `b"foo\xff"`
+    ByteStr,
+    // the hexadecimal column of a hexdump row, eg: `: 7f45 4c46 0201  `,
+    // only the first `HEX_DUMP_ROW_LEN` bytes are written.
+    #[cfg(feature = "non_basic")]
+    HexRow,
+    // the ascii column of a hexdump row, eg: `.ELF...`,
+    // only the first `HEX_DUMP_ROW_LEN` bytes are written.
+    #[cfg(feature = "non_basic")]
+    AsciiRow,
 }
 
 #[derive(Copy, Clone)]
@@ -48,13 +86,16 @@ pub(crate) struct StrFmt {
     pub(crate) leftpad: u8,
     pub(crate) rightpad: u8,
     pub(crate) fmt_kind: FmtKind,
+    // the char that the padding is written with
+    pub(crate) fill: PackedChar,
 }
 
 impl StrFmt {
-    const DISPLAY: Self = Self {
+    pub(crate) const DISPLAY: Self = Self {
         leftpad: 0,
         rightpad: 0,
         fmt_kind: FmtKind::Display,
+        fill: PackedChar::new(' '),
     };
 
     pub const fn new(fmtarg: FmtArg) -> Self {
@@ -62,7 +103,70 @@ impl StrFmt {
             leftpad: 0,
             rightpad: 0,
             fmt_kind: fmtarg.fmt_kind,
+            fill: PackedChar::new(' '),
+        }
+    }
+
+    // Pads a value that is `char_len` chars long to `fmtarg.width` chars,
+    // using `default_alignment` if `fmtarg.alignment` is `None`.
+    pub(crate) const fn padded(
+        fmtarg: FmtArg,
+        fmt_kind: FmtKind,
+        char_len: usize,
+        default_alignment: Alignment,
+    ) -> Self {
+        let pad = if fmtarg.width() as usize > char_len {
+            (fmtarg.width() as usize - char_len) as u8
+        } else {
+            0
+        };
+
+        let alignment = match fmtarg.alignment() {
+            Some(x) => x,
+            None => default_alignment,
+        };
+
+        let (leftpad, rightpad) = match alignment {
+            Alignment::Left => (0, pad),
+            Alignment::Right => (pad, 0),
+            Alignment::Center => (pad / 2, pad - pad / 2),
+        };
+
+        Self {
+            leftpad,
+            rightpad,
+            fmt_kind,
+            fill: fmtarg.fill,
+        }
+    }
+
+    // Truncates `string` to `fmtarg.precision` chars, then pads it to `fmtarg.width` chars.
+    //
+    // Like in std, Debug-formatted strings are neither truncated nor padded.
+    pub(crate) const fn for_str(fmtarg: FmtArg, string: &str) -> (Self, &str) {
+        if let FmtKind::Debug = fmtarg.fmt_kind {
+            return (Self::new(fmtarg), string);
         }
+
+        let string = match fmtarg.precision() {
+            Some(precision) => {
+                let bytes = string.as_bytes();
+                let len = crate::utils::chars_byte_len(bytes, precision as usize);
+                // SAFETY: `chars_byte_len` returns the index of a char boundary
+                unsafe { core::str::from_utf8_unchecked(crate::utils::bytes_up_to(bytes, len)) }
+            }
+            None => string,
+        };
+
+        let char_len = crate::utils::char_count(string.as_bytes());
+        let this = Self::padded(fmtarg, FmtKind::Display, char_len, Alignment::Left);
+
+        (this, string)
+    }
+
+    // the length in bytes of the fill char
+    pub(crate) const fn fill_len(&self) -> usize {
+        self.fill.unwrap_or(' ').len_utf8()
     }
 }
 
@@ -70,26 +174,36 @@ impl<'a> PanicVal<'a> {
     /// A `PanicVal` that formats to nothing.
     pub const EMPTY: Self = PanicVal::write_str("");
 
-    /// How many spaces are printed before this
+    /// How many spaces (or fill chars) are printed before this
     pub const fn leftpad(&self) -> u8 {
         use self::PanicVariant as PV;
 
         match self.var {
-            PV::Str(strfmt, ..) => strfmt.leftpad,
+            PV::Str(strfmt, ..)
+            | PV::PreFmt(strfmt, ..)
+            | PV::Bytes(strfmt, ..)
+            | PV::Int(strfmt, ..)
+            | PV::Float(strfmt, ..) => strfmt.leftpad,
             #[cfg(feature = "non_basic")]
             PV::ShortString(strfmt, ..) => strfmt.leftpad,
-            _ => 0,
+            #[cfg(feature = "non_basic")]
+            PV::Slice(_) | PV::Nested(..) => 0,
         }
     }
-    /// How many spaces are printed after this
+    /// How many spaces (or fill chars) are printed after this
     pub const fn rightpad(&self) -> u8 {
         use self::PanicVariant as PV;
 
         match self.var {
-            PV::Str(strfmt, ..) => strfmt.rightpad,
+            PV::Str(strfmt, ..)
+            | PV::PreFmt(strfmt, ..)
+            | PV::Bytes(strfmt, ..)
+            | PV::Int(strfmt, ..)
+            | PV::Float(strfmt, ..) => strfmt.rightpad,
             #[cfg(feature = "non_basic")]
             PV::ShortString(strfmt, ..) => strfmt.rightpad,
-            _ => 0,
+            #[cfg(feature = "non_basic")]
+            PV::Slice(_) | PV::Nested(..) => 0,
         }
     }
 }
@@ -175,12 +289,94 @@ impl<'a> PanicVal<'a> {
             leftpad: 0,
             rightpad,
             fmt_kind: FmtKind::Display,
+            fill: PackedChar::new(' '),
         };
         Self {
             var: PanicVariant::ShortString(strfmt, concat.to_compact()),
         }
     }
 
+    /// Constructs a `PanicVal` which writes all the `PanicVal`s in `pvs`.
+    ///
+    /// This allows formatting a variable amount of `PanicVal`s,
+    /// and types whose formatting nests an unbounded amount of times.
+    ///
+    /// Up to [`fmtarg.depth_limit`] levels of nested `PanicVal`s
+    /// (including this one) are written,
+    /// any deeper levels are written as `...`.
+    /// This limit also ensures that self-referential `PanicVal`s stop being written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg, PanicVal};
+    ///
+    /// const INNER: &[PanicVal<'_>] = &[
+    ///     PanicVal::write_str("["),
+    ///     PanicVal::from_u8(3, FmtArg::DEBUG),
+    ///     PanicVal::write_str("]"),
+    /// ];
+    ///
+    /// const OUTER: &[PanicVal<'_>] = &[
+    ///     PanicVal::write_str("foo "),
+    ///     PanicVal::from_nested(INNER, FmtArg::DEBUG),
+    ///     PanicVal::write_str(" bar"),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<32>::from_panicvals(OUTER).unwrap(),
+    ///     "foo [3] bar",
+    /// );
+    ///
+    /// // a `PanicVal` that contains itself
+    /// static RECURSIVE: [PanicVal<'_>; 3] = [
+    ///     PanicVal::write_str("("),
+    ///     PanicVal::from_nested(&RECURSIVE, FmtArg::DEBUG.set_depth_limit(3)),
+    ///     PanicVal::write_str(")"),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<32>::from_panicvals(&RECURSIVE).unwrap(),
+    ///     "((((...))))",
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`fmtarg.depth_limit`]: crate::FmtArg::depth_limit
+    #[cfg(feature = "non_basic")]
+    pub const fn from_nested(pvs: &'a [PanicVal<'a>], fmtarg: FmtArg) -> Self {
+        Self {
+            var: PanicVariant::Nested(Packed(pvs), fmtarg.depth_limit()),
+        }
+    }
+
+    /// Constructs a `PanicVal` which writes `bytes` as a byte string literal,
+    /// escaping all non-printable-ascii bytes, eg: `b"foo\x00\xff"`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// const BYTES: &[u8] = b"bad\tmagic\x00\xff";
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<32>::from_panicvals(&[PanicVal::from_byte_str(BYTES)]).unwrap(),
+    ///     r#"b"bad\tmagic\x00\xff""#,
+    /// );
+    /// ```
+    pub const fn from_byte_str(bytes: &'a [u8]) -> Self {
+        Self::from_bytes(bytes, BytesFmt::ByteStr)
+    }
+
+    // Constructs a `PanicVal` which writes `bytes` as determined by `bytes_fmt`.
+    pub(crate) const fn from_bytes(bytes: &'a [u8], bytes_fmt: BytesFmt) -> Self {
+        Self {
+            var: PanicVariant::Bytes(StrFmt::DISPLAY, bytes_fmt, Packed(bytes)),
+        }
+    }
+
     #[inline(always)]
     pub(crate) const fn __new(var: PanicVariant<'a>) -> Self {
         Self { var }
@@ -199,13 +395,34 @@ impl<'a> PanicVal<'a> {
             }
             #[cfg(feature = "non_basic")]
             PanicVariant::ShortString(strfmt, str) => (*strfmt, PanicClass::PreFmt(str.ranged())),
-            PanicVariant::PreFmt(str) => (StrFmt::DISPLAY, PanicClass::PreFmt(str.ranged())),
-            PanicVariant::Int(int) => (StrFmt::DISPLAY, PanicClass::Int(*int)),
+            PanicVariant::PreFmt(strfmt, str) => (*strfmt, PanicClass::PreFmt(str.ranged())),
+            &PanicVariant::Bytes(strfmt, bytes_fmt, Packed(bytes)) => {
+                let end = match bytes_fmt {
+                    #[cfg(feature = "non_basic")]
+                    BytesFmt::HexRow | BytesFmt::AsciiRow => {
+                        crate::utils::min_usize(bytes.len(), crate::fmt::hex_dump::HEX_DUMP_ROW_LEN)
+                    }
+                    _ => bytes.len(),
+                };
+                let ranged = RangedBytes {
+                    start: 0,
+                    end,
+                    bytes,
+                };
+
+                (strfmt, PanicClass::Bytes(ranged, bytes_fmt))
+            }
+            PanicVariant::Int(strfmt, int) => (*strfmt, PanicClass::Int(*int)),
+            PanicVariant::Float(strfmt, float) => (*strfmt, PanicClass::Float(*float)),
             #[cfg(feature = "non_basic")]
             PanicVariant::Slice(slice) => (
                 StrFmt::new(slice.fmtarg.unpack()),
                 PanicClass::Slice(*slice),
             ),
+            #[cfg(feature = "non_basic")]
+            &PanicVariant::Nested(Packed(pvs), depth_limit) => {
+                (StrFmt::DISPLAY, PanicClass::Nested(pvs, depth_limit))
+            }
         }
     }
 
@@ -214,27 +431,29 @@ impl<'a> PanicVal<'a> {
         mut truncate_to: usize,
     ) -> (StrFmt, PanicClass<'_>, WasTruncated) {
         let (mut strfmt, class) = self.to_class();
+        let fill_len = strfmt.fill_len();
 
-        if strfmt.leftpad as usize > truncate_to {
+        if strfmt.leftpad as usize * fill_len > truncate_to {
             return (
                 StrFmt {
-                    leftpad: strfmt.leftpad - truncate_to as u8,
+                    leftpad: (truncate_to / fill_len) as u8,
                     rightpad: 0,
                     fmt_kind: FmtKind::Display,
+                    fill: strfmt.fill,
                 },
                 PanicClass::PreFmt(RangedBytes::EMPTY),
                 WasTruncated::Yes(0),
             );
         } else {
-            truncate_to -= strfmt.leftpad as usize;
+            truncate_to -= strfmt.leftpad as usize * fill_len;
         };
 
-        let was_trunc: WasTruncated;
+        let mut was_trunc: WasTruncated;
         let orig_len: usize;
 
         match class {
             PanicClass::PreFmt(str) => {
-                was_trunc = if let PanicVariant::PreFmt(pfmt) = self.var {
+                was_trunc = if let PanicVariant::PreFmt(_, pfmt) = self.var {
                     if pfmt.len() <= truncate_to {
                         WasTruncated::No
                     } else {
@@ -249,6 +468,10 @@ impl<'a> PanicVal<'a> {
                 };
                 orig_len = str.len();
             }
+            PanicClass::Bytes(bytes, bytes_fmt) => {
+                was_trunc = crate::utils::truncated_bytes_len(bytes, truncate_to, bytes_fmt);
+                orig_len = bytes.len();
+            }
             PanicClass::Int(int) => {
                 strfmt.fmt_kind = FmtKind::Display;
                 was_trunc = if int.len() <= truncate_to {
@@ -258,30 +481,58 @@ impl<'a> PanicVal<'a> {
                 };
                 orig_len = int.len();
             }
+            PanicClass::Float(float) => {
+                strfmt.fmt_kind = FmtKind::Display;
+                was_trunc = if float.len() <= truncate_to {
+                    WasTruncated::No
+                } else {
+                    WasTruncated::Yes(0)
+                };
+                orig_len = float.len();
+            }
             #[cfg(feature = "non_basic")]
-            PanicClass::Slice(_) => {
+            PanicClass::Slice(_) | PanicClass::Nested(..) => {
                 was_trunc = WasTruncated::No;
                 orig_len = 0;
             }
         }
         truncate_to -= was_trunc.get_length(orig_len);
 
-        strfmt.rightpad = crate::utils::min_usize(strfmt.rightpad as usize, truncate_to) as u8;
+        let rightpad = truncate_to / fill_len;
+        if (strfmt.rightpad as usize) > rightpad {
+            strfmt.rightpad = rightpad as u8;
+            if let WasTruncated::No = was_trunc {
+                was_trunc = WasTruncated::Yes(orig_len);
+            }
+        }
 
         (strfmt, class, was_trunc)
     }
 }
 
+// offsets of the bits in `IntVal.flags`
+const INT_NEGATIVE_OFFSET: u8 = 0;
+const INT_ALTERNATE_OFFSET: u8 = 1;
+const INT_SIGN_PLUS_OFFSET: u8 = 2;
+
 #[derive(Copy, Clone)]
 pub(crate) struct IntVal {
-    sign: Sign,
     number_fmt: NumberFmt,
-    is_alternate: bool,
-    // the size of the integer in bits
-    bits: u8,
+    // whether the integer is negative, and the alternate and sign flags,
+    // see the `INT_*` constants.
+    flags: u8,
+    // the radix used by `NumberFmt::Radix`
+    radix: u8,
+    // the amount of digits written, including the zeroes from zero-padding
+    digits: u8,
+    // the amount of digits between separators, 0 if the digits aren't grouped
+    group_len: u8,
     // the length of the integer in bytes, once written.
-    len: u8,
+    len: u16,
+
+    separator: PackedChar,
 
+    // the integer, masked into its two's complement for non-decimal formats
     value: Packed<u128>,
 }
 
@@ -299,64 +550,124 @@ impl IntVal {
     }
 
     const fn new(sign: Sign, n: u128, bits: u8, fmtarg: FmtArg) -> PanicVal<'static> {
-        use crate::int_formatting::compute_len;
+        use crate::{
+            fmt::char_formatting::char_to_utf8,
+            int_formatting::{apply_mask, count_digits, default_group_len, grouped_len},
+        };
 
-        let len = compute_len(sign, n, bits, fmtarg);
+        let n = match fmtarg.number_fmt {
+            NumberFmt::Decimal => n,
+            _ => apply_mask(sign, n, bits),
+        };
+
+        let (separator, group_len) = match fmtarg.digit_separator() {
+            Some(sep) if fmtarg.digit_group_len() == 0 => {
+                (sep, default_group_len(fmtarg.number_fmt))
+            }
+            Some(sep) => (sep, fmtarg.digit_group_len()),
+            None => (' ', 0),
+        };
 
         let this = IntVal {
-            sign,
             number_fmt: fmtarg.number_fmt,
-            is_alternate: fmtarg.is_alternate,
-            bits,
-            len,
+            flags: (sign as u8) << INT_NEGATIVE_OFFSET
+                | (fmtarg.is_alternate as u8) << INT_ALTERNATE_OFFSET
+                | (fmtarg.sign_plus() as u8) << INT_SIGN_PLUS_OFFSET,
+            radix: fmtarg.radix(),
+            digits: count_digits(n, fmtarg),
+            group_len,
+            len: 0,
+            separator: PackedChar::new(separator),
             value: Packed(n),
         };
 
-        let var = if len as usize <= string_cap::PREFMT {
-            PanicVariant::PreFmt(this.fmt::<{ string_cap::PREFMT }>())
+        let (sign_bytes, prefix) = this.sign_and_prefix();
+        let unpadded_len = (sign_bytes.len() + prefix.len()) as u16;
+        let mut this = this;
+
+        // zero-padding is done by adding leading zero digits,
+        // which are grouped like the other digits.
+        if fmtarg.zero_pad() {
+            while unpadded_len + grouped_len(this.digits, group_len, 1) < fmtarg.width() as u16 {
+                this.digits += 1;
+            }
+        }
+
+        let (_, sep_len) = char_to_utf8(separator);
+        this.len = unpadded_len + grouped_len(this.digits, group_len, sep_len as u16);
+
+        let char_len = unpadded_len + grouped_len(this.digits, group_len, 1);
+        let strfmt = StrFmt::padded(
+            fmtarg,
+            FmtKind::Display,
+            char_len as usize,
+            Alignment::Right,
+        );
+
+        let var = if this.len as usize <= string_cap::PREFMT {
+            let chunk = this.fmt_chunk(0);
+            let StartAndBytes { start, bytes } =
+                tail_byte_array::<{ string_cap::PREFMT }>(this.len(), &chunk.buffer);
+
+            // SAFETY: the entire integer is in the chunk,
+            // the separator is written as utf8, and everything else is ascii.
+            PanicVariant::PreFmt(strfmt, unsafe { PreFmtString::new(start as u16, bytes) })
         } else {
-            PanicVariant::Int(this)
+            PanicVariant::Int(strfmt, this)
         };
         PanicVal { var }
     }
 
-    pub(crate) const fn fmt<const N: usize>(self) -> TailShortString<N> {
-        use crate::int_formatting::{fmt_binary, fmt_decimal, fmt_hexadecimal};
+    // the sign and the alternate prefix written before the digits
+    const fn sign_and_prefix(&self) -> (&'static [u8], &'static [u8]) {
+        use crate::int_formatting::radix_info;
 
-        let IntVal {
-            sign,
-            number_fmt,
-            is_alternate,
-            len: _,
-            bits,
-            value: Packed(n),
-        } = self;
+        let is_negative = (self.flags >> INT_NEGATIVE_OFFSET) & 1 != 0;
+        let is_alternate = (self.flags >> INT_ALTERNATE_OFFSET) & 1 != 0;
+        let sign_plus = (self.flags >> INT_SIGN_PLUS_OFFSET) & 1 != 0;
 
-        match number_fmt {
-            NumberFmt::Decimal => fmt_decimal::<N>(sign, n),
-            NumberFmt::Binary => {
-                let masked = apply_mask(sign, n, bits);
-                fmt_binary::<N>(masked, is_alternate)
+        match self.number_fmt {
+            NumberFmt::Decimal => {
+                let sign: &[u8] = match (is_negative, sign_plus) {
+                    (true, _) => b"-",
+                    (false, true) => b"+",
+                    (false, false) => b"",
+                };
+                (sign, b"")
             }
-            NumberFmt::Hexadecimal => {
-                let masked = apply_mask(sign, n, bits);
-                fmt_hexadecimal::<N>(masked, is_alternate)
+            number_fmt => {
+                let sign: &[u8] = if sign_plus { b"+" } else { b"" };
+                let (_, _, prefix) = radix_info(number_fmt, self.radix);
+                let prefix: &[u8] = if is_alternate { prefix } else { b"" };
+                (sign, prefix)
             }
         }
     }
 
-    pub(crate) const fn len(&self) -> usize {
-        self.len as usize
-    }
-}
+    // the bytes of this formatted integer starting from `offset`,
+    // at most `string_cap::NUMBER_CHUNK` of them.
+    pub(crate) const fn fmt_chunk(self, offset: usize) -> NumberChunk {
+        use crate::int_formatting::{fmt_int, radix_info, DigitsFmt};
+
+        let (radix, letter, _) = radix_info(self.number_fmt, self.radix);
+        let (sign, prefix) = self.sign_and_prefix();
+        let separator = self.separator.unwrap_or(' ');
+        let Packed(n) = self.value;
+
+        let fmt = DigitsFmt {
+            radix,
+            letter,
+            digits: self.digits,
+            separator,
+            group_len: self.group_len,
+        };
 
-const fn apply_mask(sign: Sign, n: u128, bits: u8) -> u128 {
-    if let Sign::Negative = sign {
-        let mask: u128 = if bits == 128 { !0 } else { (1 << bits) - 1 };
+        let len = self.len();
+        fmt_int(n, fmt, sign, prefix, len, NumberChunk::new(offset, len))
+    }
 
-        (n as i128).wrapping_neg() as u128 & mask
-    } else {
-        n
+    pub(crate) const fn len(&self) -> usize {
+        self.len as usize
     }
 }
 
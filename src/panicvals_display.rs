@@ -0,0 +1,71 @@
+use crate::PanicVal;
+
+use core::fmt::{self, Debug, Display};
+
+/// Adapter for writing `PanicVal`s with [`core::fmt`] formatting, at runtime.
+///
+/// Both the `Display` and `Debug` impls of this type write the same text that
+/// the [`concat_panic`](fn@crate::concat_panic) function panics with,
+/// except that it isn't truncated.
+///
+/// The [`panicvals_display`] macro constructs this type from any type that
+/// implements [`PanicFmt`](crate::PanicFmt).
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{FmtArg, PanicValsDisplay, StdWrapper};
+///
+/// let numbers = StdWrapper(&[3u8, 5, 8]).to_panicvals(FmtArg::DEBUG);
+/// let text = StdWrapper("hello\n").to_panicvals(FmtArg::DEBUG);
+///
+/// let display = PanicValsDisplay(&[&numbers, &text]);
+///
+/// assert_eq!(display.to_string(), r#"[3, 5, 8]"hello\n""#);
+/// assert_eq!(format!("{:?}", display), r#"[3, 5, 8]"hello\n""#);
+///
+/// ```
+///
+/// [`panicvals_display`]: crate::panicvals_display
+#[derive(Copy, Clone)]
+pub struct PanicValsDisplay<'a>(pub &'a [&'a [PanicVal<'a>]]);
+
+impl Display for PanicValsDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::concat_panic_::fmt_panicvals(self.0, f)
+    }
+}
+
+impl Debug for PanicValsDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::concat_panic_::fmt_panicvals(self.0, f)
+    }
+}
+
+/// Formats the `PanicVal`s into a `String`, without a length limit.
+///
+/// This writes the same text that the [`concat_panic`](fn@crate::concat_panic) function
+/// panics with, except that it isn't truncated.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{FmtArg, PanicVal};
+///
+/// let long = "hello world ".repeat(5000);
+///
+/// let string = const_panic::to_string(&[&[
+///     PanicVal::from_str(&long, FmtArg::DISPLAY),
+///     PanicVal::from_u32(100, FmtArg::DEBUG),
+/// ]]);
+///
+/// assert_eq!(string.len(), 60003);
+/// assert!(string.ends_with("world 100"));
+///
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+pub fn to_string(args: &[&[PanicVal<'_>]]) -> alloc::string::String {
+    alloc::string::ToString::to_string(&PanicValsDisplay(args))
+}
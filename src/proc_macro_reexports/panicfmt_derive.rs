@@ -36,7 +36,29 @@ This limitation is caused by:
 
 Const parameters must not affect the value of the `PanicFmt::PV_COUNT`  of this type,
 since the const parameter [must be replaceable with a concrete value](#concrete-pv-count).
-<br>Note that arrays have a `PV_COUNT` of `1` for all lengths.
+<br>Note that arrays of primitive types have a `PV_COUNT` of `1` for all lengths.
+
+### Arrays
+
+Fields that are arrays of other types (eg: `[Point; 4]`, `[[u8; 4]; 4]`)
+are formatted with [`array_panicvals`](crate::array_panicvals),
+which requires the array type to be written out in the field type
+(type aliases of arrays are formatted with their `PanicFmt` impl),
+and its length to not depend on const parameters.
+
+Whether the element type is a primitive type is determined from how it's written,
+so arrays of type aliases of primitive types (eg: `[Byte; 4]`)
+are formatted with `array_panicvals`,
+which can be overriden with the [`array_impl`](#pfmt-array-attrs) attribute.
+Arrays of type parameters can't be formatted.
+
+### Tuples
+
+Fields that are tuples (eg: `(u8, &'a str)`, `(u8, (Point, u8))`)
+are formatted with [`tuple_panicvals`](crate::tuple_panicvals),
+which requires the tuple type to be written out in the field type
+(type aliases of tuples can't be formatted),
+and its elements to not be type parameters.
 
 <a id = "concrete-pv-count"></a>
 ### Concrete `Self` type for `PanicFmt::PV_COUNT`
@@ -84,6 +106,17 @@ Calls the provided function when the deriving type is attempted to be Display-fo
 Tells the derive the minimum amount of [`PanicVal`]s that this type needs,
 generally only useful in combination with the [`display_fmt`](#display_fmt-attr) attribute.
 
+<a id = "flags-attr"></a>
+### `#[pfmt(flags(FLAG_A = expression, FLAG_B = expression))]`: 
+
+Formats the type as a set of bit flags, using [`FlagsFmt`](crate::fmt::FlagsFmt),
+eg: `READ | EXEC | 0x40` when Display formatted,
+and `Perms(READ | EXEC | 0x40)` when Debug formatted.
+
+This can only be used on structs with a single integer field.
+
+[example below](#flags-example)
+
 <a id = "pfmt-ignored-attr"></a>
 ### `#[pfmt(ignored(T, C))]`
 
@@ -124,6 +157,21 @@ this also requires a [`#[pfmt(ignored(T))]`](#pfmt-ignored-attr) attribute
 
 ([more conplete example](#type-parameter-example))
 
+### Field attributes
+
+Attributes used above fields.
+
+<a id = "pfmt-array-attrs"></a>
+### `#[pfmt(array_impl)]` and `#[pfmt(array_panicvals)]`
+
+Overrides how a field of array type is [formatted](#arrays),
+`array_impl` formats it with the `PanicFmt` impl of the array type,
+while `array_panicvals` formats it with [`array_panicvals`](crate::array_panicvals).
+
+examples:
+- `#[pfmt(array_impl)] bytes: [Byte; 4]`
+- `#[pfmt(array_panicvals)] bytes: [u8; 4]`: formats each byte individually
+
 # Examples
 
 ### Basic struct
@@ -300,6 +348,35 @@ impl Foo {
 
 ```
 
+<a id = "flags-example"></a>
+### Flags formatting
+
+This example demonstrates the [`flags` attribute](#flags-attr)
+
+```rust
+use const_panic::PanicFmt;
+
+const RW: Perms = Perms(Perms::READ.0 | Perms::WRITE.0);
+
+assert_eq!(const_panic::concat_!(display: RW), "READ | WRITE");
+assert_eq!(const_panic::concat_!(debug: RW), "Perms(READ | WRITE)");
+
+// bits that aren't part of any flag are written in hexadecimal
+assert_eq!(const_panic::concat_!(Perms(0x45)), "Perms(READ | EXEC | 0x40)");
+
+assert_eq!(const_panic::concat_!(Perms(0)), "Perms(0x0)");
+
+#[derive(PanicFmt)]
+#[pfmt(flags(READ = Perms::READ.0, WRITE = Perms::WRITE.0, EXEC = Perms::EXEC.0))]
+struct Perms(u32);
+
+impl Perms {
+    const READ: Self = Self(1);
+    const WRITE: Self = Self(2);
+    const EXEC: Self = Self(4);
+}
+```
+
 <a id = "crate-example"></a>
 ### Crate renaming
 
@@ -1,12 +1,20 @@
 use crate::{
-    fmt::{FmtArg, PackedFmtArg, PanicFmt},
-    panic_val::{PanicVal, PanicVariant},
+    fmt::{
+        hex_dump::{next_hex_dump_row, HEX_DUMP_ROW_LEN},
+        FmtArg, PackedFmtArg, PanicFmt,
+    },
+    panic_val::{BytesFmt, PanicVal, PanicVariant},
     utils::Packed,
     StdWrapper,
 };
 
+use core::ops::{Range, RangeInclusive};
+
 macro_rules! impl_panicfmt_array {
-    ($(($variant:ident, $panicval_ctor:ident, $ty:ty)),* $(,)*) => {
+    (
+        $(($variant:ident, $panicval_ctor:ident, $ty:ty)),* $(,)*;
+        ranges: $(($range_variant:ident, $range_incl_variant:ident, $range_ty:ty)),* $(,)*
+    ) => {
 
         #[derive(Copy, Clone)]
         #[repr(packed)]
@@ -21,6 +29,13 @@ macro_rules! impl_panicfmt_array {
             $(
                 $variant(Packed<&'s [$ty]>),
             )*
+            $(
+                $range_variant(Packed<&'s [Range<$range_ty>]>),
+                $range_incl_variant(Packed<&'s [RangeInclusive<$range_ty>]>),
+            )*
+            // the bytes that remain to be printed in a hexdump,
+            // and the offset of the first one.
+            HexDump(Packed<&'s [u8]>, Packed<u32>),
         }
 
 
@@ -31,19 +46,45 @@ macro_rules! impl_panicfmt_array {
                     $(
                         SliceV::$variant(Packed(arr)) => arr.len(),
                     )*
+                    $(
+                        SliceV::$range_variant(Packed(arr)) => arr.len(),
+                        SliceV::$range_incl_variant(Packed(arr)) => arr.len(),
+                    )*
+                    SliceV::HexDump(Packed(bytes), _) => bytes.len(),
                 }
             }
         }
 
         impl<'s> SliceV<'s> {
-            const fn get(self, index: usize, fmtarg: FmtArg) -> PanicVal<'s> {
+            // how many times `SliceIter::next` is called for each element
+            const fn elem_steps(self) -> usize {
+                match self {
+                    $(
+                        SliceV::$range_variant(_) | SliceV::$range_incl_variant(_) => 2,
+                    )*
+                    _ => 1,
+                }
+            }
+
+            // the `step`th part of the `index`th element,
+            // the second `PanicVal` of the last part is replaced with a separator.
+            const fn get(self, index: usize, step: usize, fmtarg: FmtArg) -> [PanicVal<'s>; 2] {
                 match self {
                     $(
                         SliceV::$variant(Packed(arr)) => {
                             let elem: &'s <$ty as PanicFmt>::This = &arr[index];
-                            StdWrapper(elem).to_panicval(fmtarg)
+                            [StdWrapper(elem).to_panicval(fmtarg), PanicVal::EMPTY]
                         },
                     )*
+                    $(
+                        SliceV::$range_variant(Packed(arr)) => {
+                            range_step(StdWrapper(&arr[index]).to_panicvals(fmtarg), step)
+                        }
+                        SliceV::$range_incl_variant(Packed(arr)) => {
+                            range_step(StdWrapper(&arr[index]).to_panicvals(fmtarg), step)
+                        }
+                    )*
+                    SliceV::HexDump(..) => unreachable!(),
                 }
             }
         }
@@ -52,17 +93,13 @@ macro_rules! impl_panicfmt_array {
         impl<'s> PanicVal<'s> {
             $(
                 /// Constructs a `PanicVal` from a slice.
-                pub const fn $panicval_ctor(this: &'s [$ty], mut fmtarg: FmtArg) -> PanicVal<'s> {
-                    fmtarg = fmtarg.indent();
-                    if this.is_empty() {
-                        fmtarg = fmtarg.set_alternate(false);
+                pub const fn $panicval_ctor(this: &'s [$ty], fmtarg: FmtArg) -> PanicVal<'s> {
+                    let vari = SliceV::$variant(Packed(this));
+                    if let (true, SliceV::U8(Packed(bytes))) = (fmtarg.byte_str(), vari) {
+                        return PanicVal::from_byte_str(bytes);
                     }
-                    PanicVal::__new(
-                        PanicVariant::Slice(Slice{
-                            fmtarg: fmtarg.pack(),
-                            vari: SliceV::$variant(Packed(this)),
-                        })
-                    )
+
+                    PanicVal::from_slice_v(vari, this.is_empty(), fmtarg)
                 }
             )*
         }
@@ -104,6 +141,47 @@ macro_rules! impl_panicfmt_array {
             }
         )*
 
+        $(
+            impl_panicfmt_range_array! {$range_variant, Range<$range_ty>}
+            impl_panicfmt_range_array! {$range_incl_variant, RangeInclusive<$range_ty>}
+        )*
+    };
+}
+
+macro_rules! impl_panicfmt_range_array {
+    ($variant:ident, $ty:ty) => {
+        impl PanicFmt for [$ty] {
+            type This = Self;
+            type Kind = crate::fmt::IsStdType;
+            const PV_COUNT: usize = 1;
+        }
+        impl<const LEN: usize> PanicFmt for [$ty; LEN] {
+            type This = Self;
+            type Kind = crate::fmt::IsStdType;
+            const PV_COUNT: usize = 1;
+        }
+
+        impl<'s> StdWrapper<&'s [$ty]> {
+            /// Converts the slice to a single-element `PanicVal` array.
+            pub const fn to_panicvals(self: Self, f: FmtArg) -> [PanicVal<'s>; 1] {
+                [self.to_panicval(f)]
+            }
+            /// Converts the slice to a `PanicVal`.
+            pub const fn to_panicval(self: Self, f: FmtArg) -> PanicVal<'s> {
+                PanicVal::from_slice_v(SliceV::$variant(Packed(self.0)), self.0.is_empty(), f)
+            }
+        }
+
+        impl<'s, const LEN: usize> StdWrapper<&'s [$ty; LEN]> {
+            /// Converts the array to a single-element `PanicVal` array.
+            pub const fn to_panicvals(self: Self, f: FmtArg) -> [PanicVal<'s>; 1] {
+                [self.to_panicval(f)]
+            }
+            /// Converts the array to a `PanicVal`.
+            pub const fn to_panicval(self: Self, f: FmtArg) -> PanicVal<'s> {
+                PanicVal::from_slice_v(SliceV::$variant(Packed(self.0)), LEN == 0, f)
+            }
+        }
     };
 }
 
@@ -120,9 +198,34 @@ impl_panicfmt_array! {
     (I64, from_slice_i64, i64),
     (I128, from_slice_i128, i128),
     (Isize, from_slice_isize, isize),
+    (F32, from_slice_f32, f32),
+    (F64, from_slice_f64, f64),
     (Bool, from_slice_bool, bool),
     (Char, from_slice_char, char),
-    (Str, from_slice_str, &'s str),
+    (Str, from_slice_str, &'s str);
+    ranges:
+    (RangeU8, RangeInclusiveU8, u8),
+    (RangeU16, RangeInclusiveU16, u16),
+    (RangeU32, RangeInclusiveU32, u32),
+    (RangeU64, RangeInclusiveU64, u64),
+    (RangeU128, RangeInclusiveU128, u128),
+    (RangeUsize, RangeInclusiveUsize, usize),
+    (RangeI8, RangeInclusiveI8, i8),
+    (RangeI16, RangeInclusiveI16, i16),
+    (RangeI32, RangeInclusiveI32, i32),
+    (RangeI64, RangeInclusiveI64, i64),
+    (RangeI128, RangeInclusiveI128, i128),
+    (RangeIsize, RangeInclusiveIsize, isize),
+    (RangeChar, RangeInclusiveChar, char),
+}
+
+// the `step`th half of a range formatted as `[start, "..", end]`
+const fn range_step(pvs: [PanicVal<'static>; 3], step: usize) -> [PanicVal<'static>; 2] {
+    if step == 0 {
+        [pvs[0], pvs[1]]
+    } else {
+        [pvs[2], PanicVal::EMPTY]
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -142,19 +245,59 @@ impl IterState {
     const End: Self = Self(u32::MAX);
 }
 
+impl<'s> PanicVal<'s> {
+    const fn from_slice_v(vari: SliceV<'s>, is_empty: bool, mut fmtarg: FmtArg) -> Self {
+        fmtarg = fmtarg.indent();
+        if is_empty {
+            fmtarg = fmtarg.set_alternate(false);
+        }
+        PanicVal::__new(PanicVariant::Slice(Slice {
+            fmtarg: fmtarg.pack(),
+            vari,
+        }))
+    }
+
+    pub(crate) const fn from_hex_dump(bytes: &'s [u8], offset: u32) -> Self {
+        PanicVal::__new(PanicVariant::Slice(Slice {
+            fmtarg: FmtArg::DISPLAY.pack(),
+            vari: SliceV::HexDump(Packed(bytes), Packed(offset)),
+        }))
+    }
+}
+
 impl<'s> Slice<'s> {
     pub(crate) const fn iter<'b>(&'b self) -> SliceIter<'s> {
+        if let SliceV::HexDump(Packed(bytes), _) = self.vari {
+            // each hexdump row is output in two halves
+            let rows = (bytes.len() + HEX_DUMP_ROW_LEN - 1) / HEX_DUMP_ROW_LEN;
+
+            return SliceIter {
+                slice: self.vari,
+                fmtarg: self.fmtarg.unpack(),
+                state: if rows == 0 {
+                    IterState::End
+                } else {
+                    IterState(0)
+                },
+                arr_len: (rows * 2) as u32,
+            };
+        }
+
         SliceIter {
             slice: self.vari,
             fmtarg: self.fmtarg.unpack(),
             state: IterState::Start,
-            arr_len: self.arr_len() as u32,
+            arr_len: (self.arr_len() * self.vari.elem_steps()) as u32,
         }
     }
 }
 
 impl<'s> SliceIter<'s> {
     pub(crate) const fn next(mut self) -> ([PanicVal<'s>; 2], Option<Self>) {
+        if let SliceV::HexDump(..) = self.slice {
+            return self.next_hex_dump();
+        }
+
         let fmtarg = self.fmtarg;
 
         let ret = match self.state {
@@ -172,19 +315,69 @@ impl<'s> SliceIter<'s> {
                 return ([close_brace, PanicVal::EMPTY], None);
             }
             IterState(x) => {
-                let comma = if x + 1 == self.arr_len {
+                let steps = self.slice.elem_steps() as u32;
+                let mut ret = self
+                    .slice
+                    .get((x / steps) as usize, (x % steps) as usize, fmtarg);
+
+                if x + 1 == self.arr_len {
                     self.state = IterState::End;
-                    crate::fmt::COMMA_TERM
+                    ret[1] = crate::fmt::COMMA_TERM.to_panicval(fmtarg);
                 } else {
                     self.state = IterState(x + 1);
-                    crate::fmt::COMMA_SEP
+                    if (x + 1) % steps == 0 {
+                        ret[1] = crate::fmt::COMMA_SEP.to_panicval(fmtarg);
+                    }
                 }
-                .to_panicval(fmtarg);
 
-                [self.slice.get(x as usize, fmtarg), comma]
+                ret
             }
         };
 
         (ret, Some(self))
     }
 }
+
+impl<'s> SliceIter<'s> {
+    // outputs the offset and hexadecimal bytes of a row on even states,
+    // and the ascii bytes of that row followed by a newline on odd states.
+    const fn next_hex_dump(mut self) -> ([PanicVal<'s>; 2], Option<Self>) {
+        let (bytes, offset) = match self.slice {
+            SliceV::HexDump(Packed(bytes), Packed(offset)) => (bytes, offset),
+            _ => unreachable!(),
+        };
+
+        let x = match self.state {
+            IterState::End => return ([PanicVal::EMPTY, PanicVal::EMPTY], None),
+            IterState(x) => x,
+        };
+
+        let ret = if x % 2 == 0 {
+            self.state = IterState(x + 1);
+
+            let offset_fmt = FmtArg::LOWER_HEX.set_width(8).set_zero_pad(true);
+            [
+                PanicVal::from_u32(offset, offset_fmt),
+                PanicVal::from_bytes(bytes, BytesFmt::HexRow),
+            ]
+        } else if x + 1 == self.arr_len {
+            self.state = IterState::End;
+            [
+                PanicVal::from_bytes(bytes, BytesFmt::AsciiRow),
+                PanicVal::EMPTY,
+            ]
+        } else {
+            self.state = IterState(x + 1);
+            self.slice = SliceV::HexDump(
+                Packed(next_hex_dump_row(bytes)),
+                Packed(offset + HEX_DUMP_ROW_LEN as u32),
+            );
+            [
+                PanicVal::from_bytes(bytes, BytesFmt::AsciiRow),
+                PanicVal::write_str("\n"),
+            ]
+        };
+
+        (ret, Some(self))
+    }
+}
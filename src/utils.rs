@@ -1,6 +1,6 @@
 //! Utility functions
 
-use crate::debug_str_fmt::ForEscaping;
+use crate::{debug_str_fmt::ForEscaping, panic_val::BytesFmt};
 
 #[cfg(feature = "rust_1_64")]
 #[cfg(test)]
@@ -33,7 +33,7 @@ pub const fn max_usize(l: usize, r: usize) -> usize {
 
 #[derive(Copy, Clone)]
 pub(crate) struct TailShortString<const LEN: usize> {
-    start: u8,
+    start: u16,
     buffer: [u8; LEN],
 }
 
@@ -47,11 +47,14 @@ pub(crate) mod string_cap {
     // the TailShortString that's stored in PanicVal
     pub(crate) const PREFMT: usize = 21;
 
-    // length of string to alternate binary format a 64 bit integer
-    pub(crate) const MEDIUM: usize = 66;
+    // the amount of bytes of an integer or float that are written to a panic message at a time,
+    // so that the buffer for the longest number (hundreds of bytes long)
+    // isn't in the stack frame of the function that formats the message.
+    pub(crate) const NUMBER_CHUNK: usize = 64;
 
-    // length of string to alternate binary format a 128 bit integer
-    pub(crate) const LARGE: usize = 130;
+    // length of string to Display format the most negative f64
+    // with the largest precision (which is longer than any zero-padded float).
+    pub(crate) const FLOAT: usize = 566;
 }
 
 impl<const LEN: usize> TailShortString<LEN> {
@@ -60,7 +63,7 @@ impl<const LEN: usize> TailShortString<LEN> {
     ///
     /// `buffer` must be valid utf8 starting from the `start` index.
     #[inline(always)]
-    pub(crate) const unsafe fn new(start: u8, buffer: [u8; LEN]) -> Self {
+    pub(crate) const unsafe fn new(start: u16, buffer: [u8; LEN]) -> Self {
         Self { start, buffer }
     }
 
@@ -79,6 +82,33 @@ impl<const LEN: usize> TailShortString<LEN> {
 
 ////////////////////////////////////////////////////////
 
+// The bytes of a formatted integer or float in the
+// `offset .. offset + len` range, stored at the start of `buffer`.
+#[derive(Copy, Clone)]
+pub(crate) struct NumberChunk {
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) buffer: [u8; string_cap::NUMBER_CHUNK],
+}
+
+impl NumberChunk {
+    // the chunk of a `number_len` byte long number that starts at `offset`
+    pub(crate) const fn new(offset: usize, number_len: usize) -> Self {
+        Self {
+            offset,
+            len: min_usize(number_len - offset, string_cap::NUMBER_CHUNK),
+            buffer: [0u8; string_cap::NUMBER_CHUNK],
+        }
+    }
+
+    // whether the byte at the `pos` index of the number is in this chunk
+    pub(crate) const fn contains(&self, pos: usize) -> bool {
+        self.offset <= pos && pos - self.offset < self.len
+    }
+}
+
+////////////////////////////////////////////////////////
+
 #[repr(packed)]
 #[derive(Copy)]
 pub(crate) struct Packed<T>(pub(crate) T);
@@ -91,6 +121,47 @@ impl<T: Copy> Clone for Packed<T> {
 
 ////////////////////////////////////////////////////////
 
+// An optional `char` stored in 3 bytes (`char`s are at most 21 bits large),
+// to make the types that store `char`s smaller.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) struct PackedChar([u8; 3]);
+
+impl PackedChar {
+    // not a valid `char`, since it's larger than `char::MAX`
+    pub(crate) const NONE: Self = Self([0xFF; 3]);
+
+    pub(crate) const fn new(c: char) -> Self {
+        let c = c as u32;
+        Self([c as u8, (c >> 8) as u8, (c >> 16) as u8])
+    }
+
+    pub(crate) const fn get(self) -> Option<char> {
+        if let Self::NONE = self {
+            return None;
+        }
+
+        let [b0, b1, b2] = self.0;
+        let code = b0 as u32 | (b1 as u32) << 8 | (b2 as u32) << 16;
+
+        // `char::from_u32` isn't const until Rust 1.67
+        union Transmute {
+            code: u32,
+            c: char,
+        }
+        // SAFETY: all `PackedChar`s other than `NONE` are constructed from a `char`
+        Some(unsafe { Transmute { code }.c })
+    }
+
+    pub(crate) const fn unwrap_or(self, default: char) -> char {
+        match self.get() {
+            Some(x) => x,
+            None => default,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////
+
 #[derive(Copy, Clone)]
 pub(crate) struct RangedBytes<B> {
     pub(crate) start: usize,
@@ -203,6 +274,163 @@ pub(crate) const fn truncated_debug_str_len(
     }
 }
 
+pub(crate) const fn truncated_bytes_len(
+    ranged: RangedBytes<&[u8]>,
+    truncate_to: usize,
+    bytes_fmt: BytesFmt,
+) -> WasTruncated {
+    // the length of the quotes and the `b` prefix,
+    // or the `: ` before and the padding after the bytes of a hexdump row.
+    let mut fmtlen = match bytes_fmt {
+        BytesFmt::Lossy => 0,
+        BytesFmt::Escaped => 2,
+        BytesFmt::ByteStr => 3,
+        #[cfg(feature = "non_basic")]
+        BytesFmt::HexRow => 2 + crate::fmt::hex_dump::hex_row_padding(ranged.len()),
+        #[cfg(feature = "non_basic")]
+        BytesFmt::AsciiRow => 0,
+    };
+
+    let mut i = ranged.start;
+    while i < ranged.end {
+        let (seq_len, is_escaped) = bytes_sequence(ranged.bytes, i, ranged.end, bytes_fmt);
+
+        let written_len = match (is_escaped, bytes_fmt) {
+            (false, _) => seq_len,
+            // the length of `U+FFFD`
+            (true, BytesFmt::Lossy) => 3,
+            #[cfg(feature = "non_basic")]
+            (true, BytesFmt::HexRow) => crate::fmt::hex_dump::hex_row_byte_len(i - ranged.start),
+            #[cfg(feature = "non_basic")]
+            (true, BytesFmt::AsciiRow) => 1,
+            (true, BytesFmt::Escaped | BytesFmt::ByteStr) => {
+                let mut len = 0;
+                let mut j = i;
+                while j < i + seq_len {
+                    len += ForEscaping::byte_str_len(ranged.bytes[j]);
+                    j += 1;
+                }
+                len
+            }
+        };
+
+        if fmtlen + written_len > truncate_to {
+            return WasTruncated::Yes(i - ranged.start);
+        }
+        fmtlen += written_len;
+        i += seq_len;
+    }
+
+    if fmtlen <= truncate_to {
+        WasTruncated::No
+    } else {
+        WasTruncated::Yes(0)
+    }
+}
+
+// Returns the length of the bytes starting at `i` that are written together,
+// and whether they're escaped (or replaced with `U+FFFD` for `BytesFmt::Lossy`).
+//
+// `BytesFmt::Escaped` only writes valid non-ascii chars verbatim,
+// while `BytesFmt::ByteStr` escapes all non-ascii bytes.
+pub(crate) const fn bytes_sequence(
+    bytes: &[u8],
+    i: usize,
+    end: usize,
+    bytes_fmt: BytesFmt,
+) -> (usize, bool) {
+    match bytes_fmt {
+        BytesFmt::ByteStr => (1, true),
+        #[cfg(feature = "non_basic")]
+        BytesFmt::HexRow | BytesFmt::AsciiRow => (1, true),
+        BytesFmt::Escaped => {
+            let (len, is_valid) = utf8_sequence(bytes, i, end);
+            (len, !is_valid || len == 1)
+        }
+        BytesFmt::Lossy => {
+            let (len, is_valid) = utf8_sequence(bytes, i, end);
+            (len, !is_valid)
+        }
+    }
+}
+
+// Returns the length of the utf8-encoded char that starts at `i`, and whether it's valid.
+//
+// For invalid utf8, this returns the length of the longest prefix of a valid char
+// (at least 1 byte long), each of which `String::from_utf8_lossy` replaces with `U+FFFD`.
+pub(crate) const fn utf8_sequence(bytes: &[u8], i: usize, end: usize) -> (usize, bool) {
+    let first = bytes[i];
+
+    // the expected length, and the range of the second byte
+    let (len, min, max) = match first {
+        0x00..=0x7F => return (1, true),
+        0xC2..=0xDF => (2, 0x80, 0xBF),
+        0xE0 => (3, 0xA0, 0xBF),
+        0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+        0xED => (3, 0x80, 0x9F),
+        0xF0 => (4, 0x90, 0xBF),
+        0xF1..=0xF3 => (4, 0x80, 0xBF),
+        0xF4 => (4, 0x80, 0x8F),
+        _ => return (1, false),
+    };
+
+    let mut j = 1;
+    while j < len {
+        if i + j == end {
+            return (j, false);
+        }
+
+        let b = bytes[i + j];
+        let (lo, hi) = if j == 1 { (min, max) } else { (0x80, 0xBF) };
+        if b < lo || b > hi {
+            return (j, false);
+        }
+        j += 1;
+    }
+
+    (len, true)
+}
+
+// The length in bytes and the amount of chars of the first `max_chars` chars of `bytes`
+// when written by `BytesFmt::Lossy` (each invalid sequence is written as one `U+FFFD` char).
+#[cfg(feature = "rust_1_82")]
+pub(crate) const fn lossy_chars_len(bytes: &[u8], max_chars: usize) -> (usize, usize) {
+    let mut i = 0;
+    let mut chars = 0;
+    while i < bytes.len() && chars < max_chars {
+        i += utf8_sequence(bytes, i, bytes.len()).0;
+        chars += 1;
+    }
+    (i, chars)
+}
+
+// the amount of chars in a utf8-encoded string
+pub(crate) const fn char_count(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        count += is_char_boundary(bytes[i]) as usize;
+        i += 1;
+    }
+    count
+}
+
+// the length in bytes of the first `chars` chars of a utf8-encoded string
+pub(crate) const fn chars_byte_len(bytes: &[u8], chars: usize) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if is_char_boundary(bytes[i]) {
+            if count == chars {
+                break;
+            }
+            count += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
 const fn next_char_boundary(ranged: RangedBytes<&[u8]>, mut i: usize) -> usize {
     while i < ranged.end && !is_char_boundary(ranged.bytes[i]) {
         i += 1;
@@ -293,3 +521,32 @@ pub const fn bytes_up_to(buffer: &[u8], upto: usize) -> &[u8] {
         unsafe { core::slice::from_raw_parts(buffer.as_ptr(), upto) }
     }
 }
+
+// removes the first `from` bytes of `buffer`, requires `from <= buffer.len()`
+#[cfg(feature = "non_basic")]
+#[clippy::msrv = "1.64"]
+pub(crate) const fn bytes_from(buffer: &[u8], from: usize) -> &[u8] {
+    #[cfg(not(feature = "rust_1_64"))]
+    {
+        let mut to_remove = from;
+        let mut out: &[u8] = buffer;
+
+        while to_remove != 0 {
+            if let [_, rem @ ..] = out {
+                out = rem;
+            }
+            to_remove -= 1;
+        }
+
+        out
+    }
+
+    #[cfg(feature = "rust_1_64")]
+    {
+        assert!(from <= buffer.len());
+
+        // SAFETY: the above assertion ensures that `from` doesn't
+        // create a partially-dangling slice
+        unsafe { core::slice::from_raw_parts(buffer.as_ptr().add(from), buffer.len() - from) }
+    }
+}
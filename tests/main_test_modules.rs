@@ -5,6 +5,14 @@ macro_rules! trunc_fmt {
     ($len:expr; $($args:tt)*) => ( const_panic::concat_fmt!($len, $len; $($args)*).unwrap() )
 }
 
+// the message of the panic that `f` is expected to raise
+fn panic_message<F: FnOnce() + std::panic::UnwindSafe>(f: F) -> String {
+    *std::panic::catch_unwind(f)
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap()
+}
+
 macro_rules! test_val {
     (
         $value:expr
@@ -54,6 +62,9 @@ macro_rules! test_val {
 }
 
 mod main_tests {
+    #[cfg(feature = "alloc")]
+    mod alloc_tests;
+
     #[cfg(feature = "non_basic")]
     mod array_tests;
 
@@ -67,15 +78,23 @@ mod main_tests {
     #[cfg(feature = "non_basic")]
     mod concat_macro_tests;
 
+    mod concat_panic_tests;
+
     #[cfg(feature = "rust_1_64")]
     mod rust_1_64_types_tests;
 
+    #[cfg(feature = "rust_1_77")]
+    mod rust_1_77_types_tests;
+
     #[cfg(feature = "rust_1_82")]
     mod rust_1_82_types_tests;
 
     #[cfg(feature = "rust_1_88")]
     mod rust_1_88_types_tests;
 
+    #[cfg(feature = "non_basic")]
+    mod error_types_tests;
+
     #[cfg(feature = "non_basic")]
     mod impl_panicfmt_tests;
 
@@ -88,12 +107,24 @@ mod main_tests {
     #[cfg(feature = "derive")]
     mod derive_tests;
 
+    mod float_tests;
+
+    #[cfg(feature = "fmt_str")]
+    mod fmt_str_tests;
+
     mod integer_tests;
 
     mod misc_macros_tests;
 
+    #[cfg(feature = "non_basic")]
+    mod nested_tests;
+
+    mod padding_tests;
+
     mod panicval_macros_tests;
 
+    mod panicvals_display_tests;
+
     #[cfg(feature = "non_basic")]
     mod pvcount_tests;
 
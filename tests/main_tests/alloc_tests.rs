@@ -0,0 +1,100 @@
+use const_panic::{FmtArg, PanicVal};
+
+use std::rc::Rc;
+
+#[cfg(feature = "rust_1_64")]
+use std::sync::Arc;
+
+#[test]
+fn to_string_test() {
+    let long = "hello\n".repeat(10_000);
+
+    assert_eq!(
+        const_panic::to_string(&[
+            &[
+                PanicVal::write_str("foo "),
+                PanicVal::from_str(&long, FmtArg::DEBUG)
+            ],
+            &[],
+            &[PanicVal::from_u8(100, FmtArg::DISPLAY.set_hex())],
+        ]),
+        format!("foo {:?}64", long),
+    );
+
+    assert_eq!(const_panic::to_string(&[]), "");
+}
+
+#[test]
+fn pointer_test() {
+    test_val! {Box::new(3u8)}
+    test_val! {Box::new(-3.5f64)}
+    test_val! {Box::new('a')}
+    test_val! {Box::new("foo\n")}
+    test_val! {Box::<str>::from("bar\t")}
+
+    test_val! {Rc::new(true)}
+    test_val! {Rc::new(u128::MAX)}
+    test_val! {Rc::<str>::from("bar\t")}
+}
+
+#[test]
+#[cfg(feature = "rust_1_64")]
+fn arc_test() {
+    test_val! {Arc::new(i64::MIN)}
+    test_val! {Arc::new("foo\n")}
+    test_val! {Arc::<str>::from("bar\t")}
+}
+
+#[test]
+#[cfg(feature = "rust_1_64")]
+fn pointer_runtime_panic_test() {
+    let rc = Rc::<str>::from("foo");
+    let arc = Arc::new(100u16);
+
+    let msg = crate::panic_message(|| const_panic::concat_panic!("rc: ", rc, " arc: ", arc));
+
+    assert_eq!(msg, r#"rc: "foo" arc: 100"#);
+}
+
+#[test]
+#[cfg(feature = "rust_1_88")]
+fn string_and_cow_test() {
+    use std::borrow::Cow;
+
+    test_val! {String::from("hello\nworld")}
+    test_val! {String::new()}
+
+    test_val! {Cow::<str>::Borrowed("hello\nworld")}
+    test_val! {Cow::<str>::Owned(String::from("foo\tbar"))}
+}
+
+#[test]
+#[cfg(all(feature = "rust_1_88", feature = "non_basic"))]
+fn vec_test() {
+    test_val! {vec![3u8, 5, 8], no_display}
+    test_val! {Vec::<i32>::new(), no_display}
+    test_val! {vec![-1.5f32, 2.0], no_display}
+    test_val! {vec!['a', '\n'], no_display}
+    test_val! {vec!["foo", "bar\n"], no_display}
+    test_val! {vec![false, true], no_display}
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_box_field_test() {
+    #[derive(Debug, const_panic::PanicFmt)]
+    struct Boxed {
+        x: Box<u32>,
+        y: Box<str>,
+    }
+
+    let boxed = Boxed {
+        x: Box::new(3),
+        y: Box::from("foo"),
+    };
+
+    assert_eq!(
+        const_panic::to_string(&[&boxed.to_panicvals(FmtArg::ALT_DEBUG)]),
+        format!("{:#?}", boxed),
+    );
+}
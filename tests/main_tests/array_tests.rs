@@ -40,6 +40,157 @@ fn overflow_truncating_integer_array_test() {
     overf_fmt!(20; upto34).unwrap_err();
 }
 
+#[test]
+fn byte_str_test() {
+    use const_panic::FmtArg;
+
+    fn expected(bytes: &[u8]) -> String {
+        format!("b\"{}\"", bytes.escape_ascii())
+    }
+
+    // the outputs of truncating the byte string between escapes
+    fn truncations(bytes: &[u8]) -> Vec<String> {
+        let prefixes = (0..=bytes.len()).map(|i| format!("b\"{}", bytes[..i].escape_ascii()));
+        ["".to_string(), "b".to_string()]
+            .into_iter()
+            .chain(prefixes)
+            .collect()
+    }
+
+    for bytes in [
+        &b""[..],
+        b"hello",
+        b"hi\x00\xff",
+        b"\t\n\r\\\'\"",
+        b"\x7fELF\x01",
+        "人ö个".as_bytes(),
+    ] {
+        let expected = expected(bytes);
+
+        assert_eq!(trunc_fmt!(1024; FmtArg::BYTE_STR; bytes), *expected);
+        assert_eq!(trunc_fmt!(1024; byte_str: bytes), *expected);
+
+        // the byte string is never truncated in the middle of an escape
+        for len in 0..expected.len() {
+            overf_fmt!(len; byte_str: bytes).unwrap_err();
+            let truncated = trunc_fmt!(len; byte_str: bytes);
+            assert!(
+                truncations(bytes).iter().any(|x| *x == truncated.as_str()),
+                "{:?}",
+                truncated
+            );
+        }
+        assert_eq!(
+            overf_fmt!(expected.len(); byte_str: bytes).unwrap(),
+            *expected
+        );
+    }
+
+    let arr: [u8; 3] = [b'a', 0xC0, b'"'];
+    assert_eq!(trunc_fmt!(1024; FmtArg::BYTE_STR; arr), r#"b"a\xc0\"""#);
+
+    // other formatting overrides format `u8` slices as integer lists
+    let bytes: &[u8] = b"ab";
+    assert_eq!(
+        trunc_fmt!(1024; byte_str: bytes, {?}: bytes),
+        r#"b"ab"[97, 98]"#
+    );
+}
+
+#[test]
+fn hex_dump_test() {
+    use const_panic::fmt::HexDump;
+
+    // the same format as `xxd`, without the trailing newline
+    fn expected(bytes: &[u8], offset: usize) -> String {
+        bytes
+            .chunks(16)
+            .enumerate()
+            .map(|(i, row)| {
+                let hex = row
+                    .chunks(2)
+                    .map(|pair| {
+                        pair.iter()
+                            .map(|b| format!("{:02x}", b))
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let ascii = row
+                    .iter()
+                    .map(|&b| match b {
+                        0x20..=0x7E => b as char,
+                        _ => '.',
+                    })
+                    .collect::<String>();
+                format!("{:08x}: {:<39}  {}", offset + i * 16, hex, ascii)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    let blob: Vec<u8> = (0..=255u8).rev().chain(*b"\x7fELF hello world").collect();
+
+    for len in [0, 1, 2, 3, 15, 16, 17, 31, 32, 33, 50] {
+        let bytes = &blob[..len];
+        let expected = expected(bytes, 0);
+        let hex_dump = HexDump::new(bytes);
+
+        assert_eq!(trunc_fmt!(1024; hex_dump), *expected);
+        assert_eq!(trunc_fmt!(1024; display: hex_dump), *expected);
+        assert_eq!(trunc_fmt!(1024; {#x}: hex_dump), *expected);
+
+        // truncation can happen in the middle of a row
+        for trunc_len in 0..expected.len() {
+            overf_fmt!(trunc_len; hex_dump).unwrap_err();
+            let truncated = trunc_fmt!(trunc_len; hex_dump);
+            assert!(
+                expected.starts_with(truncated.as_str()),
+                "{:?} {:?}",
+                truncated,
+                expected,
+            );
+        }
+        assert_eq!(overf_fmt!(expected.len(); hex_dump).unwrap(), *expected);
+    }
+
+    for (start, end) in [
+        (0, 0),
+        (0, 5),
+        (3, 40),
+        (16, 32),
+        (250, 300),
+        (270, 300),
+        (300, 400),
+    ] {
+        let clamped_end = end.min(blob.len());
+        let clamped_start = start.min(clamped_end);
+        let expected = expected(&blob[clamped_start..clamped_end], clamped_start);
+
+        let hex_dump = HexDump::new(&blob).window(start..end);
+        assert_eq!(hex_dump.bytes(), &blob[clamped_start..clamped_end]);
+        assert_eq!(hex_dump.offset() as usize, clamped_start);
+        assert_eq!(trunc_fmt!(1024; hex_dump), *expected);
+
+        // windows of windows keep the offset relative to the whole slice
+        let nested = HexDump::new(&blob)
+            .window(start / 2..end)
+            .window(start - start / 2..end);
+        assert_eq!(nested.bytes(), hex_dump.bytes());
+        assert_eq!(nested.offset(), hex_dump.offset());
+    }
+
+    assert_eq!(
+        trunc_fmt!(1024; "<", HexDump::new(b"\x00\x01\x02\x03abcdefghijkl\xff"), ">"),
+        concat!(
+            "<00000000: 0001 0203 6162 6364 6566 6768 696a 6b6c  ....abcdefghijkl\n",
+            "00000010: ff                                       .>",
+        ),
+    );
+
+    assert!(std::panic::catch_unwind(|| HexDump::new(&blob).window(3..2)).is_err());
+}
+
 #[test]
 fn string_test() {
     assert_eq!(trunc_fmt!(0; ["h\nllo", "人ö个"]), r#""#);
@@ -256,3 +407,120 @@ fn test_bool_arrays() {
     assert_eq!(trunc_fmt!(11; [true, true]), "[true, true");
     assert_eq!(trunc_fmt!(12; [true, true]), "[true, true]");
 }
+
+#[derive(Debug, Copy, Clone)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+const_panic::impl_panicfmt! {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Dir {
+    Up,
+    Left(u8),
+}
+
+const_panic::impl_panicfmt! {
+    enum Dir {
+        Up,
+        Left(u8),
+    }
+}
+
+#[test]
+fn custom_type_array_test() {
+    use const_panic::{array_panicvals, FmtArg};
+
+    const POINTS: [Point; 3] = [
+        Point { x: 3, y: 5 },
+        Point { x: 8, y: 13 },
+        Point { x: 21, y: 34 },
+    ];
+    const DIRS: [Dir; 2] = [Dir::Up, Dir::Left(3)];
+    const GRID: [[u8; 3]; 2] = [[1, 2, 3], [4, 5, 6]];
+    const NESTED: [[Point; 1]; 2] = [[POINTS[0]], [POINTS[1]]];
+    const EMPTY: [Point; 0] = [];
+
+    macro_rules! case {
+        ($ty:tt, $array:expr) => {
+            for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+                let expected = if fmtarg.is_alternate {
+                    format!("{:#?}", $array)
+                } else {
+                    format!("{:?}", $array)
+                };
+
+                assert_eq!(
+                    trunc_fmt!(1024; array_panicvals!(fmtarg; $ty => $array)),
+                    *expected,
+                );
+            }
+        };
+    }
+
+    case!([Point; 3], POINTS);
+    case!([Dir; 2], DIRS);
+    case!([[u8; 3]; 2], GRID);
+    case!([[Point; 1]; 2], NESTED);
+    case!([Point; 0], EMPTY);
+}
+
+#[test]
+fn custom_type_slice_test() {
+    use const_panic::{array_panicvals, FmtArg};
+
+    let points: &[Point] = &[
+        Point { x: 3, y: 5 },
+        Point { x: 8, y: 13 },
+        Point { x: 21, y: 34 },
+    ];
+
+    for (max_len, expected) in [
+        (0, "[...]"),
+        (1, "[Point { x: 3, y: 5 }, ...]"),
+        (2, "[Point { x: 3, y: 5 }, Point { x: 8, y: 13 }, ...]"),
+        (3, &*format!("{:?}", points)),
+        (4, &*format!("{:?}", points)),
+    ] {
+        let pvs = match max_len {
+            0 => trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [Point; ..0] => points)),
+            1 => trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [Point; ..1] => points)),
+            2 => trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [Point; ..2] => points)),
+            3 => trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [Point; ..3] => points)),
+            _ => trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [Point; ..4] => points)),
+        };
+        assert_eq!(pvs, expected);
+    }
+
+    assert_eq!(
+        trunc_fmt!(1024; array_panicvals!(FmtArg::ALT_DEBUG; [Point; ..1] => points)),
+        concat!(
+            "[\n",
+            "    Point {\n",
+            "        x: 3,\n",
+            "        y: 5,\n",
+            "    },\n",
+            "    ...,\n",
+            "]",
+        ),
+    );
+
+    let empty: &[Point] = &[];
+    assert_eq!(
+        trunc_fmt!(1024; array_panicvals!(FmtArg::ALT_DEBUG; [Point; ..1] => empty)),
+        "[]",
+    );
+
+    let grid: &[[u8; 2]] = &[[1, 2], [3, 4]];
+    assert_eq!(
+        trunc_fmt!(1024; array_panicvals!(FmtArg::DEBUG; [[u8; 2]; ..1] => grid)),
+        "[[1, 2], ...]",
+    );
+}
@@ -133,3 +133,31 @@ fn fmt_arraystring_test() {
         assert_eq!(trunc_fmt!(200; display: short_disp), string);
     }
 }
+
+#[test]
+fn concat_and_from_panicvals_lossy_test() {
+    let long = "hello world".repeat(4);
+
+    for (string, expected) in [
+        ("", ""),
+        ("hello", "hello"),
+        (&long[..], "hello world... [truncated, 44 bytes total]"),
+        (&long[..42], &long[..42]),
+        (&long[..43], "hello world... [truncated, 43 bytes total]"),
+    ] {
+        assert_eq!(
+            ArrayString::<42>::from_panicvals_lossy(&[PanicVal::write_str(string)]),
+            *expected,
+        );
+        assert_eq!(
+            ArrayString::<42>::concat_panicvals_lossy(&[&[], &[PanicVal::write_str(string)]]),
+            *expected,
+        );
+    }
+
+    // too small to fit the marker
+    assert_eq!(
+        ArrayString::<10>::from_panicvals_lossy(&[PanicVal::write_str(&long)]),
+        "hello worl",
+    );
+}
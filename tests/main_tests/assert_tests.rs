@@ -1,3 +1,5 @@
+use crate::panic_message;
+
 use const_panic::concat_assert;
 
 #[test]
@@ -9,3 +11,469 @@ fn test_concat_assert() {
     concat_assert!(zero == 0, "hello", 100u8);
     std::panic::catch_unwind(|| concat_assert!(zero == 1, "hello", 100u8)).unwrap_err();
 }
+
+#[test]
+fn assert_eq_ne_passing() {
+    use const_panic::{concat_assert_eq, concat_assert_ne};
+
+    let string = String::from("hello");
+
+    concat_assert_eq!(3u8, 3);
+    concat_assert_eq!(-3i128, -3i128);
+    concat_assert_eq!(true, true);
+    concat_assert_eq!('ñ', 'ñ');
+    concat_assert_eq!(&*string, "hello");
+    concat_assert_eq!(3u8, 3, "message");
+
+    concat_assert_ne!(3u8, 4);
+    concat_assert_ne!(usize::MAX, 0);
+    concat_assert_ne!(true, false);
+    concat_assert_ne!('a', 'ñ');
+    concat_assert_ne!(&*string, "hell");
+    concat_assert_ne!(&*string, "hellp");
+    concat_assert_ne!(3u8, 4, "message");
+}
+
+#[test]
+fn assert_eq_ne_failing() {
+    use const_panic::{concat_assert_eq, concat_assert_ne, FmtArg};
+
+    let x = 3u32;
+
+    assert_eq!(
+        panic_message(|| concat_assert_eq!(x, 5)),
+        "assertion `left == right` failed\n  left: 3\n right: 5",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_eq!("foo", "bar\n")),
+        "assertion `left == right` failed\n  left: \"foo\"\n right: \"bar\\n\"",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_ne!(x, 3)),
+        "assertion `left != right` failed\n  left: 3\n right: 3",
+    );
+
+    // with a message
+    assert_eq!(
+        panic_message(|| concat_assert_eq!(x, 5, "x is ", x)),
+        "assertion `left == right` failed: x is 3\n  left: 3\n right: 5",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_ne!(x, 3, "x is ", x,)),
+        "assertion `left != right` failed: x is 3\n  left: 3\n right: 3",
+    );
+
+    // the `$fmtarg` argument only affects the message
+    assert_eq!(
+        panic_message(|| concat_assert_eq!(x, 255, FmtArg::HEX; "x is ", x)),
+        "assertion `left == right` failed: x is 3\n  left: 3\n right: 255",
+    );
+
+    // the `capacity` argument limits the length of the entire message
+    assert_eq!(
+        panic_message(|| concat_assert_eq!(x, 5, capacity = 25; "message")),
+        "assertion `left == right`",
+    );
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn assert_eq_ne_slices() {
+    use const_panic::{concat_assert_eq, concat_assert_ne};
+
+    concat_assert_eq!([3u8, 5], [3, 5]);
+    concat_assert_eq!(&[3u8, 5][..], &[3, 5][..]);
+    concat_assert_eq!(["foo", "bar"], ["foo", "bar"]);
+    concat_assert_eq!([true], [true]);
+    concat_assert_eq!(&['a', 'b'][..], &['a', 'b'][..]);
+
+    concat_assert_ne!([3u8, 5], [3, 6]);
+    concat_assert_ne!(&[3u8, 5][..], &[3][..]);
+    concat_assert_ne!(["foo", "bar"], ["foo", "baz"]);
+    concat_assert_ne!(&["foo"][..], &["foo", "bar"][..]);
+
+    assert_eq!(
+        panic_message(|| concat_assert_eq!([3u8, 5], [3, 6])),
+        "assertion `left == right` failed\n  left: [3, 5]\n right: [3, 6]",
+    );
+
+    // long operands are alternate-Debug formatted,
+    // the message must fit in the 256 bytes of the `max_panic_msg_len_256` feature.
+    let long = [4_000_000_000u32; 6];
+    let expected_long = format!("{:#?}", long);
+    assert_eq!(
+        panic_message(|| concat_assert_ne!(long, long)),
+        format!(
+            "assertion `left != right` failed\n  left: {}\n right: {}",
+            expected_long, expected_long,
+        ),
+    );
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn assert_eq_ne_custom_type() {
+    use const_panic::{concat_assert_eq, concat_assert_ne};
+
+    #[derive(Debug)]
+    struct Pair(u8, &'static str);
+
+    const_panic::impl_panicfmt! {
+        struct Pair(u8, &'static str);
+    }
+
+    impl Pair {
+        const fn const_eq(&self, other: &Self) -> bool {
+            self.0 == other.0 && const_panic::StdWrapper(self.1).const_eq(other.1)
+        }
+    }
+
+    const _: () = concat_assert_eq!(Pair(3, "foo"), Pair(3, "foo"));
+    const _: () = concat_assert_ne!(Pair(3, "foo"), Pair(3, "bar"));
+
+    assert_eq!(
+        panic_message(|| concat_assert_eq!(Pair(3, "foo"), Pair(4, "foo"))),
+        "assertion `left == right` failed\n  left: Pair(3, \"foo\")\n right: Pair(4, \"foo\")",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_ne!(&Pair(3, "foo"), &Pair(3, "foo"))),
+        "assertion `left != right` failed\n  left: Pair(3, \"foo\")\n right: Pair(3, \"foo\")",
+    );
+}
+
+#[test]
+fn assert_eq_const_fn() {
+    const fn checked_sub(l: u32, r: u32) -> u32 {
+        const_panic::concat_assert_ne!(l < r, true, "cannot subtract ", r, " from ", l);
+        l - r
+    }
+
+    const DIFF: u32 = checked_sub(5, 3);
+    assert_eq!(DIFF, 2);
+
+    assert_eq!(
+        panic_message(|| {
+            checked_sub(3, 5);
+        }),
+        "assertion `left != right` failed: cannot subtract 5 from 3\n  left: true\n right: true",
+    );
+}
+
+#[test]
+fn assert_ord_passing() {
+    use const_panic::{concat_assert_ge, concat_assert_gt, concat_assert_le, concat_assert_lt};
+
+    concat_assert_lt!(3u8, 4);
+    concat_assert_lt!(-1i64, 0, "message");
+    concat_assert_lt!('a', 'b');
+    concat_assert_lt!(false, true);
+
+    concat_assert_le!(3u8, 3);
+    concat_assert_le!(3u8, 4);
+    concat_assert_le!(i128::MIN, i128::MAX, "message",);
+
+    concat_assert_gt!(4u8, 3);
+    concat_assert_gt!(usize::MAX, 0);
+
+    concat_assert_ge!(4u8, 4);
+    concat_assert_ge!('ñ', 'n');
+}
+
+#[test]
+fn assert_ord_failing() {
+    use const_panic::{
+        concat_assert_ge, concat_assert_gt, concat_assert_le, concat_assert_lt, FmtArg,
+    };
+
+    const CAP: usize = 64;
+    let len = 70usize;
+    let letter = 'b';
+
+    assert_eq!(
+        panic_message(|| concat_assert_lt!(len, CAP)),
+        "assertion failed: len < CAP (len: 70, CAP: 64)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_lt!(CAP, CAP)),
+        "assertion failed: CAP < CAP (CAP: 64, CAP: 64)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_le!(len + 1, CAP * 2 - 60)),
+        "assertion failed: len + 1 <= CAP * 2 - 60 (len + 1: 71, CAP * 2 - 60: 68)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_gt!(letter, 'c')),
+        "assertion failed: letter > 'c' (letter: 'b', 'c': 'c')",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_ge!(-3i8, 0)),
+        "assertion failed: -3i8 >= 0 (-3i8: -3, 0: 0)",
+    );
+
+    // with a message
+    assert_eq!(
+        panic_message(|| concat_assert_lt!(len, CAP, "too long: ", len,)),
+        "assertion failed: len < CAP (len: 70, CAP: 64): too long: 70",
+    );
+
+    // the `$fmtarg` argument only affects the message
+    assert_eq!(
+        panic_message(|| concat_assert_lt!(len, CAP, FmtArg::HEX; len)),
+        "assertion failed: len < CAP (len: 70, CAP: 64): 46",
+    );
+
+    // the `capacity` argument limits the length of the entire message
+    assert_eq!(
+        panic_message(|| concat_assert_lt!(len, CAP, capacity = 21; "message")),
+        "assertion failed: len",
+    );
+}
+
+#[test]
+fn assert_ord_evaluates_once() {
+    use const_panic::{concat_assert_in_range, concat_assert_lt};
+
+    let mut count = 0u32;
+    let mut next = || {
+        count += 1;
+        count
+    };
+
+    concat_assert_lt!(next(), 10);
+    concat_assert_in_range!(next(), 1..=10);
+    concat_assert_in_range!(next(), 1..);
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn assert_in_range() {
+    use const_panic::concat_assert_in_range;
+
+    const CAP: usize = 64;
+    let len = 70usize;
+    let zero = 0usize;
+
+    concat_assert_in_range!(10u8, 10..11);
+    concat_assert_in_range!(10u8, 0..=10);
+    concat_assert_in_range!(10u8, 10..);
+    concat_assert_in_range!(10u8, ..11);
+    concat_assert_in_range!(10u8, ..=10, "message");
+    concat_assert_in_range!('b', 'a'..='z');
+    concat_assert_in_range!(-5i32, -10..-4);
+
+    assert_eq!(
+        panic_message(|| concat_assert_in_range!(len, 1..=CAP)),
+        "assertion failed: len <= CAP (len: 70, CAP: 64)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_in_range!(len, 1..70)),
+        "assertion failed: len < 70 (len: 70, 70: 70)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_in_range!(len, ..CAP + 1)),
+        "assertion failed: len < CAP + 1 (len: 70, CAP + 1: 65)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_in_range!(zero, 1..=CAP)),
+        "assertion failed: 1 <= zero (1: 1, zero: 0)",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_in_range!(zero, CAP / 2.., "len is ", zero)),
+        "assertion failed: CAP / 2 <= zero (CAP / 2: 32, zero: 0): len is 0",
+    );
+}
+
+#[test]
+fn assert_ord_const_fn() {
+    use const_panic::{concat_assert_in_range, concat_assert_lt};
+
+    const fn get(array: [u8; 4], index: usize) -> u8 {
+        concat_assert_lt!(index, array.len(), "out of bounds");
+        array[index]
+    }
+    const fn percentage(n: u32) -> u32 {
+        concat_assert_in_range!(n, 0..=100);
+        n
+    }
+
+    const ELEM: u8 = get([3, 5, 8, 13], 2);
+    const PERC: u32 = percentage(100);
+    assert_eq!(ELEM, 8);
+    assert_eq!(PERC, 100);
+
+    assert_eq!(
+        panic_message(|| {
+            get([3, 5, 8, 13], 4);
+        }),
+        "assertion failed: index < array.len() (index: 4, array.len(): 4): out of bounds",
+    );
+}
+
+#[test]
+fn assert_matches_passing() {
+    use const_panic::concat_assert_matches;
+
+    let x = 3u8;
+    let string = "hello";
+
+    concat_assert_matches!(x, 3);
+    concat_assert_matches!(x, 0..=5);
+    concat_assert_matches!(x, 1 | 3 | 5,);
+    concat_assert_matches!(x, n if n % 2 == 1, "message");
+    concat_assert_matches!(string, "hello" | "world");
+    concat_assert_matches!('ñ', 'a'..='z' | 'ñ', "message",);
+    concat_assert_matches!(x, _);
+}
+
+#[test]
+fn assert_matches_failing() {
+    use const_panic::{concat_assert_matches, FmtArg};
+
+    let x = 3u8;
+
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(x, 0 | 1)),
+        "assertion `left matches right` failed\n  left: 3\n right: 0 | 1",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(x, n if n > 5)),
+        "assertion `left matches right` failed\n  left: 3\n right: n if n > 5",
+    );
+    assert_eq!(
+        panic_message(|| concat_assert_matches!("foo", "bar")),
+        "assertion `left matches right` failed\n  left: \"foo\"\n right: \"bar\"",
+    );
+
+    // with a message
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(x, 4..=10, "x is ", x,)),
+        "assertion `left matches right` failed: x is 3\n  left: 3\n right: 4..=10",
+    );
+
+    // the `$fmtarg` argument only affects the message
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(x + 10, 0, FmtArg::HEX; x + 10)),
+        "assertion `left matches right` failed: D\n  left: 13\n right: 0",
+    );
+
+    // the `capacity` argument limits the length of the entire message
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(x, 0, capacity = 30; "message")),
+        "assertion `left matches right`",
+    );
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn assert_matches_enum() {
+    use const_panic::concat_assert_matches;
+
+    #[derive(Copy, Clone)]
+    enum State {
+        Idle,
+        Running { step: u32, name: &'static str },
+        Done(u64),
+    }
+
+    const_panic::impl_panicfmt! {
+        enum State {
+            Idle,
+            Running { step: u32, name: &'static str },
+            Done(u64),
+        }
+    }
+
+    const fn advance(state: State) -> State {
+        concat_assert_matches!(
+            state,
+            State::Idle | State::Running { .. },
+            "cannot advance a finished state"
+        );
+        match state {
+            State::Running { step: 3, .. } => State::Done(3),
+            State::Running { step, name } => State::Running {
+                step: step + 1,
+                name,
+            },
+            _ => State::Running {
+                step: 0,
+                name: "foo",
+            },
+        }
+    }
+
+    const DONE: State = advance(advance(advance(advance(advance(State::Idle)))));
+    concat_assert_matches!(DONE, State::Done(3));
+    concat_assert_matches!(DONE, State::Done(n) if n == 3);
+
+    assert_eq!(
+        panic_message(|| {
+            advance(DONE);
+        }),
+        "assertion `left matches right` failed: cannot advance a finished state\
+         \n  left: Done(3)\n right: State::Idle | State::Running { .. }",
+    );
+
+    // the scrutinee is alternate-Debug formatted when it doesn't fit on one line
+    let running = State::Running {
+        step: 1,
+        name: "a very long name for a state that is still running",
+    };
+    assert_eq!(
+        panic_message(|| concat_assert_matches!(running, State::Done(_))),
+        "assertion `left matches right` failed\
+         \n  left: Running {\
+         \n    step: 1,\
+         \n    name: \"a very long name for a state that is still running\",\
+         \n}\
+         \n right: State::Done(_)",
+    );
+}
+
+#[test]
+fn debug_asserts() {
+    use const_panic::{concat_debug_assert, concat_debug_assert_eq, concat_debug_assert_ne};
+
+    let x = 3u32;
+
+    concat_debug_assert!(x == 3);
+    concat_debug_assert!(x == 3, "x is ", x);
+    concat_debug_assert_eq!(x, 3);
+    concat_debug_assert_eq!(x, 3, "message");
+    concat_debug_assert_ne!(x, 4);
+    concat_debug_assert_ne!(x, 4, "message",);
+
+    if cfg!(debug_assertions) {
+        assert_eq!(
+            panic_message(|| concat_debug_assert!(x == 4, "x is ", x)),
+            "x is 3",
+        );
+        assert_eq!(
+            panic_message(|| concat_debug_assert_eq!(x, 4, "message")),
+            "assertion `left == right` failed: message\n  left: 3\n right: 4",
+        );
+        assert_eq!(
+            panic_message(|| concat_debug_assert_ne!(x, 3)),
+            "assertion `left != right` failed\n  left: 3\n right: 3",
+        );
+    } else {
+        concat_debug_assert!(x == 4, "x is ", x);
+        concat_debug_assert_eq!(x, 4, "message");
+        concat_debug_assert_ne!(x, 3);
+    }
+}
+
+#[test]
+fn debug_asserts_const_fn() {
+    use const_panic::{concat_debug_assert, concat_debug_assert_eq, concat_debug_assert_ne};
+
+    const fn checked(n: u32) -> u32 {
+        concat_debug_assert!(n != 0, "n must not be zero");
+        concat_debug_assert_eq!(n % 2, 1);
+        concat_debug_assert_ne!(n, 5);
+        n
+    }
+
+    const N: u32 = checked(3);
+    assert_eq!(N, 3);
+}
@@ -53,3 +53,143 @@ fn concat_with_formatters() {
         "[\n    0b100000,\n    0b100001,\n]"
     );
 }
+
+#[test]
+fn concat_tuples() {
+    #[derive(Debug)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+    const_panic::impl_panicfmt! {
+        struct Point {
+            x: u8,
+            y: u8,
+        }
+    }
+
+    const P: Point = Point { x: 3, y: 5 };
+
+    assert_eq!(concat_!((1u8,)), format!("{:?}", (1u8,)));
+    assert_eq!(concat_!((1u8, "a")), format!("{:?}", (1u8, "a")));
+    assert_eq!(concat_!((1u8, "a", P)), format!("{:?}", (1u8, "a", P)));
+    assert_eq!(
+        concat_!((1u8, "a", false,)),
+        format!("{:?}", (1u8, "a", false))
+    );
+    assert_eq!(
+        concat_!((1u8, 2u16, 3u32, 4u64, 5u128, 6i8, 7i16, 8i32, 9i64, 10i128, 11isize, 12usize)),
+        format!(
+            "{:?}",
+            (1u8, 2u16, 3u32, 4u64, 5u128, 6i8, 7i16, 8i32, 9i64, 10i128, 11isize, 12usize)
+        ),
+    );
+
+    assert_eq!(
+        concat_!(FmtArg::ALT_DEBUG; (1u8,)),
+        format!("{:#?}", (1u8,))
+    );
+    assert_eq!(
+        concat_!(FmtArg::ALT_DEBUG; (1u8, "a", P)),
+        format!("{:#?}", (1u8, "a", P))
+    );
+    assert_eq!(
+        concat_!(alt_debug: (1u8, "a", P)),
+        format!("{:#?}", (1u8, "a", P))
+    );
+    assert_eq!(
+        concat_!(FmtArg::ALT_DEBUG; "[", [3u8], (1u8, "a"), "]"),
+        "[[\n    3,\n](\n    1,\n    \"a\",\n)]"
+    );
+
+    // the formatting override applies to all of the elements
+    assert_eq!(concat_!(display: ("a", 'b')), "(a, b)");
+    assert_eq!(concat_!({x}: (255u8, [16u8])), "(ff, [10])");
+    assert_eq!(concat_!({#X}: (255u8,)), "(\n    0xFF,\n)");
+    assert_eq!(concat_!({>3}: (1u8, 2u8)), "(  1,   2)");
+
+    // parenthesized expressions without commas aren't tuples
+    assert_eq!(
+        concat_!("a", ("b"), (3u8), ("c", "d")),
+        r#"a"b"3("c", "d")"#
+    );
+}
+
+#[test]
+fn concat_tuple_overrides_evaluated_once() {
+    // the `open` override indents the tuple once, not once per element
+    assert_eq!(
+        concat_!(FmtArg::ALT_DEBUG; open: (1u8, 2u8), close: ""),
+        "(\n        1,\n        2,\n    )",
+    );
+    assert_eq!(
+        concat_!(FmtArg::ALT_DEBUG; open: (1u8,), close: ""),
+        "(\n        1,\n    )",
+    );
+}
+
+#[test]
+fn tuple_panicvals_test() {
+    use const_panic::tuple_panicvals;
+
+    #[derive(Debug, Copy, Clone)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+    const_panic::impl_panicfmt! {
+        struct Point {
+            x: u8,
+            y: u8,
+        }
+    }
+
+    macro_rules! case {
+        ($tuple_ty:tt => $tuple:expr) => {{
+            let tuple: $tuple_ty = $tuple;
+
+            for (fmtarg, expected) in [
+                (FmtArg::DEBUG, format!("{:?}", tuple)),
+                (FmtArg::ALT_DEBUG, format!("{:#?}", tuple)),
+            ] {
+                assert_eq!(
+                    trunc_fmt!(1024; tuple_panicvals!(fmtarg; $tuple_ty => tuple)),
+                    *expected,
+                );
+            }
+        }};
+    }
+
+    let t = (1u8, ("a", 2u8));
+    assert_eq!(
+        trunc_fmt!(1024; "t: ", tuple_panicvals!(FmtArg::DEBUG; (u8, (&str, u8)) => t)),
+        r#"t: (1, ("a", 2))"#
+    );
+    assert_eq!(
+        trunc_fmt!(1024; tuple_panicvals!(FmtArg::DEBUG; (&str, u8) => &t.1)),
+        r#"("a", 2)"#
+    );
+
+    case! {(u8,) => (1,)}
+    case! {(u8, &str) => (1, "a")}
+    case! {(u8, (&str, u8)) => (1, ("a", 2))}
+    case! {((u8,), (Point, (char, bool)),) => ((1,), (Point { x: 3, y: 5 }, ('c', true)))}
+    case! {(Point, [u8; 2], Option<u8>) => (Point { x: 3, y: 5 }, [8, 13], Some(21))}
+    case! {
+        (u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize, usize)
+        => (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)
+    }
+
+    // the formatting is applied to all of the elements
+    assert_eq!(
+        concat_!(tuple_panicvals!(FmtArg::HEX; (u8, (u8, [u8; 1])) => (255, (16, [10])))),
+        "(FF, (10, [A]))"
+    );
+
+    // output length is the `PV_COUNT` of the tuple
+    let pvs = tuple_panicvals!(FmtArg::DEBUG; (u8, (&str, u8)) => t);
+    assert_eq!(
+        pvs.len(),
+        <(u8, (&str, u8)) as const_panic::PanicFmt>::PV_COUNT
+    );
+}
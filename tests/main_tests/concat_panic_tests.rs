@@ -0,0 +1,202 @@
+use crate::panic_message;
+
+use const_panic::{
+    concat_assert, concat_panic, concat_panic_with_capacity, FmtArg, PanicVal, MAX_PANIC_MSG_LEN,
+};
+
+#[test]
+#[cfg(not(any(feature = "max_panic_msg_len_256", feature = "max_panic_msg_len_1024")))]
+fn default_max_panic_msg_len() {
+    assert_eq!(MAX_PANIC_MSG_LEN, 32768);
+}
+
+#[test]
+fn concat_panic_truncation() {
+    let long = "abcd".repeat(MAX_PANIC_MSG_LEN);
+
+    let msg = panic_message(|| concat_panic!(display: &*long));
+    assert_eq!(msg.len(), MAX_PANIC_MSG_LEN);
+    assert_truncated(&msg, &long);
+}
+
+// asserts that `msg` is a prefix of `full` followed by the truncation marker
+#[track_caller]
+fn assert_truncated(msg: &str, full: &str) {
+    let marker = format!("... [truncated, {} bytes total]", full.len());
+    let prefix = msg.strip_suffix(&*marker).unwrap();
+    assert!(full.starts_with(prefix), "{:?}", msg);
+}
+
+#[test]
+fn concat_panic_with_capacity_fn() {
+    let args: &[&[PanicVal<'_>]] = &[&[
+        PanicVal::write_str("hello "),
+        PanicVal::from_u32(1234, FmtArg::DISPLAY),
+    ]];
+
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<100>(args)),
+        "hello 1234"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<10>(args)),
+        "hello 1234"
+    );
+    // integers aren't partially written
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<8>(args)),
+        "hello "
+    );
+    assert_eq!(panic_message(|| concat_panic_with_capacity::<0>(args)), "");
+}
+
+#[test]
+fn concat_panic_with_larger_capacity() {
+    const CAP: usize = MAX_PANIC_MSG_LEN * 2;
+
+    let long = "abcd".repeat(MAX_PANIC_MSG_LEN);
+
+    let msg = panic_message(|| concat_panic!(capacity = CAP; display: &*long));
+    assert_eq!(msg.len(), CAP);
+    assert_truncated(&msg, &long);
+}
+
+#[test]
+fn truncation_marker() {
+    let args: &[&[PanicVal<'_>]] = &[&[
+        PanicVal::write_str("hello "),
+        PanicVal::from_str("world\n", FmtArg::DEBUG),
+        PanicVal::from_u32(1234, FmtArg::DISPLAY),
+    ]];
+
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<19>(args)),
+        "hello \"world\\n\"1234",
+    );
+    // the marker is only written if it fits in the capacity
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<18>(args)),
+        "hello \"world\\n\"",
+    );
+
+    let long = "hello world".repeat(4);
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 31; display: &*long)),
+        "... [truncated, 44 bytes total]",
+    );
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 35; display: &*long)),
+        "hell... [truncated, 44 bytes total]",
+    );
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 36; debug: &*long)),
+        "\"hell... [truncated, 46 bytes total]",
+    );
+}
+
+#[test]
+fn truncation_marker_char_boundary() {
+    let chars = "ñ".repeat(20);
+    let args: &[&[PanicVal<'_>]] = &[&[PanicVal::write_str(&chars)]];
+
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<33>(args)),
+        "ñ... [truncated, 40 bytes total]",
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<34>(args)),
+        "ñ... [truncated, 40 bytes total]",
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_with_capacity::<35>(args)),
+        "ññ... [truncated, 40 bytes total]",
+    );
+}
+
+#[test]
+fn capacity_macro_arg() {
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 6; "hello", " world")),
+        "hello "
+    );
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 2 + 3; FmtArg::DEBUG; "foo", ("bar"))),
+        "foo\"b"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic!(capacity = 100; FmtArg::HEX; "foo", 255u8)),
+        "fooFF"
+    );
+}
+
+#[test]
+fn capacity_macro_arg_assert() {
+    let zero = 0;
+    concat_assert!(zero == 0, capacity = 4; "hello", 100u8);
+
+    assert_eq!(
+        panic_message(|| concat_assert!(zero == 1, capacity = 4; "hello", 100u8)),
+        "hell"
+    );
+}
+
+#[test]
+fn unreachable_todo_unimplemented() {
+    use const_panic::{concat_todo, concat_unimplemented, concat_unreachable};
+
+    let x = 10u8;
+
+    assert_eq!(
+        panic_message(|| concat_unreachable!()),
+        "internal error: entered unreachable code",
+    );
+    assert_eq!(
+        panic_message(|| concat_unreachable!("x is ", x)),
+        "internal error: entered unreachable code: x is 10",
+    );
+    assert_eq!(panic_message(|| concat_todo!()), "not yet implemented");
+    assert_eq!(
+        panic_message(|| concat_todo!("x is ", x,)),
+        "not yet implemented: x is 10",
+    );
+    assert_eq!(panic_message(|| concat_unimplemented!()), "not implemented");
+    assert_eq!(
+        panic_message(|| concat_unimplemented!("x is ", x)),
+        "not implemented: x is 10",
+    );
+
+    // the `$fmtarg` argument
+    assert_eq!(
+        panic_message(|| concat_todo!(FmtArg::HEX; "x is ", x, ' ', debug: 'a')),
+        "not yet implemented: x is A 'a'",
+    );
+
+    // the `capacity` argument limits the length of the entire message
+    assert_eq!(
+        panic_message(|| concat_unimplemented!(capacity = 20; "x is ", x)),
+        "not implemented: x i",
+    );
+}
+
+#[test]
+fn unreachable_const_fn() {
+    use const_panic::concat_unreachable;
+
+    const fn to_bool(n: u8) -> bool {
+        match n {
+            0 => false,
+            1 => true,
+            _ => concat_unreachable!("expected 0 or 1, found ", n),
+        }
+    }
+
+    const TRUE: bool = to_bool(1);
+    assert!(TRUE);
+
+    assert_eq!(
+        panic_message(|| {
+            to_bool(2);
+        }),
+        "internal error: entered unreachable code: expected 0 or 1, found 2",
+    );
+}
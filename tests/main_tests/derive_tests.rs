@@ -453,3 +453,196 @@ pub struct IgnoredAndImpl<'a, A, B, const X: u32, const Y: char>(
     pub MyPhantomData<A>,
     pub MyPhantomData<B>,
 );
+
+#[test]
+fn custom_type_array_fields() {
+    let foo = ArrayFields {
+        points: [Point { x: 3, y: 5 }],
+        grid: [[1, 2], [3, 4]],
+        bytes: [21],
+    };
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; ArrayFields => foo),
+        *format!("{:?}", foo)
+    );
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; ArrayFields => foo),
+        *format!("{:#?}", foo)
+    );
+
+    let bar = ArrayVariants::Nested([[Point { x: 0, y: 1 }]]);
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; ArrayVariants => bar),
+        *format!("{:?}", bar)
+    );
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; ArrayVariants => bar),
+        *format!("{:#?}", bar)
+    );
+}
+
+#[derive(Debug, PanicFmt)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Debug, PanicFmt)]
+struct ArrayFields {
+    points: [Point; 1],
+    grid: [[u8; 2]; 2],
+    bytes: [u8; 1],
+}
+
+#[derive(Debug, PanicFmt)]
+enum ArrayVariants {
+    #[allow(dead_code)]
+    Empty([Point; 0]),
+    Nested([[Point; 1]; 1]),
+}
+
+#[test]
+fn array_field_attributes() {
+    // arrays formatted with their `PanicFmt` impl are written as byte strings,
+    // while arrays formatted with `array_panicvals` are written element by element.
+    let foo = ArrayFmts {
+        qualified: [1, 2],
+        aliased: [3, 4],
+        elements: [5, 6],
+    };
+    assert_eq!(
+        fmt_flatten!(FmtArg::BYTE_STR; ArrayFmts => foo),
+        r#"ArrayFmts { qualified: b"\x01\x02", aliased: b"\x03\x04", elements: [5, 6] }"#,
+    );
+}
+
+type Byte = u8;
+
+#[derive(PanicFmt)]
+struct ArrayFmts {
+    qualified: [core::primitive::u8; 2],
+    #[pfmt(array_impl)]
+    aliased: [Byte; 2],
+    #[pfmt(array_panicvals)]
+    elements: [u8; 2],
+}
+
+#[test]
+fn tuple_fields() {
+    let foo = TupleFields {
+        pair: (3, ("hello", Point { x: 5, y: 8 })),
+        unit: (),
+    };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; TupleFields => foo),
+        *format!("{:?}", foo)
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; TupleFields => foo),
+        *format!("{:#?}", foo)
+    );
+
+    let bar = TupleVariant::Single((13,));
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; TupleVariant => bar),
+        *format!("{:?}", bar)
+    );
+}
+
+#[derive(Debug, PanicFmt)]
+struct TupleFields<'a> {
+    pair: (u8, (&'a str, Point)),
+    unit: (),
+}
+
+#[derive(Debug, PanicFmt)]
+enum TupleVariant {
+    Single((u16,)),
+}
+
+#[test]
+fn std_wrapper_fields() {
+    use core::{num::Wrapping, ops::Bound, task::Poll};
+
+    let foo = WrapperFields {
+        wrapping: Wrapping(3),
+        bound: Bound::Excluded(5),
+        poll: Poll::Pending,
+    };
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; WrapperFields => foo),
+        *format!("{:?}", foo)
+    );
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; WrapperFields => foo),
+        *format!("{:#?}", foo)
+    );
+}
+
+#[derive(Debug, PanicFmt)]
+struct WrapperFields {
+    wrapping: core::num::Wrapping<u8>,
+    bound: core::ops::Bound<u16>,
+    poll: core::task::Poll<&'static str>,
+}
+
+#[test]
+fn flags_formatting() {
+    for (perms, display) in [
+        (Perms(0b101), "READ | EXEC"),
+        (Perms(0b111), "READ | WRITE | EXEC"),
+        (Perms(0x46), "WRITE | EXEC | 0x40"),
+        (Perms(0x100), "0x100"),
+        (Perms(0), "0x0"),
+    ] {
+        assert_eq!(fmt_flatten!(FmtArg::DISPLAY; Perms => perms), display);
+        assert_eq!(
+            fmt_flatten!(FmtArg::DEBUG; Perms => perms),
+            *format!("Perms({})", display)
+        );
+        assert_eq!(
+            fmt_flatten!(FmtArg::ALT_DEBUG; Perms => perms),
+            *format!("Perms(\n    {},\n)", display)
+        );
+    }
+
+    let named = NamedPerms { bits: 0b11 };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; NamedPerms => named),
+        "NamedPerms(READ | WRITE)"
+    );
+
+    // signed integers aren't sign-extended
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; SignedPerms => SignedPerms(i16::MIN)),
+        "SignedPerms(HIGH)"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; SignedPerms => SignedPerms(-1)),
+        "SignedPerms(LOW | HIGH | 0x7ffe)"
+    );
+}
+
+#[derive(PanicFmt)]
+#[pfmt(flags(READ = 1, WRITE = 1 << 1, EXEC = Perms::EXEC))]
+struct Perms(u16);
+
+impl Perms {
+    const EXEC: u16 = 4;
+}
+
+#[derive(PanicFmt)]
+#[pfmt(flags(READ = 1, WRITE = 2))]
+struct NamedPerms {
+    bits: u8,
+}
+
+#[derive(PanicFmt)]
+#[pfmt(flags(LOW = 1, HIGH = i16::MIN))]
+struct SignedPerms(i16);
@@ -0,0 +1,55 @@
+use core::{alloc::Layout, convert::TryFrom};
+
+#[test]
+fn test_error_types() {
+    test_val! {u8::try_from(300u32).unwrap_err()}
+    test_val! {<[u8; 2]>::try_from(&[3u8][..]).unwrap_err()}
+    test_val! {char::try_from(0xD800u32).unwrap_err()}
+    test_val! {"yes".parse::<bool>().unwrap_err()}
+    test_val! {Layout::from_size_align(1, 3).unwrap_err()}
+
+    // the kind of error can't be read in const
+    for err in ["".parse::<char>(), "ab".parse::<char>()] {
+        let err = err.unwrap_err();
+        assert_eq!(trunc_fmt!(1024; err), "ParseCharError { .. }");
+        assert_eq!(
+            trunc_fmt!(1024; display: err),
+            "cannot parse char from string"
+        );
+    }
+}
+
+#[test]
+fn test_layout() {
+    for layout in [
+        Layout::new::<()>(),
+        Layout::new::<u8>(),
+        Layout::new::<[u64; 3]>(),
+        Layout::from_size_align(4096, 4096).unwrap(),
+    ] {
+        test_val! {layout, no_display}
+        assert_eq!(trunc_fmt!(1024; {X}: layout), *format!("{:X?}", layout));
+    }
+}
+
+#[test]
+fn test_fp_category() {
+    for category in [
+        0.0f64,
+        1.0,
+        f64::NAN,
+        f64::INFINITY,
+        f64::MIN_POSITIVE / 2.0,
+    ]
+    .iter()
+    .map(|x| x.classify())
+    {
+        test_val! {category, no_display}
+    }
+}
+
+#[test]
+#[should_panic(expected = "invoked `unwrap_ok` macro with an `Err` value: TryFromIntError(())")]
+fn test_unwrap_ok_conversion_error() {
+    let _: u8 = const_panic::unwrap_ok!(u8::try_from(300u32));
+}
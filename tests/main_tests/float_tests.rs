@@ -0,0 +1,143 @@
+use const_panic::{FmtArg, PanicVal};
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+macro_rules! test_case {
+    ($num:expr)=> (
+        let float = $num;
+
+        for (fmt, string) in [
+            (FmtArg::DEBUG, format!("{:?}", float)),
+            (FmtArg::ALT_DEBUG, format!("{:#?}", float)),
+            (FmtArg::DISPLAY, format!("{}", float)),
+            (FmtArg::ALT_DISPLAY, format!("{:#}", float)),
+        ] {
+            let msg = || format!(
+                "string.len(): {} num: {:?} bits: {:x} fmt_override: {:?}",
+                string.len(), float, float.to_bits(), fmt,
+            );
+
+            assert_eq!(
+                overf_fmt!(string.len(); fmt; float).unwrap(),
+                *string,
+                "{}",
+                msg(),
+            );
+            assert_eq!(trunc_fmt!(string.len(); fmt; float), *string, "{}", msg());
+
+            overf_fmt!(string.len() - 1; fmt; float).unwrap_err();
+            assert_eq!(
+                trunc_fmt!(string.len() - 1; fmt; float),
+                "",
+                "{}", msg()
+            );
+        }
+    )
+}
+
+macro_rules! float_test {
+    ($ty:ident, $bits:ty, $seed:expr) => {{
+        use core::$ty as consts;
+
+        let special = [
+            0.0,
+            1.0,
+            0.1,
+            0.3,
+            0.5,
+            1.5,
+            2.0,
+            10.0,
+            100.0,
+            123.456,
+            1e-4,
+            9.999e-5,
+            1e15,
+            1e16,
+            9.999999e15,
+            1e-7,
+            1e30,
+            consts::EPSILON,
+            consts::MIN_POSITIVE,
+            consts::MAX,
+            consts::INFINITY,
+            consts::NAN,
+            consts::consts::PI,
+            consts::consts::E,
+            <$ty>::from_bits(1),
+            <$ty>::from_bits(2),
+            <$ty>::from_bits(12345),
+            <$ty>::from_bits(<$ty>::MIN_POSITIVE.to_bits() - 1),
+        ];
+
+        for x in special.iter().flat_map(|&x| [x, -x]) {
+            test_case!(x);
+        }
+
+        for pow in (-45..40).map(|n| (10.0 as $ty).powi(n)) {
+            test_case!(pow);
+            test_case!(-pow);
+        }
+
+        for pow in (<$ty>::MIN_EXP - <$ty>::MANTISSA_DIGITS as i32..<$ty>::MAX_EXP)
+            .map(|n| (2.0 as $ty).powi(n))
+        {
+            test_case!(pow);
+            test_case!(<$ty>::from_bits(pow.to_bits().saturating_sub(1)));
+            test_case!(<$ty>::from_bits(pow.to_bits() + 1));
+        }
+
+        let mut rng = SmallRng::seed_from_u64($seed);
+        for _ in 0..5000 {
+            test_case!(<$ty>::from_bits(rng.gen::<$bits>()));
+        }
+    }};
+}
+
+#[test]
+fn basic_float_test() {
+    float_test! {f32, u32, 2876345938127371039}
+    float_test! {f64, u64, 8128638125743718923}
+}
+
+#[test]
+fn float_std_wrapper_test() {
+    test_val! {1.5f32}
+    test_val! {-0.0f32}
+    test_val! {1e100f64}
+    test_val! {-2.5e-300f64}
+    test_val! {f64::NAN}
+    test_val! {f64::NEG_INFINITY}
+}
+
+#[test]
+fn float_const_test() {
+    const DEBUG: PanicVal<'_> = PanicVal::from_f64(-1.2345678901234568e-300, FmtArg::DEBUG);
+    const DISPLAY: PanicVal<'_> = PanicVal::from_f32(0.1, FmtArg::DISPLAY);
+
+    assert_eq!(trunc_fmt!(1024; DEBUG), "-1.2345678901234568e-300");
+    assert_eq!(trunc_fmt!(1024; DISPLAY), "0.1");
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn float_slice_option_test() {
+    let arr = [1.0f64, -0.5, 1e20, f64::NAN];
+    let arr32 = [3.25f32, f32::INFINITY];
+
+    assert_eq!(trunc_fmt!(1024; arr), *format!("{:?}", arr));
+    assert_eq!(trunc_fmt!(1024; alt_debug: arr), *format!("{:#?}", arr));
+    assert_eq!(
+        trunc_fmt!(1024; display: &arr[..]),
+        "[1, -0.5, 100000000000000000000, NaN]"
+    );
+    assert_eq!(trunc_fmt!(1024; arr32), *format!("{:?}", arr32));
+
+    assert_eq!(trunc_fmt!(1024; Some(0.25f32)), "Some(0.25)");
+    assert_eq!(trunc_fmt!(1024; None::<f64>), "None");
+    assert_eq!(trunc_fmt!(1024; Some(arr32)), *format!("{:?}", Some(arr32)));
+    assert_eq!(
+        trunc_fmt!(1024; Some(&arr[..])),
+        *format!("{:?}", Some(&arr[..]))
+    );
+}
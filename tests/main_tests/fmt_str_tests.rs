@@ -0,0 +1,181 @@
+use crate::panic_message;
+
+// compares the message of `const_panic::panic` with the equivalent std format string
+macro_rules! fmt_test {
+    ($($args:tt)*) => {
+        assert_eq!(
+            panic_message(|| const_panic::panic!($($args)*)),
+            format!($($args)*),
+        )
+    };
+}
+
+#[test]
+fn literal_text() {
+    fmt_test!("");
+    fmt_test!("hello");
+    fmt_test!("{{hello}}");
+    fmt_test!("}}{{");
+    fmt_test!("ñ{{}}ñ");
+
+    assert_eq!(panic_message(|| const_panic::panic!()), "explicit panic");
+}
+
+#[test]
+fn argument_kinds() {
+    let x = 3u8;
+    let y = "foo";
+
+    // positional arguments
+    fmt_test!("{} {}", x, y);
+    fmt_test!("{1} {0} {1}", x, y);
+    fmt_test!("{} {0} {} {1}", x, y);
+    fmt_test!("{}", x + 2,);
+    fmt_test!("{}", x == 3);
+
+    // named arguments
+    fmt_test!("{a} {b:?} {a}", a = x, b = y);
+    fmt_test!("{} {a}", y, a = x);
+
+    // inline captured arguments
+    fmt_test!("{x} {y:?}");
+    fmt_test!("{x} {y} {}", y, y = 5u8);
+}
+
+#[test]
+fn argument_expressions() {
+    fn pick<A, B>(a: A, _: B) -> A {
+        a
+    }
+
+    // commas in generic arguments, closures, and array expressions
+    fmt_test!("{}", pick::<u8, u16>(3, 5));
+    fmt_test!("{}", (|a: u8, b: u8| a + b)(3, 5));
+    fmt_test!("{} {x}", [1u8, 2, 3][2], x = [4u8, 5][1]);
+    // formatting arrays requires the "non_basic" feature
+    #[cfg(feature = "non_basic")]
+    fmt_test!("{:?} {x}", [1u8, 2, 3], x = [4u8, 5][1]);
+}
+
+#[test]
+fn arguments_evaluated_once() {
+    let count = std::cell::Cell::new(0u32);
+    let next = || {
+        count.set(count.get() + 1);
+        count.get()
+    };
+
+    assert_eq!(
+        panic_message(std::panic::AssertUnwindSafe(|| {
+            const_panic::panic!("{0} {0} {a} {a:?} {}", next(), a = next())
+        })),
+        "1 1 2 2 1",
+    );
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn formatting_types() {
+    let num = 1234u32;
+    let neg = -1234i32;
+    let string = "hello\n";
+
+    fmt_test!("{num} {num:?} {num:#?} {num:x} {num:X} {num:o} {num:b}");
+    fmt_test!("{num:#x} {num:#X} {num:#o} {num:#b} {num:x?} {num:X?} {num:#X?}");
+    fmt_test!("{neg} {neg:?} {neg:+}");
+    fmt_test!("{string} {string:?} {string:#?}");
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn formatting_types_non_basic() {
+    let array = [3u8, 5, 8];
+
+    fmt_test!("{array:?} {array:#?} {array:x?} {array:#X?}");
+}
+
+#[test]
+fn formatting_spec() {
+    let num = 1234u32;
+    let neg = -1234i32;
+    let string = "hello";
+    let float = 1.5f64;
+
+    fmt_test!("[{num:8}] [{num:<8}] [{num:^8}] [{num:>8}]");
+    fmt_test!("[{num:*<8}] [{num:-^9}] [{num:ñ>8}] [{num: >8}]");
+    fmt_test!("[{num:08}] [{neg:08}] [{num:+08}] [{num:#010x}] [{num:#010b}]");
+    fmt_test!("[{string:8}] [{string:>8}] [{string:^8?}] [{string:.3}] [{string:>6.2}]");
+    fmt_test!("[{float:.3}] [{float:8.2}] [{float:+}] [{float:<+8.1}]");
+}
+
+#[test]
+fn digit_grouping() {
+    let num = 1234567u32;
+
+    assert_eq!(
+        panic_message(|| const_panic::panic!("{num:_} {num:,} {num:#_X}")),
+        "1_234_567 1,234,567 0x12_D687",
+    );
+}
+
+#[test]
+fn capacity_argument() {
+    let x = 12345u32;
+
+    assert_eq!(
+        panic_message(|| const_panic::panic!(capacity = 8; "x = {x}")),
+        "x = ",
+    );
+    assert_eq!(
+        panic_message(|| const_panic::panic!(capacity = 9; "x = {x}")),
+        "x = 12345",
+    );
+}
+
+#[test]
+fn const_panic_fmt_str() {
+    const fn checked_div(n: u32, d: u32) -> u32 {
+        if d == 0 {
+            const_panic::panic!("cannot divide {n} by {d}");
+        }
+        n / d
+    }
+
+    const QUOT: u32 = checked_div(10, 3);
+    assert_eq!(QUOT, 3);
+
+    assert_eq!(
+        panic_message(|| {
+            checked_div(10, 0);
+        }),
+        "cannot divide 10 by 0",
+    );
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn format_macro() {
+    use const_panic::format_;
+
+    const NUM: u8 = 10;
+    const NAME: &str = "foo";
+    const ARRAY: [u32; 2] = [3, 5];
+
+    assert_eq!(format_!(), "");
+    assert_eq!(format_!(""), "");
+    assert_eq!(format_!("{{}}"), "{}");
+    assert_eq!(format_!("{NUM}"), "10");
+    assert_eq!(format_!("{NAME:?} {NAME:>5}"), r#""foo"   foo"#);
+    assert_eq!(
+        format_!("{ARRAY:?} {ARRAY:#X?}"),
+        format!("{ARRAY:?} {ARRAY:#X?}")
+    );
+    assert_eq!(
+        format_!("{0:08b} {0:#x} {n}", NUM, n = NUM + 1),
+        "00001010 0xa 11"
+    );
+
+    // arguments are formatted like in `concat_`
+    const S: &str = format_!("{:?} {:?}", ("a", 3u8), ["b"]);
+    assert_eq!(S, r#"("a", 3) ["b"]"#);
+}
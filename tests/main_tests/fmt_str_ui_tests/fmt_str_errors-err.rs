@@ -0,0 +1,13 @@
+fn main() {
+    let x = 3u8;
+
+    const_panic::panic!("{x:e}");
+
+    const_panic::panic!("{} {x:>1$}", x, 8);
+
+    const_panic::panic!("{ {x}");
+
+    const_panic::panic!("{2}", x, x);
+
+    const_panic::panic!("{x}", name = x);
+}
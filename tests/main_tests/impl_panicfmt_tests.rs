@@ -453,3 +453,73 @@ impl AttrsEnum {
         )
     }
 }
+
+#[test]
+fn flags_formatting() {
+    for (perms, display) in [
+        (Perms(0b101), "READ | EXEC"),
+        (Perms(0b111), "READ | WRITE | EXEC"),
+        (Perms(0b110), "WRITE | EXEC"),
+        (Perms(0x46), "WRITE | EXEC | 0x40"),
+        (Perms(0xF000_0000), "0xf0000000"),
+        (Perms(0), "0x0"),
+    ] {
+        assert_eq!(trunc_fmt!(999;FmtArg::DISPLAY; perms), display);
+        assert_eq!(
+            trunc_fmt!(999;FmtArg::DEBUG; perms),
+            *format!("Perms({})", display)
+        );
+        assert_eq!(
+            trunc_fmt!(999;FmtArg::ALT_DEBUG; perms),
+            *format!("Perms(\n    {},\n)", display)
+        );
+    }
+
+    // flags that overlap with previously written flags aren't written
+    let all = NamedPerms { bits: 0b111 };
+    assert_eq!(
+        trunc_fmt!(999;FmtArg::DEBUG; all),
+        "NamedPerms(READ | WRITE | EXEC)"
+    );
+
+    let rw = NamedPerms { bits: 0b011 };
+    assert_eq!(
+        trunc_fmt!(999;FmtArg::DEBUG; rw),
+        "NamedPerms(READ | WRITE)"
+    );
+
+    // signed integers aren't sign-extended
+    assert_eq!(
+        trunc_fmt!(999;FmtArg::DEBUG; SignedPerms(-128)),
+        "SignedPerms(HIGH)"
+    );
+    assert_eq!(
+        trunc_fmt!(999;FmtArg::DEBUG; SignedPerms(-1)),
+        "SignedPerms(LOW | HIGH | 0x7e)"
+    );
+}
+
+struct Perms(u32);
+
+const_panic::impl_panicfmt! {
+    #[pfmt(flags(READ = 1, WRITE = 2, EXEC = 4))]
+    struct Perms(u32);
+}
+
+struct NamedPerms {
+    bits: u8,
+}
+
+const_panic::impl_panicfmt! {
+    #[pfmt(flags(READ = 1, WRITE = 2, RW = 3, EXEC = 4, ALL = 7,))]
+    struct NamedPerms {
+        bits: u8,
+    }
+}
+
+struct SignedPerms(i8);
+
+const_panic::impl_panicfmt! {
+    #[pfmt(flags(LOW = 1, HIGH = i8::MIN))]
+    struct SignedPerms(i8);
+}
@@ -23,6 +23,13 @@ macro_rules! test_case {
             (FmtArg::ALT_HEX, format!("{:#X}", int)),
             (FmtArg::BIN, format!("{:b}", int)),
             (FmtArg::ALT_BIN, format!("{:#b}", int)),
+            (FmtArg::OCT, format!("{:o}", int)),
+            (FmtArg::ALT_OCT, format!("{:#o}", int)),
+            (FmtArg::LOWER_HEX, format!("{:x}", int)),
+            (FmtArg::ALT_LOWER_HEX, format!("{:#x}", int)),
+            (FmtArg::DEBUG.set_radix(16), format!("{:x}", int)),
+            (FmtArg::ALT_DEBUG.set_radix(8), format!("{:o}", int)),
+            (FmtArg::DEBUG.set_radix(2), format!("{:b}", int)),
         ] {
             let msg = || format!(
                 "string.len(): {} num: {:?} fmt_override: {:?} type: {}",
@@ -68,6 +75,45 @@ macro_rules! int_test {
     }};
 }
 
+#[test]
+fn radix_test() {
+    for radix in 2..=36u8 {
+        let fmt = FmtArg::DEBUG.set_radix(radix);
+        let mut rng_state = 0x9E37_79B9_7F4A_7C15u64;
+
+        for _ in 0..200 {
+            rng_state = rng_state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let int = rng_state >> (rng_state % 64);
+
+            let expected = format_radix(int as u128, radix);
+            assert_eq!(trunc_fmt!(1024; fmt; int), *expected, "radix: {}", radix);
+
+            let neg = -(int as i128);
+            let expected = format_radix(neg as u128, radix);
+            assert_eq!(trunc_fmt!(1024; fmt; neg), *expected, "radix: {}", radix);
+        }
+        assert_eq!(trunc_fmt!(1024; fmt; 0u8), "0");
+        assert_eq!(trunc_fmt!(1024; fmt; radix), "10");
+        let alt_fmt = fmt.set_alternate(true);
+        assert_eq!(trunc_fmt!(1024; alt_fmt; radix), "10");
+    }
+
+    assert_eq!(trunc_fmt!(1024; {radix = 36}: 1295u16), "zz");
+    assert_eq!(trunc_fmt!(1024; {radix = 3}: 100u8), "10201");
+    assert_eq!(trunc_fmt!(1024; {radix = 7}: -1i8), "513");
+}
+
+fn format_radix(mut int: u128, radix: u8) -> String {
+    let mut out = Vec::new();
+    loop {
+        out.push(std::char::from_digit((int % radix as u128) as u32, radix as u32).unwrap());
+        int /= radix as u128;
+        if int == 0 {
+            break out.iter().rev().collect();
+        }
+    }
+}
+
 #[test]
 fn integer_test() {
     int_test! {u8}
@@ -102,3 +148,24 @@ fn nonzero_integer_test() {
     test_case! {NonZeroUsize::new(55).unwrap()}
     test_case! {NonZeroIsize::new(-55).unwrap()}
 }
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn number_fmt_collections_test() {
+    let arr = [8u16, 255, 4096];
+
+    assert_eq!(
+        trunc_fmt!(1024; {#o}: arr),
+        "[\n    0o10,\n    0o377,\n    0o10000,\n]"
+    );
+    assert_eq!(trunc_fmt!(1024; {x}: &arr[..]), *format!("{:x?}", arr));
+    assert_eq!(trunc_fmt!(1024; {radix = 32}: arr), "[8, 7v, 400]");
+    assert_eq!(
+        trunc_fmt!(1024; {#x}: Some(-2i8)),
+        *format!("{:#x?}", Some(-2i8))
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {radix = 36}: Some(&arr[..])),
+        "Some([8, 73, 35s])"
+    );
+}
@@ -143,4 +143,76 @@ fn set_fmt_from_kw_test() {
         fmt.is_alternate = true;
         fmt.number_fmt = NumberFmt::Binary;
     }}
+
+    case! {lower_hex, initb, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = false;
+        fmt.number_fmt = NumberFmt::LowerHexadecimal;
+    }}
+    case! {{x}, initb, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = false;
+        fmt.number_fmt = NumberFmt::LowerHexadecimal;
+    }}
+    case! {alt_lower_hex, inita, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = true;
+        fmt.number_fmt = NumberFmt::LowerHexadecimal;
+    }}
+    case! {{#x}, inita, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = true;
+        fmt.number_fmt = NumberFmt::LowerHexadecimal;
+    }}
+
+    case! {oct, initb, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = false;
+        fmt.number_fmt = NumberFmt::Octal;
+    }}
+    case! {{o}, initb, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = false;
+        fmt.number_fmt = NumberFmt::Octal;
+    }}
+    case! {alt_oct, inita, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = true;
+        fmt.number_fmt = NumberFmt::Octal;
+    }}
+    case! {{#o}, inita, |fmt| {
+        fmt.fmt_kind = FmtKind::Debug;
+        fmt.is_alternate = true;
+        fmt.number_fmt = NumberFmt::Octal;
+    }}
+
+    case! {{radix = 3}, inita, |fmt| {
+        fmt.is_alternate = false;
+        fmt = fmt.set_radix(3);
+    }}
+}
+
+#[test]
+fn fmtarg_eq_test() {
+    // the radix is only compared when it's used
+    assert_eq!(FmtArg::DEBUG.set_radix(3).set_hex(), FmtArg::HEX);
+    assert_ne!(FmtArg::DEBUG.set_radix(3), FmtArg::DEBUG.set_radix(5));
+    assert_eq!(
+        FmtArg::DEBUG.set_radix(16).set_hex(),
+        FmtArg::DEBUG.set_radix(8).set_hex()
+    );
+
+    assert_eq!(
+        FmtArg::DISPLAY.set_precision(3).clear_flags(),
+        FmtArg::DISPLAY
+    );
+    assert_ne!(FmtArg::DISPLAY.set_precision(0), FmtArg::DISPLAY);
+    assert_ne!(
+        FmtArg::DISPLAY.set_precision(2),
+        FmtArg::DISPLAY.set_precision(3)
+    );
+
+    assert_eq!(FmtArg::DISPLAY.set_fill(' '), FmtArg::DISPLAY);
+    assert_ne!(FmtArg::DISPLAY.set_fill('-'), FmtArg::DISPLAY);
+    assert_ne!(FmtArg::DISPLAY.set_width(3), FmtArg::DISPLAY);
 }
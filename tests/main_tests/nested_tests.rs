@@ -0,0 +1,102 @@
+use const_panic::{fmt::MAX_DEPTH_LIMIT, ArrayString, FmtArg, PanicVal};
+
+const INNER: &[PanicVal<'_>] = &[
+    PanicVal::write_str("["),
+    PanicVal::from_u16(1000, FmtArg::DEBUG),
+    PanicVal::write_str(", "),
+    PanicVal::from_str("foo", FmtArg::DEBUG),
+    PanicVal::write_str("]"),
+];
+
+const OUTER: &[PanicVal<'_>] = &[
+    PanicVal::write_str("a"),
+    PanicVal::from_nested(INNER, FmtArg::DEBUG),
+    PanicVal::from_nested(&[], FmtArg::DEBUG),
+    PanicVal::write_str("b"),
+    PanicVal::from_nested(INNER, FmtArg::DEBUG),
+];
+
+#[test]
+fn nested_panicvals_test() {
+    const EXPECTED: &str = r#"a[1000, "foo"]b[1000, "foo"]"#;
+
+    assert_eq!(trunc_fmt!(1024; OUTER), EXPECTED);
+    assert_eq!(
+        ArrayString::<64>::concat_panicvals(&[OUTER, INNER]).unwrap(),
+        concat!(r#"a[1000, "foo"]b[1000, "foo"]"#, r#"[1000, "foo"]"#),
+    );
+
+    // `concat_` computes the length of the string by writing the nested `PanicVal`s
+    assert_eq!(
+        const_panic::concat_!(PanicVal::from_nested(OUTER, FmtArg::DEBUG), "c"),
+        concat!(r#"a[1000, "foo"]b[1000, "foo"]"#, "c"),
+    );
+
+    for len in 0..EXPECTED.len() {
+        overf_fmt!(len; OUTER).unwrap_err();
+    }
+    assert_eq!(overf_fmt!(EXPECTED.len(); OUTER).unwrap(), EXPECTED);
+}
+
+#[test]
+fn nested_truncation_test() {
+    for (len, expected) in [
+        (0, ""),
+        (1, "a"),
+        (2, "a["),
+        (5, "a["),
+        (6, "a[1000"),
+        (8, "a[1000, "),
+        (12, "a[1000, \"foo"),
+        (13, "a[1000, \"foo\""),
+        (14, "a[1000, \"foo\"]"),
+        (15, "a[1000, \"foo\"]b"),
+        (16, "a[1000, \"foo\"]b["),
+    ] {
+        assert_eq!(trunc_fmt!(len; OUTER), expected);
+    }
+}
+
+#[test]
+fn nested_depth_limit_test() {
+    const LEVEL3: &[PanicVal<'_>] = &[PanicVal::write_str("3")];
+    const LEVEL2: &[PanicVal<'_>] = &[
+        PanicVal::write_str("2("),
+        PanicVal::from_nested(LEVEL3, FmtArg::DEBUG),
+        PanicVal::write_str(")"),
+    ];
+    const LEVEL1: &[PanicVal<'_>] = &[
+        PanicVal::write_str("1("),
+        PanicVal::from_nested(LEVEL2, FmtArg::DEBUG),
+        PanicVal::write_str(")"),
+    ];
+
+    for (depth_limit, expected) in [
+        (0, "..."),
+        (1, "1(...)"),
+        (2, "1(2(...))"),
+        (3, "1(2(3))"),
+        (MAX_DEPTH_LIMIT, "1(2(3))"),
+    ] {
+        // the inner levels are limited by the depth limit of the outermost level
+        let fmtarg = FmtArg::DEBUG.set_depth_limit(depth_limit);
+        let level0 = PanicVal::from_nested(LEVEL1, fmtarg);
+        assert_eq!(trunc_fmt!(1024; level0), expected);
+    }
+
+    static RECURSIVE: [PanicVal<'_>; 2] = [
+        PanicVal::write_str("."),
+        PanicVal::from_nested(&RECURSIVE, FmtArg::DEBUG),
+    ];
+
+    assert_eq!(
+        trunc_fmt!(1024; RECURSIVE),
+        *format!(".{}...", ".".repeat(MAX_DEPTH_LIMIT as usize)),
+    );
+}
+
+#[test]
+#[should_panic]
+fn depth_limit_above_max_test() {
+    let _ = FmtArg::DEBUG.set_depth_limit(MAX_DEPTH_LIMIT + 1);
+}
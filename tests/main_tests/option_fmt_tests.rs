@@ -102,3 +102,127 @@ fn test_option_fmt() {
         )
     }
 }
+
+#[derive(Debug, Copy, Clone)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+const_panic::impl_panicfmt! {
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum MyError {
+    TooLarge { max: u32 },
+    Empty,
+}
+
+const_panic::impl_panicfmt! {
+    enum MyError {
+        TooLarge { max: u32 },
+        Empty,
+    }
+}
+
+#[test]
+fn custom_type_option_test() {
+    use const_panic::option_panicvals;
+
+    for opt in [Some(Point { x: 1, y: 2 }), None] {
+        for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+            let expected = if fmtarg.is_alternate {
+                format!("{:#?}", opt)
+            } else {
+                format!("{:?}", opt)
+            };
+
+            assert_eq!(
+                trunc_fmt!(1024; option_panicvals!(fmtarg; Option<Point> => opt)),
+                *expected,
+            );
+        }
+    }
+
+    assert_eq!(
+        trunc_fmt!(1024; option_panicvals!(FmtArg::DEBUG; Option<Point> => Some(Point { x: 1, y: 2 }))),
+        "Some(Point { x: 1, y: 2 })",
+    );
+    assert_eq!(
+        trunc_fmt!(1024; option_panicvals!(FmtArg::HEX; Option<Point> => &Some(Point { x: 10, y: 11 }))),
+        "Some(Point { x: A, y: B })",
+    );
+
+    let pvs = option_panicvals!(FmtArg::DEBUG; Option<Point> => None::<Point>);
+    assert_eq!(
+        pvs.len(),
+        <Option<Point> as const_panic::PanicFmt>::PV_COUNT
+    );
+}
+
+#[test]
+fn custom_type_result_test() {
+    use const_panic::result_panicvals;
+
+    for res in [
+        Ok(3),
+        Err(MyError::TooLarge { max: 5 }),
+        Err(MyError::Empty),
+    ] {
+        for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+            let expected = if fmtarg.is_alternate {
+                format!("{:#?}", res)
+            } else {
+                format!("{:?}", res)
+            };
+
+            assert_eq!(
+                trunc_fmt!(1024; result_panicvals!(fmtarg; Result<u32, MyError> => res)),
+                *expected,
+            );
+        }
+    }
+
+    assert_eq!(
+        trunc_fmt!(1024; result_panicvals!(FmtArg::DEBUG; Result<u32, MyError> => Err::<u32, _>(MyError::TooLarge { max: 5 }))),
+        "Err(TooLarge { max: 5 })",
+    );
+
+    let ok: Result<Point, &str> = Ok(Point { x: 1, y: 2 });
+    assert_eq!(
+        trunc_fmt!(1024; result_panicvals!(FmtArg::DEBUG; Result<Point, &'_ str> => ok)),
+        "Ok(Point { x: 1, y: 2 })",
+    );
+    let err: Result<Point, &str> = Err("nope");
+    assert_eq!(
+        trunc_fmt!(1024; result_panicvals!(FmtArg::DEBUG; Result<Point, &'_ str> => err)),
+        "Err(\"nope\")",
+    );
+
+    // the length is enough for the larger of the two payloads
+    let res: Result<u32, Point> = Ok(3);
+    let pvs = result_panicvals!(FmtArg::DEBUG; Result<u32, Point> => res);
+    assert_eq!(pvs.len(), 4 + <Point as const_panic::PanicFmt>::PV_COUNT);
+}
+
+#[test]
+fn custom_type_option_result_const_test() {
+    use const_panic::{concat_, option_panicvals, result_panicvals};
+
+    const OPT: Option<Point> = Some(Point { x: 1, y: 2 });
+    const RES: Result<u32, MyError> = Err(MyError::TooLarge { max: 5 });
+
+    assert_eq!(
+        concat_!(
+            "opt: ",
+            option_panicvals!(FmtArg::DEBUG; Option<Point> => OPT),
+            " res: ",
+            result_panicvals!(FmtArg::DEBUG; Result<u32, MyError> => RES),
+        ),
+        "opt: Some(Point { x: 1, y: 2 }) res: Err(TooLarge { max: 5 })",
+    );
+}
@@ -1,8 +1,9 @@
-use const_panic::{FmtArg, StdWrapper};
+use const_panic::{concat_, fmt::RangeFmt, option_panicvals, FmtArg, StdWrapper};
 
 use core::{
     cmp::Ordering,
     marker::{PhantomData, PhantomPinned},
+    ops::Range,
     ptr::NonNull,
     sync::atomic::Ordering as AtomicOrdering,
 };
@@ -67,4 +68,284 @@ fn fmt_range() {
     test_vals! {3..=5, "3..=5"}
     test_vals! {..5, "..5"}
     test_vals! {..=5, "..=5"}
+
+    // unsuffixed ranges are inferred to be `usize` ranges
+    assert_eq!(trunc_fmt!(1024; 3..5), "3..5");
+    assert_eq!(trunc_fmt!(1024; Some(3..=5)), "Some(3..=5)");
+    assert_eq!(concat_!("range: ", ..=5, " ", 8..), "range: ..=5 8..");
+}
+
+#[test]
+fn fmt_range_all_types() {
+    macro_rules! test_range_types {
+        ($($ty:ty => $start:expr, $end:expr;)*) => ($({
+            let (start, end): ($ty, $ty) = ($start, $end);
+
+            test_range_std!{start..end, start.., ..end, start..=end, ..=end}
+
+            let opt = Some(RangeFmt(start..end));
+            for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+                let expected = if fmtarg.is_alternate {
+                    format!("{:#?}", opt.clone().map(|x| x.0))
+                } else {
+                    format!("{:?}", opt.clone().map(|x| x.0))
+                };
+
+                assert_eq!(
+                    trunc_fmt!(1024;
+                        option_panicvals!(fmtarg; Option<RangeFmt<Range<$ty>>> => opt.clone())
+                    ),
+                    *expected,
+                );
+            }
+        })*)
+    }
+
+    macro_rules! test_range_std {
+        ($($range:expr),*) => ($({
+            let range = $range;
+
+            assert_eq!(trunc_fmt!(1024; RangeFmt(range.clone())), *format!("{:?}", range));
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&range).to_panicvals(FmtArg::DEBUG)),
+                *format!("{:?}", range),
+            );
+        })*)
+    }
+
+    test_range_types! {
+        u8 => 0, 255;
+        u16 => 3, 1000;
+        u32 => 3, u32::MAX;
+        u64 => 3, u64::MAX;
+        u128 => 3, u128::MAX;
+        usize => 3, usize::MAX;
+        i8 => -128, 127;
+        i16 => -3, 1000;
+        i32 => i32::MIN, 5;
+        i64 => -3, i64::MAX;
+        i128 => i128::MIN, i128::MAX;
+        isize => -3, 5;
+        char => 'a', 'z';
+    }
+
+    test_vals! {None::<Range<usize>>, "None"}
+    test_vals! {None::<core::ops::RangeFull>, "None"}
+    assert_eq!(trunc_fmt!(1024; Some(..)), "Some(..)");
+}
+
+#[test]
+fn fmt_range_number_formats() {
+    let range = &RangeFmt(10u8..=255);
+
+    assert_eq!(trunc_fmt!(1024; {X}: range), *format!("{:X?}", range.0));
+    assert_eq!(trunc_fmt!(1024; {#X}: range), *format!("{:#X?}", range.0));
+    assert_eq!(trunc_fmt!(1024; {#x}: range), "0xa..=0xff");
+    assert_eq!(trunc_fmt!(1024; {b}: range), "1010..=11111111");
+    assert_eq!(trunc_fmt!(1024; {#b}: range), "0b1010..=0b11111111");
+    assert_eq!(trunc_fmt!(1024; {#X}: RangeFmt(-1i8..3)), "0xFF..0x3");
+    assert_eq!(trunc_fmt!(1024; {b}: Some(0..2)), "Some(0..10)");
+    assert_eq!(
+        trunc_fmt!(1024; {#b}: Some(0..2)),
+        "Some(\n    0b0..0b10,\n)",
+    );
+
+    assert_eq!(
+        trunc_fmt!(1024; "value ", 300u16, " out of allowed range ", RangeFmt(0u16..=255)),
+        "value 300 out of allowed range 0..=255",
+    );
+}
+
+#[test]
+fn fmt_range_slices() {
+    macro_rules! test_range_slice {
+        ($($slice:expr),* $(,)?) => ($({
+            let slice = $slice;
+
+            assert_eq!(trunc_fmt!(1024; slice), *format!("{:?}", slice));
+            assert_eq!(trunc_fmt!(1024; {#?}: slice), *format!("{:#?}", slice));
+            assert_eq!(trunc_fmt!(1024; &slice[..]), *format!("{:?}", &slice[..]));
+            assert_eq!(trunc_fmt!(1024; {#?}: &slice[..]), *format!("{:#?}", &slice[..]));
+        })*)
+    }
+
+    test_range_slice! {
+        [0u8..1, 3..5, 8..255],
+        [0u8..=1, 3..=5],
+        [-3i64..0],
+        [0usize..=0],
+        ['a'..'z', 'A'..'Z'],
+        ['0'..='9'],
+        [0u128..1; 0],
+        [0i32..=1; 0],
+    }
+
+    let hex = [10u8..11, 12..13];
+    assert_eq!(trunc_fmt!(1024; {X}: hex), *format!("{:X?}", hex));
+    assert_eq!(trunc_fmt!(1024; {#X}: hex), *format!("{:#X?}", hex));
+}
+
+#[test]
+fn fmt_duration() {
+    use const_panic::fmt::Alignment;
+    use core::time::Duration;
+
+    let durations = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_000),
+        Duration::from_nanos(3_000_001),
+        Duration::from_nanos(1_999_999),
+        Duration::from_nanos(999_999_999),
+        Duration::from_micros(250),
+        Duration::from_millis(250),
+        Duration::from_millis(1_500),
+        Duration::new(1, 1),
+        Duration::new(59, 999_500_000),
+        Duration::from_nanos(2_500_000),
+        Duration::from_nanos(3_500_000),
+        Duration::from_nanos(63_945),
+        Duration::from_nanos(63_955),
+        Duration::from_nanos(241_988_500),
+        Duration::new(u64::MAX, 999_999_999),
+        Duration::MAX,
+    ];
+
+    for dur in durations {
+        assert_eq!(trunc_fmt!(1024; dur), *format!("{:?}", dur));
+        assert_eq!(trunc_fmt!(1024; {#?}: dur), *format!("{:#?}", dur));
+        assert_eq!(trunc_fmt!(1024; {+}: dur), *format!("{:+?}", dur));
+
+        for precision in 0..=12u8 {
+            let fmtarg = FmtArg::DEBUG.set_precision(precision);
+            let p = precision as usize;
+            assert_eq!(
+                trunc_fmt!(1024; fmtarg; dur),
+                *format!("{:.*?}", p, dur),
+                "precision: {}",
+                precision,
+            );
+        }
+
+        for width in [0u8, 5, 12, 30] {
+            let w = width as usize;
+            let fmtarg = FmtArg::DEBUG.set_width(width);
+            assert_eq!(
+                trunc_fmt!(1024; fmtarg; dur),
+                *format!("{:w$?}", dur, w = w)
+            );
+
+            let fmtarg = fmtarg.set_precision(11).set_sign_plus(true);
+            assert_eq!(
+                trunc_fmt!(1024; fmtarg; dur),
+                *format!("{:+w$.11?}", dur, w = w)
+            );
+
+            let fmtarg = fmtarg.set_alignment(Alignment::Right).set_fill('-');
+            assert_eq!(
+                trunc_fmt!(1024; fmtarg; dur),
+                *format!("{:->+w$.11?}", dur, w = w)
+            );
+
+            let fmtarg = FmtArg::DEBUG
+                .set_width(width)
+                .set_fill('é')
+                .set_alignment(Alignment::Center);
+            assert_eq!(
+                trunc_fmt!(1024; fmtarg; dur),
+                *format!("{:é^w$?}", dur, w = w)
+            );
+        }
+    }
+
+    // ties are rounded to the even digit
+    assert_eq!(
+        trunc_fmt!(1024; {.3}: Duration::from_nanos(241_988_500)),
+        "241.988ms"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {.3}: Duration::from_nanos(241_987_500)),
+        "241.988ms"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {.2}: Duration::from_nanos(63_945)),
+        "63.94µs"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {.2}: Duration::from_nanos(63_955)),
+        "63.96µs"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {.0}: Duration::from_nanos(2_500_000)),
+        "2ms"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {.0}: Duration::from_nanos(3_500_000)),
+        "4ms"
+    );
+}
+
+macro_rules! test_wrapper {
+    ($($value:expr),* $(,)?) => ({$({
+        let val = $value;
+        assert_eq!(trunc_fmt!(1024; val), *format!("{:?}", val));
+        assert_eq!(trunc_fmt!(1024; {#?}: val), *format!("{:#?}", val));
+        assert_eq!(trunc_fmt!(1024; {X}: val), *format!("{:X?}", val));
+    })*})
+}
+
+#[test]
+fn fmt_wrapper_types() {
+    use core::{
+        cmp::Reverse,
+        mem::ManuallyDrop,
+        num::Wrapping,
+        ops::{Bound, ControlFlow},
+        task::Poll,
+    };
+
+    test_wrapper! {
+        Wrapping(255u8),
+        Wrapping(-3i64),
+        Wrapping("hello"),
+        Reverse(3u8),
+        Reverse('c'),
+        Reverse(()),
+        Bound::Included(3u16),
+        Bound::Excluded(-5i8),
+        Bound::<u128>::Unbounded,
+        Bound::Included("a\nb"),
+        ControlFlow::<u8, &str>::Break(10),
+        ControlFlow::<u8, &str>::Continue("hello"),
+        ControlFlow::<(), bool>::Continue(true),
+        ControlFlow::<(), bool>::Break(()),
+        Poll::Ready(0.5f64),
+        Poll::<bool>::Pending,
+    }
+
+    // std's output for `ManuallyDrop` changed between versions,
+    // so this compares with the output that it had for most of its history
+    assert_eq!(
+        trunc_fmt!(1024; ManuallyDrop::new(100u32)),
+        "ManuallyDrop { value: 100 }"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; {#?}: ManuallyDrop::new("hello")),
+        "ManuallyDrop {\n    value: \"hello\",\n}"
+    );
+
+    // these only output the wrapped value, like in std
+    assert_eq!(trunc_fmt!(1024; display: Wrapping(255u8)), "255");
+    assert_eq!(trunc_fmt!(1024; display: Wrapping("hello")), "hello");
+    assert_eq!(trunc_fmt!(1024; {X}: Wrapping(255u8)), "FF");
+
+    #[cfg(feature = "rust_1_82")]
+    {
+        use core::num::Saturating;
+
+        test_wrapper! {Saturating(10u8), Saturating(-10i32)}
+
+        assert_eq!(trunc_fmt!(1024; display: Saturating(-10i32)), "-10");
+    }
 }
@@ -0,0 +1,366 @@
+use const_panic::{
+    fmt::{Alignment, FmtArg},
+    PanicVal,
+};
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+// compares the `{...}:` formatting override with the equivalent std formatting string
+macro_rules! spec_test {
+    ($value:expr; $({$($spec:tt)*} $std_fmt:literal)*) => {{
+        let val = $value;
+        $({
+            let expected = format!($std_fmt, val);
+            let msg = || format!("spec: {:?} value: {:?}", stringify!($($spec)*), val);
+
+            assert_eq!(
+                overf_fmt!(expected.len(); {$($spec)*}: val).unwrap(),
+                *expected,
+                "{}",
+                msg(),
+            );
+            overf_fmt!(expected.len() - 1; {$($spec)*}: val).unwrap_err();
+        })*
+    }};
+}
+
+#[test]
+fn integer_padding_test() {
+    macro_rules! int_cases {
+        ($($value:expr),* $(,)?) => {$(
+            spec_test! {
+                $value;
+                {1} "{:1}" {8} "{:8}" {>8} "{:>8}" {<8} "{:<8}" {^8} "{:^8}" {^9} "{:^9}"
+                {08} "{:08}" {<08} "{:<08}" {^08} "{:^08}" {*^10} "{:*^10}" {x>8} "{:x>8}"
+                {-<6} "{:-<6}" {é^7} "{:é^7}"
+                {08?} "{:08?}" {8?} "{:8?}" {#010x} "{:#010x}" {08X} "{:08X}" {#o} "{:#o}"
+                {#012o} "{:#012o}" {#010b} "{:#010b}" {_>12x?} "{:_>12x?}" {#14X?} "{:#14X?}"
+                {010b} "{:010b}"
+                {40} "{:40}" {#0200b} "{:#0200b}" {#^255X} "{:#^255X}"
+            }
+        )*};
+    }
+
+    int_cases! {
+        0u8, 5u8, 255u8, -1i8, i8::MIN,
+        5i32, -5i32, 1234567i32,
+        u64::MAX, i64::MIN,
+        u128::MAX, i128::MIN, -1i128,
+        usize::MAX, -100isize,
+    }
+}
+
+#[test]
+fn string_padding_test() {
+    for string in ["a", "ab", "héllo", "hello world", "\n\"\u{1F600}"] {
+        spec_test! {
+            string;
+            {8} "{:8}" {>8} "{:>8}" {<8} "{:<8}" {^9} "{:^9}" {^10} "{:^10}" {*^9} "{:*^9}"
+            {08} "{:08}" {é>7} "{:é>7}" {.2} "{:.2}" {^9.1} "{:^9.1}" {>12.3} "{:>12.3}"
+            {.100} "{:.100}"
+            {?} "{:?}" {>20?} "{:>20?}" {020?} "{:020?}" {.2?} "{:.2?}"
+        }
+    }
+}
+
+#[test]
+fn char_padding_test() {
+    for c in ['c', 'é', '\u{1F600}', '\n', '\''] {
+        spec_test! {
+            c;
+            {1} "{:1}" {5} "{:5}" {>5} "{:>5}" {^5} "{:^5}" {^6} "{:^6}" {05} "{:05}"
+            {*<3} "{:*<3}" {.1} "{:.1}" {>4.3} "{:>4.3}" {5?} "{:5?}" {05?} "{:05?}"
+                                    {.0?} "{:.0?}"
+        }
+    }
+
+    assert_eq!(trunc_fmt!(16; {.0}: 'c'), "");
+    assert_eq!(trunc_fmt!(16; {>3.0}: 'c'), "   ");
+}
+
+#[test]
+fn bool_padding_test() {
+    for b in [false, true] {
+        spec_test! {
+            b;
+            {7} "{:7}" {>7} "{:>7}" {^8} "{:^8}" {-^8} "{:-^8}" {07} "{:07}" {.2} "{:.2}"
+            {>6.1} "{:>6.1}" {>7?} "{:>7?}" {.3?} "{:.3?}"
+        }
+    }
+}
+
+#[test]
+fn float_padding_test() {
+    macro_rules! float_cases {
+        ($($value:expr),* $(,)?) => {
+            for float in [$($value),*] {
+                spec_test! {
+                    float;
+                    {10} "{:10}" {<10} "{:<10}" {^12} "{:^12}" {*>12} "{:*>12}" {010} "{:010}"
+                    {010?} "{:010?}" {12?} "{:12?}" {#?} "{:#?}"
+                    {.0} "{:.0}" {.1} "{:.1}" {.3} "{:.3}" {.20} "{:.20}" {010.3} "{:010.3}"
+                    {*>12.2} "{:*>12.2}" {^15.5} "{:^15.5}" {.2?} "{:.2?}" {#.2?} "{:#.2?}"
+        {.0?} "{:.0?}"
+                    {0100.30} "{:0100.30}"
+                }
+            }
+        };
+    }
+
+    float_cases! {
+        1.5f64, -1.5, 0.0, -0.0, 2.5, 0.125, 0.5, -0.05,
+        1e20, 1e-7, 123.456, 9.999, 0.999, 99.5,
+        f64::NAN, f64::INFINITY, f64::NEG_INFINITY,
+        f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::EPSILON, f64::from_bits(1),
+    }
+    float_cases! {
+        0.1f32, -2.5, 1e30, f32::MAX, f32::MIN_POSITIVE, f32::from_bits(1),
+        f32::NAN, f32::NEG_INFINITY,
+    }
+}
+
+#[test]
+fn float_precision_test() {
+    macro_rules! case {
+        ($float:expr, $precision:expr) => {{
+            let float = $float;
+            let precision: u8 = $precision;
+            let fmtarg = FmtArg::DISPLAY.set_precision(precision);
+            let debug_fmtarg = fmtarg.set_debug();
+
+            let expected = format!("{:.*}", precision as usize, float);
+            let msg = || format!("float: {:?} precision: {}", float, precision);
+
+            assert_eq!(
+                overf_fmt!(expected.len(); fmtarg; float).unwrap(),
+                *expected,
+                "{}",
+                msg(),
+            );
+            assert_eq!(
+                trunc_fmt!(expected.len(); debug_fmtarg; float),
+                *expected,
+                "{}",
+                msg(),
+            );
+            overf_fmt!(expected.len() - 1; fmtarg; float).unwrap_err();
+        }};
+    }
+
+    for float in [f64::MAX, f64::MIN_POSITIVE, f64::from_bits(1), -1e300, 0.3] {
+        for precision in [0, 1, 17, 100, 254, 255] {
+            case! {float, precision}
+        }
+    }
+
+    let mut rng = SmallRng::seed_from_u64(6543210987123456789);
+    for _ in 0..1000 {
+        let precision = rng.gen_range(0..=30);
+        case! {f64::from_bits(rng.gen::<u64>()), precision}
+        case! {f32::from_bits(rng.gen::<u32>()), precision}
+    }
+
+    for _ in 0..1000 {
+        let precision = rng.gen_range(0..=10);
+        case! {rng.gen_range(-1000.0..1000.0f64), precision}
+        case! {rng.gen_range(-1000.0..1000.0f32), precision}
+    }
+}
+
+#[test]
+fn fmtarg_padding_test() {
+    const WIDE: FmtArg = FmtArg::DISPLAY.set_width(4);
+    const CENTER: FmtArg = WIDE.set_fill('-').set_alignment(Alignment::Center);
+    const ZEROED: FmtArg = FmtArg::DEBUG.set_width(6).set_zero_pad(true);
+    const PRECISE: FmtArg = FmtArg::DISPLAY.set_precision(2);
+    const NUM: u32 = 7;
+    const FLOAT: f32 = 0.5;
+    const STR: &str = "abcd";
+    const CHAR: char = 'c';
+
+    assert_eq!(
+        trunc_fmt!(1024; WIDE; NUM, "|", STR, "|", CHAR),
+        "   7|abcd|c   "
+    );
+    assert_eq!(trunc_fmt!(1024; CENTER; NUM, "|", true), "-7--|true");
+    assert_eq!(
+        trunc_fmt!(1024; ZEROED; NUM, " ", FLOAT, " ", STR),
+        "000007 0000.5 \"abcd\""
+    );
+    assert_eq!(
+        trunc_fmt!(1024; PRECISE; NUM, " ", FLOAT, " ", STR),
+        "7 0.50 ab"
+    );
+
+    // keyword formatting overrides (including `{}`, `{#x}`, and `{X}`)
+    // keep the padding and precision, while the other `{...}` overrides replace them.
+    assert_eq!(
+        trunc_fmt!(1024; WIDE; NUM, display: NUM, debug: NUM, hex: NUM, _: NUM),
+        "   7   7   7   7   7",
+    );
+    assert_eq!(
+        trunc_fmt!(1024; (FmtArg::DISPLAY.set_width(8)); hex: 255u8, {#x}: 255u8, {}: 255u8),
+        "      FF    0xff     255",
+    );
+    assert_eq!(trunc_fmt!(1024; WIDE; {<}: NUM, {X}: NUM), "7   7");
+    assert_eq!(
+        trunc_fmt!(1024; PRECISE; display: STR, {.3}: STR, {}: STR),
+        "ababcab"
+    );
+
+    // literals are never padded nor truncated
+    assert_eq!(trunc_fmt!(1024; WIDE; "a", NUM, "bc"), "a   7bc");
+    assert_eq!(trunc_fmt!(1024; PRECISE; "abc"), "abc");
+
+    // padding with multi-byte chars
+    let pv = PanicVal::from_str("ab", FmtArg::DISPLAY.set_width(5).set_fill('é'));
+    assert_eq!(trunc_fmt!(1024; pv), "abééé");
+    assert_eq!(pv.leftpad(), 0);
+    assert_eq!(pv.rightpad(), 3);
+
+    let pv = PanicVal::from_u8(3, CENTER.set_fill('\u{1F600}'));
+    assert_eq!(pv.leftpad(), 1);
+    assert_eq!(pv.rightpad(), 2);
+    assert_eq!(trunc_fmt!(1024; pv), "\u{1F600}3\u{1F600}\u{1F600}");
+
+    // truncation never splits the fill char
+    for (len, expected) in [
+        (0, ""),
+        (3, ""),
+        (4, "\u{1F600}"),
+        (5, "\u{1F600}3"),
+        (8, "\u{1F600}3"),
+        (9, "\u{1F600}3\u{1F600}"),
+        (13, "\u{1F600}3\u{1F600}\u{1F600}"),
+    ] {
+        assert_eq!(trunc_fmt!(len; pv), expected, "len: {}", len);
+    }
+}
+
+#[test]
+fn sign_plus_test() {
+    macro_rules! int_cases {
+        ($($value:expr),* $(,)?) => {$(
+            spec_test! {
+                $value;
+                {+} "{:+}" {+?} "{:+?}" {+08} "{:+08}" {+>8} "{:+>8}" {*^+9} "{:*^+9}"
+                {+x} "{:+x}" {+#x} "{:+#x}" {+#010b} "{:+#010b}" {+o} "{:+o}"
+            }
+        )*};
+    }
+
+    int_cases! {0u8, 255u8, -1i8, i8::MIN, 1234i32, -1234i32, u128::MAX, i128::MIN}
+
+    for float in [
+        0.0f64,
+        -0.0,
+        1.5,
+        -1.5,
+        1.25,
+        1e20,
+        1e-7,
+        f64::NAN,
+        f64::INFINITY,
+    ] {
+        spec_test! {
+            float;
+            {+} "{:+}" {+?} "{:+?}" {+.1} "{:+.1}" {+010.2} "{:+010.2}" {+^12} "{:+^12}"
+        }
+    }
+}
+
+#[test]
+fn digit_grouping_test() {
+    macro_rules! case {
+        ($fmtarg:expr; $value:expr, $expected:expr) => {{
+            let fmtarg: FmtArg = $fmtarg;
+            let value = $value;
+            let expected: &str = $expected;
+            let msg = || format!("value: {:?} expected: {:?}", value, expected);
+
+            assert_eq!(
+                overf_fmt!(expected.len(); fmtarg; value).unwrap(),
+                expected,
+                "{}",
+                msg()
+            );
+            overf_fmt!(expected.len() - 1; fmtarg; value).unwrap_err();
+        }};
+    }
+
+    const COMMAS: FmtArg = FmtArg::DISPLAY.set_digit_separator(',');
+    const UNDERSCORES: FmtArg = FmtArg::DISPLAY.set_digit_separator('_');
+
+    case! {COMMAS; 0u8, "0"}
+    case! {COMMAS; 999u16, "999"}
+    case! {COMMAS; 1000u16, "1,000"}
+    case! {COMMAS; -1000i16, "-1,000"}
+    case! {COMMAS; 1000000u32, "1,000,000"}
+    case! {COMMAS.set_sign_plus(true); 1000000u32, "+1,000,000"}
+    case! {COMMAS; u64::MAX, "18,446,744,073,709,551,615"}
+    case! {COMMAS; u128::MAX, "340,282,366,920,938,463,463,374,607,431,768,211,455"}
+    case! {
+        UNDERSCORES; i128::MIN,
+        "-170_141_183_460_469_231_731_687_303_715_884_105_728"
+    }
+
+    // zero-padding adds leading zeroes, which are grouped like the other digits
+    case! {COMMAS.set_width(8).set_zero_pad(true); 1000u32, "0,001,000"}
+    case! {COMMAS.set_width(7).set_zero_pad(true); -5i32, "-00,005"}
+    case! {COMMAS.set_width(8).set_alignment(Alignment::Left); 1000u32, "1,000   "}
+
+    // hexadecimal and binary use groups of 4 digits by default
+    case! {FmtArg::ALT_HEX.set_digit_separator('_'); 0xDEAD_BEEFu32, "0xDEAD_BEEF"}
+    case! {FmtArg::HEX.set_digit_separator('_'); -1i32, "FFFF_FFFF"}
+    case! {FmtArg::ALT_BIN.set_digit_separator('_'); 0b101_0101u8, "0b101_0101"}
+    case! {FmtArg::ALT_OCT.set_digit_separator('_'); 0o7_654_321u32, "0o7_654_321"}
+
+    const BYTES: FmtArg = FmtArg::ALT_BIN
+        .set_digit_grouping('_', 8)
+        .set_zero_pad(true);
+    case! {BYTES.set_width(19); 0b1010_1010u16, "0b00000000_10101010"}
+    case! {BYTES.set_width(21); 0b1010_1010u16, "0b0_00000000_10101010"}
+
+    // custom group lengths and multi-byte separators
+    case! {FmtArg::DISPLAY.set_digit_grouping(' ', 2); 1234567u32, "1 23 45 67"}
+    case! {FmtArg::DISPLAY.set_digit_grouping('é', 3); 1234567u32, "1é234é567"}
+    case! {
+        FmtArg::ALT_BIN.set_sign_plus(true).set_digit_grouping('\u{1F600}', 1); u128::MAX,
+        &format!("+0b1{}", "\u{1F600}1".repeat(127))
+    }
+
+    // padding counts chars, not bytes
+    case! {
+        FmtArg::DISPLAY.set_digit_grouping('é', 3).set_width(10); 1234567u32,
+        " 1é234é567"
+    }
+
+    // formatting overrides
+    assert_eq!(trunc_fmt!(1024; {,}: 1234567u32), "1,234,567");
+    assert_eq!(trunc_fmt!(1024; {_}: 1234567u32), "1_234_567");
+    assert_eq!(trunc_fmt!(1024; {+010,}: 1234u32), "+0,001,234");
+    assert_eq!(trunc_fmt!(1024; {#_X}: 0xDEAD_BEEFu32), "0xDEAD_BEEF");
+    assert_eq!(trunc_fmt!(1024; {#012_b}: 5u8), "0b0_0000_0101");
+    assert_eq!(trunc_fmt!(1024; {>12_x}: 0xABCDEFu32), "     ab_cdef");
+    assert_eq!(trunc_fmt!(1024; {,}: 1234.5f64), "1234.5");
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn collection_padding_test() {
+    let ints = [1u8, 20, 255];
+    let floats = [0.5f64, -1.0];
+    let strings = ["a", "bc"];
+
+    spec_test! {ints; {>4?} "{:>4?}" {04?} "{:04?}" {#>4?} "{:#>4?}" {#4?} "{:#4?}"
+    {<4x?} "{:<4x?}" {#06x?} "{:#06x?}" {^5?} "{:^5?}" {#^5?} "{:#^5?}"}
+    spec_test! {floats; {8?} "{:8?}" {08.2?} "{:08.2?}" {#8.1?} "{:#8.1?}"}
+    spec_test! {strings; {8?} "{:8?}" {#8?} "{:#8?}"}
+    spec_test! {Some(5u8); {4?} "{:4?}" {#4?} "{:#4?}"}
+
+    // the sign and digit grouping flags are stored in the `PackedFmtArg` of slices
+    assert_eq!(trunc_fmt!(1024; {+}: ints), "[+1, +20, +255]");
+    assert_eq!(trunc_fmt!(1024; {_}: [1000u16, 65535]), "[1_000, 65_535]");
+    const PAIRS: FmtArg = FmtArg::DEBUG.set_digit_grouping('\'', 2);
+    assert_eq!(trunc_fmt!(1024; PAIRS; [1000u16]), "[10'00]");
+}
@@ -0,0 +1,3 @@
+const _: () = const_panic::concat_panic!({:>8}: 10u8);
+
+fn main() {}
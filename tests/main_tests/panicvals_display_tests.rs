@@ -0,0 +1,119 @@
+use const_panic::{
+    fmt::{Alignment, FmtArg},
+    for_tests::format_panic_message,
+    PanicVal, PanicValsDisplay, StdWrapper,
+};
+
+// asserts that `PanicValsDisplay` writes the same text as panicking with the args does
+#[track_caller]
+fn assert_same_as_panic(args: &[&[PanicVal<'_>]]) {
+    let expected = format_panic_message::<4096>(args, 4096, 4096).unwrap();
+    let display = PanicValsDisplay(args);
+
+    assert_eq!(display.to_string(), expected.as_str());
+    assert_eq!(format!("{:?}", display), expected.as_str());
+}
+
+#[test]
+fn strings_test() {
+    let unicode = "ñé€\u{1F600}\n\t\"\\\u{7f}\u{80}";
+
+    for prefix_len in 0..80 {
+        let string = format!("{}{}", "a".repeat(prefix_len), unicode.repeat(3));
+
+        for fmtarg in [
+            FmtArg::DISPLAY,
+            FmtArg::DEBUG,
+            FmtArg::DISPLAY.set_width(120).set_fill('é'),
+            FmtArg::DEBUG
+                .set_width(150)
+                .set_alignment(Alignment::Center)
+                .set_fill('\u{1F600}'),
+            FmtArg::DISPLAY.set_precision(7),
+        ] {
+            assert_same_as_panic(&[&[PanicVal::from_str(&string, fmtarg)]]);
+            assert_same_as_panic(&[
+                &[PanicVal::write_str(&string[..prefix_len])],
+                &[],
+                &[
+                    PanicVal::from_str(unicode, fmtarg),
+                    PanicVal::from_char('\u{1F600}', fmtarg),
+                ],
+            ]);
+        }
+    }
+}
+
+#[test]
+fn numbers_test() {
+    for fmtarg in [
+        FmtArg::DISPLAY,
+        FmtArg::DEBUG.set_hex(),
+        FmtArg::ALT_DEBUG.set_bin(),
+        FmtArg::DISPLAY.set_digit_separator('\u{1F600}'),
+        FmtArg::DISPLAY.set_width(50).set_zero_pad(true),
+    ] {
+        assert_same_as_panic(&[&[
+            StdWrapper(&u128::MAX).to_panicval(fmtarg),
+            PanicVal::write_str(" "),
+            StdWrapper(&i128::MIN).to_panicval(fmtarg),
+            PanicVal::write_str(" "),
+            StdWrapper(&-1.5e-7f64).to_panicval(fmtarg),
+            StdWrapper(&true).to_panicval(fmtarg),
+        ]]);
+    }
+}
+
+#[test]
+fn no_length_limit_test() {
+    let long = "hello\u{1F600}".repeat(10_000);
+    let display = PanicValsDisplay(&[&[PanicVal::write_str(&long)]]);
+
+    assert_eq!(display.to_string(), long);
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn panicvals_display_macro_test() {
+    let value = Point {
+        x: 3,
+        name: "foo\n",
+        next: Some(5),
+    };
+
+    assert_eq!(
+        format!("{}", const_panic::panicvals_display!(FmtArg::DEBUG; value)),
+        format!("{:?}", value),
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            const_panic::panicvals_display!(FmtArg::ALT_DEBUG; value)
+        ),
+        format!("{:#?}", value),
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            const_panic::panicvals_display!(FmtArg::DISPLAY; "foo\n")
+        ),
+        "foo\n",
+    );
+}
+
+#[cfg(feature = "non_basic")]
+#[derive(Debug)]
+struct Point {
+    x: u8,
+    name: &'static str,
+    next: Option<u16>,
+}
+
+#[cfg(feature = "non_basic")]
+const_panic::impl_panicfmt! {
+    struct Point {
+        x: u8,
+        name: &'static str,
+        next: Option<u16>,
+    }
+}
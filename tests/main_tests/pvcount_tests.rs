@@ -1,4 +1,4 @@
-use const_panic::{ComputePvCount, TypeDelim};
+use const_panic::{ComputePvCount, PanicFmt, TypeDelim};
 
 #[test]
 fn compute_pvcount_test() {
@@ -23,3 +23,20 @@ fn compute_pvcount_test() {
         );
     }
 }
+
+#[test]
+fn tuple_pvcount_test() {
+    assert_eq!(<(u8,)>::PV_COUNT, 5);
+    assert_eq!(<(u8, &str)>::PV_COUNT, 7);
+    assert_eq!(<(u8, Option<u8>)>::PV_COUNT, 11);
+    assert_eq!(<(u8, (u8, u8))>::PV_COUNT, 13);
+    assert_eq!(
+        <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>::PV_COUNT,
+        ComputePvCount {
+            field_amount: 12,
+            summed_pv_count: 12,
+            delimiter: TypeDelim::Tupled,
+        }
+        .call()
+    );
+}
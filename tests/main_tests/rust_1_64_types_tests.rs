@@ -12,3 +12,45 @@ fn test_utf8_error() {
     test_val!(has_no_error_len, no_alternate);
     test_val!(has_error_len, no_alternate);
 }
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn test_result_fmt() {
+    macro_rules! test_case {
+        ($expr:expr) => {{
+            let res = $expr;
+            assert_eq!(trunc_fmt!(1024; FmtArg::DEBUG; res), *format!("{:?}", res));
+            assert_eq!(trunc_fmt!(1024; FmtArg::ALT_DEBUG; res), *format!("{:#?}", res));
+        }};
+    }
+
+    test_case! {Ok::<u8, &str>(3)}
+    test_case! {Err::<u8, &str>("hello")}
+    test_case! {Ok::<&str, &str>("world")}
+    test_case! {Ok::<(), u32>(())}
+    test_case! {Err::<(), u32>(100)}
+    test_case! {Ok::<bool, i8>(false)}
+    test_case! {Err::<bool, i8>(-1)}
+    test_case! {Ok::<u128, i128>(u128::MAX)}
+    test_case! {Err::<u128, i128>(i128::MIN)}
+    test_case! {Ok::<usize, isize>(5)}
+    test_case! {Err::<f32, f64>(0.5)}
+    test_case! {Ok::<f32, f64>(-1.25)}
+    test_case! {Ok::<char, u64>('Ñ')}
+    test_case! {Err::<char, u64>(u64::MAX)}
+
+    // the `&str` doesn't need to be `'static`
+    let string = String::from("not static");
+    test_case! {Ok::<&str, u16>(&string)}
+    test_case! {Err::<i16, &str>(&string[4..])}
+
+    assert_eq!(trunc_fmt!(1024; FmtArg::HEX; Err::<(), i8>(-2)), "Err(FE)");
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::ALT_HEX; Ok::<u16, ()>(255)),
+        "Ok(\n    0xFF,\n)"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::DISPLAY; Err::<u8, &str>("foo")),
+        "Err(foo)"
+    );
+}
@@ -0,0 +1,102 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use const_panic::{fmt::Alignment, FmtArg, StdWrapper};
+
+macro_rules! test_net_val {
+    ($value:expr) => {{
+        let val = $value;
+
+        test_val! {val}
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(&val).to_panicvals(FmtArg::DEBUG.set_hex())),
+            *format!("{:X?}", val),
+        );
+
+        for width in [0u8, 10, 30, 50] {
+            let w = width as usize;
+            let fmtarg = FmtArg::DISPLAY.set_width(width);
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&val).to_panicvals(fmtarg)),
+                *format!("{:w$}", val, w = w),
+            );
+
+            let fmtarg = fmtarg.set_alignment(Alignment::Right).set_fill('-');
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&val).to_panicvals(fmtarg)),
+                *format!("{:->w$}", val, w = w),
+            );
+
+            let fmtarg = FmtArg::DEBUG
+                .set_width(width)
+                .set_alignment(Alignment::Center);
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&val).to_panicvals(fmtarg)),
+                *format!("{:^w$?}", val, w = w),
+            );
+        }
+    }};
+}
+
+const IPV4S: &[Ipv4Addr] = &[
+    Ipv4Addr::new(0, 0, 0, 0),
+    Ipv4Addr::new(10, 0, 0, 1),
+    Ipv4Addr::new(127, 0, 0, 1),
+    Ipv4Addr::new(192, 168, 100, 255),
+    Ipv4Addr::new(255, 255, 255, 255),
+];
+
+const IPV6S: &[Ipv6Addr] = &[
+    Ipv6Addr::UNSPECIFIED,
+    Ipv6Addr::LOCALHOST,
+    Ipv6Addr::new(1, 0, 0, 0, 0, 0, 0, 0),
+    Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+    Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1),
+    Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1),
+    Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 1, 1, 1, 1),
+    Ipv6Addr::new(0xfe80, 0, 0, 0, 0x1ff, 0xfe23, 0x4567, 0x890a),
+    Ipv6Addr::new(
+        0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
+    ),
+    Ipv6Addr::new(0xABCD, 0, 0xEF, 0, 0, 0x10, 0, 0),
+    // ipv4-mapped
+    Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0x0a00, 0x0001),
+    // ipv4-compatible (deprecated), not specially formatted
+    Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0x0a00, 0x0001),
+];
+
+#[test]
+fn test_ip_addrs() {
+    for &ip in IPV4S {
+        test_net_val! {ip}
+        test_net_val! {IpAddr::V4(ip)}
+    }
+    for &ip in IPV6S {
+        test_net_val! {ip}
+        test_net_val! {IpAddr::V6(ip)}
+    }
+}
+
+#[test]
+fn test_socket_addrs() {
+    for &ip in IPV4S {
+        for port in [0, 80, 8080, 65535] {
+            test_net_val! {SocketAddrV4::new(ip, port)}
+            test_net_val! {SocketAddr::V4(SocketAddrV4::new(ip, port))}
+        }
+    }
+    for &ip in IPV6S {
+        for (port, scope_id) in [(0, 0), (443, 0), (8080, 2), (65535, u32::MAX)] {
+            let addr = SocketAddrV6::new(ip, port, 0, scope_id);
+            test_net_val! {addr}
+            test_net_val! {SocketAddr::V6(addr)}
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn test_net_concat() {
+    const ADDR: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 8080);
+
+    assert_eq!(const_panic::concat_!("addr: ", ADDR), "addr: 10.0.0.1:8080");
+}
@@ -1,6 +1,11 @@
-use core::num::{IntErrorKind, ParseIntError};
+use core::{
+    ffi::CStr,
+    num::{IntErrorKind, ParseIntError},
+};
 
-use const_panic::{FmtArg, StdWrapper};
+use const_panic::{fmt::Alignment, FmtArg, StdWrapper};
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 
 #[test]
 fn test_parse_int_error() {
@@ -46,3 +51,152 @@ fn test_int_error_kind() {
         );
     }
 }
+
+// the expected `Debug` output of a `CStr`,
+// which differs from std in that non-ascii chars are never escaped.
+fn cstr_debug(bytes: &[u8], closing_quote: bool) -> String {
+    let mut out = String::from("\"");
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if c.is_ascii() {
+                out.extend((c as u8).escape_ascii().map(char::from));
+            } else {
+                out.push(c);
+            }
+        }
+        out.extend(chunk.invalid().escape_ascii().map(char::from));
+    }
+    if closing_quote {
+        out.push('"');
+    }
+    out
+}
+
+#[test]
+fn test_cstr() {
+    fn test_cstr(cstr: &CStr) {
+        let bytes = cstr.to_bytes();
+        let display = String::from_utf8_lossy(bytes);
+        let debug = cstr_debug(bytes, true);
+
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(FmtArg::DISPLAY)),
+            &*display,
+        );
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(FmtArg::DEBUG)),
+            &*debug,
+        );
+
+        // Display formatting is padded and truncated like the lossy `String`,
+        // while Debug formatting is neither, like in std.
+        let fmtarg = FmtArg::DISPLAY.set_width(8).set_alignment(Alignment::Right);
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(fmtarg)),
+            *format!("{:>8}", display),
+        );
+        let fmtarg = FmtArg::DISPLAY
+            .set_width(9)
+            .set_fill('*')
+            .set_alignment(Alignment::Center)
+            .set_precision(3);
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(fmtarg)),
+            *format!("{:*^9.3}", display),
+        );
+        let fmtarg = FmtArg::DEBUG.set_width(20).set_alignment(Alignment::Right);
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(fmtarg)),
+            &*debug,
+        );
+
+        // truncation never splits chars or escapes
+        for len in 0..display.len() {
+            let truncated = trunc_fmt!(len; StdWrapper(cstr).to_panicvals(FmtArg::DISPLAY));
+            assert!(
+                display.starts_with(truncated.as_str()),
+                "{:?} {:?}",
+                display,
+                truncated
+            );
+        }
+        for len in 0..debug.len() {
+            let truncated = trunc_fmt!(len; StdWrapper(cstr).to_panicvals(FmtArg::DEBUG));
+            assert!(
+                truncated == ""
+                    || (0..=bytes.len()).any(|i| truncated == *cstr_debug(&bytes[..i], false)),
+                "{:?} {:?}",
+                debug,
+                truncated
+            );
+        }
+    }
+
+    // std escapes non-printable non-ascii chars, none of these have them.
+    for cstr in [
+        c"hello",
+        c"bad magic: \x7fELF",
+        c"人ö个",
+        c"a\xC0\x80b",
+        c"\xF0\x9F\x98",
+    ] {
+        assert_eq!(cstr_debug(cstr.to_bytes(), true), format!("{:?}", cstr));
+        assert_eq!(format!("{:>20?}", cstr), format!("{:?}", cstr));
+    }
+
+    for cstr in [
+        c"",
+        c"hello",
+        c"bad magic: \x7fELF",
+        c"\t\n\r\\\'\"",
+        c"人ö个",
+        // invalid utf8
+        c"\xff\xfe",
+        c"a\xC0\x80b",
+        c"\xE0\x80\x80",
+        c"\xED\xA0\x80",
+        c"\xF4\x90\x80\x80",
+        c"\xE6\x97",
+        c"\xF0\x9F\x98",
+        c"x\xF0\x9F\x98\x80\xF0\x9Fy",
+    ] {
+        test_cstr(cstr);
+    }
+
+    let mut rng = SmallRng::seed_from_u64(8765432109876543210);
+    for _ in 0..1000 {
+        let len = rng.gen_range(0..16);
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| match rng.gen_range(0..4) {
+                0 => rng.gen_range(1..0x80),
+                1 => rng.gen_range(0x80..0xC0),
+                _ => rng.gen_range(0xC0..=0xFF),
+            })
+            .chain([0])
+            .collect();
+
+        test_cstr(CStr::from_bytes_with_nul(&bytes).unwrap());
+    }
+
+    const NAME: &CStr = c"f\xF6o";
+    assert_eq!(
+        trunc_fmt!(1024; "name: ", display: NAME, " ", debug: NAME),
+        "name: f\u{FFFD}o \"f\\xf6o\""
+    );
+}
+
+#[test]
+#[cfg(feature = "non_basic")]
+fn test_location() {
+    let location = core::panic::Location::caller();
+
+    test_val! {*location}
+
+    // like in std, the line and column are Display formatted without the formatting options
+    let display = format!("{}", location);
+    assert_eq!(
+        trunc_fmt!(1024; (FmtArg::HEX.set_display()); *location),
+        *display
+    );
+    assert_eq!(trunc_fmt!(1024; {+}: *location), *display);
+}